@@ -0,0 +1,117 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput};
+
+use resvg_bench::{IMAGE_SIZE, drop_shadow_svg, resvg, svgfilters, synthetic_image};
+use resvg::{tiny_skia, usvg};
+use svgfilters::ImageRefMut;
+
+const PIXELS: u64 = IMAGE_SIZE as u64 * IMAGE_SIZE as u64;
+
+fn gaussian_blur(c: &mut Criterion) {
+    let image = synthetic_image(IMAGE_SIZE, IMAGE_SIZE);
+
+    let mut group = c.benchmark_group("feGaussianBlur");
+    group.throughput(Throughput::Elements(PIXELS));
+    for &std_dev in &[5.0, 10.0, 20.0] {
+        // resvg uses the box blur for any `stdDeviation` above 2,
+        // so the IIR blur is measured only for comparison.
+        group.bench_with_input(BenchmarkId::new("box", std_dev), &std_dev, |b, &std_dev| {
+            b.iter_batched_ref(
+                || image.clone(),
+                |data| svgfilters::box_blur(std_dev, std_dev, ImageRefMut::new(data, IMAGE_SIZE, IMAGE_SIZE)),
+                BatchSize::LargeInput,
+            )
+        });
+
+        group.bench_with_input(BenchmarkId::new("iir", std_dev), &std_dev, |b, &std_dev| {
+            b.iter_batched_ref(
+                || image.clone(),
+                |data| svgfilters::iir_blur(std_dev, std_dev, ImageRefMut::new(data, IMAGE_SIZE, IMAGE_SIZE)),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+fn color_matrix(c: &mut Criterion) {
+    let image = synthetic_image(IMAGE_SIZE, IMAGE_SIZE);
+    let matrix = [
+        0.393, 0.769, 0.189, 0.0, 0.0,
+        0.349, 0.686, 0.168, 0.0, 0.0,
+        0.272, 0.534, 0.131, 0.0, 0.0,
+        0.0,   0.0,   0.0,   1.0, 0.0,
+    ];
+
+    let mut group = c.benchmark_group("feColorMatrix");
+    group.throughput(Throughput::Elements(PIXELS));
+    group.bench_function("matrix", |b| {
+        b.iter_batched_ref(
+            || image.clone(),
+            |data| {
+                let kind = svgfilters::ColorMatrix::Matrix(&matrix);
+                svgfilters::color_matrix(kind, ImageRefMut::new(data, IMAGE_SIZE, IMAGE_SIZE))
+            },
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("hueRotate", |b| {
+        b.iter_batched_ref(
+            || image.clone(),
+            |data| {
+                let kind = svgfilters::ColorMatrix::HueRotate(90.0);
+                svgfilters::color_matrix(kind, ImageRefMut::new(data, IMAGE_SIZE, IMAGE_SIZE))
+            },
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+fn convolve_matrix(c: &mut Criterion) {
+    let image = synthetic_image(IMAGE_SIZE, IMAGE_SIZE);
+    let kernel = [1.0; 25];
+
+    let mut group = c.benchmark_group("feConvolveMatrix");
+    group.throughput(Throughput::Elements(PIXELS));
+    group.bench_function("5x5", |b| {
+        b.iter_batched_ref(
+            || image.clone(),
+            |data| {
+                let matrix = svgfilters::ConvolveMatrix::new(2, 2, 5, 5, &kernel).unwrap();
+                svgfilters::convolve_matrix(
+                    matrix, 25.0, 0.0, svgfilters::EdgeMode::Duplicate, false,
+                    ImageRefMut::new(data, IMAGE_SIZE, IMAGE_SIZE),
+                )
+            },
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+fn drop_shadow(c: &mut Criterion) {
+    let text = drop_shadow_svg(IMAGE_SIZE);
+    let opt = usvg::Options::default();
+
+    let mut group = c.benchmark_group("feDropShadow");
+    group.throughput(Throughput::Elements(PIXELS));
+    // Parsing and rendering, without the output encoding.
+    group.bench_function("end-to-end", |b| {
+        b.iter(|| {
+            let tree = usvg::Tree::from_str(&text, &opt.to_ref()).unwrap();
+            let mut pixmap = tiny_skia::Pixmap::new(IMAGE_SIZE, IMAGE_SIZE).unwrap();
+            resvg::render(
+                &tree, usvg::FitTo::Original, tiny_skia::Transform::default(), pixmap.as_mut(),
+            ).unwrap();
+            pixmap
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, gaussian_blur, color_matrix, convolve_matrix, drop_shadow);
+criterion_main!(benches);
@@ -0,0 +1,54 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/*!
+Shared helpers for the resvg benchmarks.
+
+The benchmarks themselves are in the `benches` directory
+and can be run via `cargo bench -p resvg-bench`.
+*/
+
+#![warn(missing_docs)]
+
+pub use resvg;
+pub use svgfilters;
+
+use svgfilters::RGBA8;
+
+/// The width and height of the synthetic images used by the benchmarks.
+pub const IMAGE_SIZE: u32 = 1024;
+
+/// Creates a synthetic premultiplied RGBA image.
+///
+/// The image is a deterministic mix of gradients and a checkerboard alpha,
+/// so filters cannot take any shortcuts on uniform or fully transparent regions.
+pub fn synthetic_image(width: u32, height: u32) -> Vec<RGBA8> {
+    let mut data = Vec::with_capacity((width * height) as usize);
+    for y in 0..height {
+        for x in 0..width {
+            let a = if (x / 32 + y / 32) % 2 == 0 { 255 } else { 128 };
+            let r = (x * 255 / width) as u8;
+            let g = (y * 255 / height) as u8;
+            let b = ((x + y) * 255 / (width + height)) as u8;
+            data.push(RGBA8 { r, g, b, a });
+        }
+    }
+
+    svgfilters::multiply_alpha(&mut data);
+    data
+}
+
+/// Returns an SVG document that applies `feDropShadow` to a shape
+/// covering most of the `size`x`size` canvas.
+pub fn drop_shadow_svg(size: u32) -> String {
+    format!(
+        "<svg xmlns='http://www.w3.org/2000/svg' width='{size}' height='{size}'>\
+            <filter id='filter1'>\
+                <feDropShadow dx='20' dy='20' stdDeviation='10' flood-color='black'/>\
+            </filter>\
+            <circle cx='{c}' cy='{c}' r='{r}' fill='seagreen' filter='url(#filter1)'/>\
+        </svg>",
+        size = size, c = size / 2, r = size * 3 / 8,
+    )
+}
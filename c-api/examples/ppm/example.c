@@ -0,0 +1,65 @@
+#include <stdlib.h>
+#include <stdio.h>
+#include <string.h>
+#include <resvg.h>
+
+int main(int argc, char **argv)
+{
+    if (argc != 3)
+    {
+        printf("Usage:\n\texample in.svg out.ppm\n");
+        return 1;
+    }
+
+    resvg_init_log();
+
+    resvg_options *opt = resvg_options_create();
+    resvg_options_load_system_fonts(opt);
+
+    resvg_render_tree *tree;
+    int err = resvg_parse_tree_from_file(argv[1], opt, &tree);
+    resvg_options_destroy(opt);
+    if (err != RESVG_OK)
+    {
+        printf("Error id: %i\n", err);
+        return 1;
+    }
+
+    resvg_size size = resvg_get_image_size(tree);
+    int width = (int)size.width;
+    int height = (int)size.height;
+
+    /* An opaque white background, so we can ignore the alpha channel afterwards. */
+    unsigned char *pixmap = malloc(width * height * 4);
+    memset(pixmap, 255, width * height * 4);
+
+    resvg_fit_to fit_to = { RESVG_FIT_TO_TYPE_ORIGINAL, 1 };
+    if (!resvg_render(tree, fit_to, resvg_transform_identity(), width, height, (char*)pixmap))
+    {
+        printf("Failed to render an image.\n");
+        free(pixmap);
+        resvg_tree_destroy(tree);
+        return 1;
+    }
+
+    resvg_tree_destroy(tree);
+
+    FILE *file = fopen(argv[2], "wb");
+    if (!file)
+    {
+        printf("Failed to create '%s'.\n", argv[2]);
+        free(pixmap);
+        return 1;
+    }
+
+    fprintf(file, "P6\n%d %d\n255\n", width, height);
+    for (int i = 0; i < width * height * 4; i += 4)
+    {
+        fwrite(pixmap + i, 1, 3, file);
+    }
+
+    fclose(file);
+    free(pixmap);
+
+    return 0;
+}
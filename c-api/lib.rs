@@ -38,6 +38,10 @@ pub enum resvg_error {
     INVALID_SIZE,
     /// Failed to parse an SVG data.
     PARSING_FAILED,
+    /// A required pointer argument is NULL.
+    NULL_POINTER,
+    /// Multiple elements have the same ID.
+    DUPLICATE_ID,
 }
 
 /// @brief A path bbox representation.
@@ -136,21 +140,23 @@ pub struct resvg_fit_to {
 
 impl resvg_fit_to {
     #[inline]
-    fn to_usvg(&self) -> usvg::FitTo {
+    fn to_usvg(&self) -> Option<usvg::FitTo> {
         match self.kind {
             resvg_fit_to_type::ORIGINAL => {
-                usvg::FitTo::Original
+                Some(usvg::FitTo::Original)
             }
-            resvg_fit_to_type::WIDTH => {
-                assert!(self.value >= 1.0);
-                usvg::FitTo::Width(self.value as u32)
+            resvg_fit_to_type::WIDTH if self.value >= 1.0 => {
+                Some(usvg::FitTo::Width(self.value as u32))
             }
-            resvg_fit_to_type::HEIGHT => {
-                assert!(self.value >= 1.0);
-                usvg::FitTo::Height(self.value as u32)
+            resvg_fit_to_type::HEIGHT if self.value >= 1.0 => {
+                Some(usvg::FitTo::Height(self.value as u32))
             }
-            resvg_fit_to_type::ZOOM => {
-                usvg::FitTo::Zoom(self.value)
+            resvg_fit_to_type::ZOOM if self.value > 0.0 => {
+                Some(usvg::FitTo::Zoom(self.value))
+            }
+            _ => {
+                log::warn!("Invalid fit to value: {}.", self.value);
+                None
             }
         }
     }
@@ -541,9 +547,9 @@ pub struct resvg_render_tree(pub usvg::Tree);
 ///
 /// See #resvg_is_image_empty for details.
 ///
-/// @param file_path UTF-8 file path.
+/// @param file_path UTF-8 file path. Must not be NULL.
 /// @param opt Rendering options. Must not be NULL.
-/// @param tree Parsed render tree. Should be destroyed via #resvg_tree_destroy.
+/// @param tree Parsed render tree. Should be destroyed via #resvg_tree_destroy. Must not be NULL.
 /// @return #resvg_error
 #[no_mangle]
 pub extern "C" fn resvg_parse_tree_from_file(
@@ -551,15 +557,16 @@ pub extern "C" fn resvg_parse_tree_from_file(
     opt: *const resvg_options,
     tree: *mut *mut resvg_render_tree,
 ) -> i32 {
+    if file_path.is_null() || opt.is_null() || tree.is_null() {
+        return resvg_error::NULL_POINTER as i32;
+    }
+
     let file_path = match cstr_to_str(file_path) {
         Some(v) => v,
         None => return resvg_error::NOT_AN_UTF8_STR as i32,
     };
 
-    let raw_opt = unsafe {
-        assert!(!opt.is_null());
-        &*opt
-    };
+    let raw_opt = unsafe { &*opt };
 
     let file_data = match std::fs::read(file_path) {
         Ok(tree) => tree,
@@ -584,7 +591,7 @@ pub extern "C" fn resvg_parse_tree_from_file(
 /// @param data SVG data. Can contain SVG string or gzip compressed data. Must not be NULL.
 /// @param len Data length.
 /// @param opt Rendering options. Must not be NULL.
-/// @param tree Parsed render tree. Should be destroyed via #resvg_tree_destroy.
+/// @param tree Parsed render tree. Should be destroyed via #resvg_tree_destroy. Must not be NULL.
 /// @return #resvg_error
 #[no_mangle]
 pub extern "C" fn resvg_parse_tree_from_data(
@@ -593,12 +600,12 @@ pub extern "C" fn resvg_parse_tree_from_data(
     opt: *const resvg_options,
     tree: *mut *mut resvg_render_tree,
 ) -> i32 {
-    let data = unsafe { slice::from_raw_parts(data as *const u8, len) };
+    if data.is_null() || opt.is_null() || tree.is_null() {
+        return resvg_error::NULL_POINTER as i32;
+    }
 
-    let raw_opt = unsafe {
-        assert!(!opt.is_null());
-        &*opt
-    };
+    let data = unsafe { slice::from_raw_parts(data as *const u8, len) };
+    let raw_opt = unsafe { &*opt };
 
     let utree = match usvg::Tree::from_data(data, &raw_opt.0.to_ref()) {
         Ok(tree) => tree,
@@ -862,6 +869,7 @@ fn convert_error(e: usvg::Error) -> resvg_error {
         usvg::Error::ElementsLimitReached => resvg_error::ELEMENTS_LIMIT_REACHED,
         usvg::Error::InvalidSize => resvg_error::INVALID_SIZE,
         usvg::Error::ParsingFailed(_) => resvg_error::PARSING_FAILED,
+        usvg::Error::DuplicateId(_) => resvg_error::DUPLICATE_ID,
     }
 }
 
@@ -873,7 +881,10 @@ fn convert_error(e: usvg::Error) -> resvg_error {
 /// @param width Pixmap width.
 /// @param height Pixmap height.
 /// @param pixmap Pixmap data. Should have width*height*4 size and contain
-///               premultiplied RGBA8888 pixels.
+///               premultiplied RGBA8888 pixels. Must not be NULL.
+/// @return `false` when `tree` or `pixmap` is NULL.
+/// @return `false` when the pixmap size or `fit_to` is invalid.
+/// @return `false` when the image cannot be fit into the pixmap.
 #[no_mangle]
 pub extern "C" fn resvg_render(
     tree: *const resvg_render_tree,
@@ -882,17 +893,25 @@ pub extern "C" fn resvg_render(
     width: u32,
     height: u32,
     pixmap: *mut c_char,
-) {
-    let tree = unsafe {
-        assert!(!tree.is_null());
-        &*tree
+) -> bool {
+    if tree.is_null() {
+        log::warn!("Render tree must not be NULL.");
+        return false;
+    }
+
+    let tree = unsafe { &*tree };
+
+    let pixmap = match pixmap_from_raw(pixmap, width, height) {
+        Some(v) => v,
+        None => return false,
     };
 
-    let pixmap_len = width as usize * height as usize * tiny_skia::BYTES_PER_PIXEL;
-    let pixmap: &mut [u8] = unsafe { std::slice::from_raw_parts_mut(pixmap as *mut u8, pixmap_len) };
-    let pixmap = tiny_skia::PixmapMut::from_bytes(pixmap, width, height).unwrap();
+    let fit_to = match fit_to.to_usvg() {
+        Some(v) => v,
+        None => return false,
+    };
 
-    resvg::render(&tree.0, fit_to.to_usvg(), transform.to_tiny_skia(), pixmap).unwrap()
+    resvg::render(&tree.0, fit_to, transform.to_tiny_skia(), pixmap).is_some()
 }
 
 /// @brief Renders a Node by ID onto the image.
@@ -905,9 +924,11 @@ pub extern "C" fn resvg_render(
 /// @param height Pixmap height.
 /// @param pixmap Pixmap data. Should have width*height*4 size and contain
 ///               premultiplied RGBA8888 pixels.
+/// @return `false` when `tree`, `id` or `pixmap` is NULL.
 /// @return `false` when `id` is not a non-empty UTF-8 string.
 /// @return `false` when the selected `id` is not present.
 /// @return `false` when an element has a zero bbox.
+/// @return `false` when the pixmap size or `fit_to` is invalid.
 #[no_mangle]
 pub extern "C" fn resvg_render_node(
     tree: *const resvg_render_tree,
@@ -918,10 +939,12 @@ pub extern "C" fn resvg_render_node(
     height: u32,
     pixmap: *mut c_char,
 ) -> bool {
-    let tree = unsafe {
-        assert!(!tree.is_null());
-        &*tree
-    };
+    if tree.is_null() || id.is_null() {
+        log::warn!("Render tree and node ID must not be NULL.");
+        return false;
+    }
+
+    let tree = unsafe { &*tree };
 
     let id = match cstr_to_str(id) {
         Some(v) => v,
@@ -934,17 +957,39 @@ pub extern "C" fn resvg_render_node(
     }
 
     if let Some(node) = tree.0.node_by_id(id) {
-        let pixmap_len = width as usize * height as usize * tiny_skia::BYTES_PER_PIXEL;
-        let pixmap: &mut [u8] = unsafe { std::slice::from_raw_parts_mut(pixmap as *mut u8, pixmap_len) };
-        let pixmap = tiny_skia::PixmapMut::from_bytes(pixmap, width, height).unwrap();
+        let pixmap = match pixmap_from_raw(pixmap, width, height) {
+            Some(v) => v,
+            None => return false,
+        };
 
-        resvg::render_node(&tree.0, &node, fit_to.to_usvg(), transform.to_tiny_skia(), pixmap).is_some()
+        let fit_to = match fit_to.to_usvg() {
+            Some(v) => v,
+            None => return false,
+        };
+
+        resvg::render_node(&tree.0, &node, fit_to, transform.to_tiny_skia(), pixmap).is_some()
     } else {
         log::warn!("A node with '{}' ID wasn't found.", id);
         false
     }
 }
 
+fn pixmap_from_raw<'a>(pixmap: *mut c_char, width: u32, height: u32) -> Option<tiny_skia::PixmapMut<'a>> {
+    if pixmap.is_null() {
+        log::warn!("Pixmap must not be NULL.");
+        return None;
+    }
+
+    let pixmap_len = width as usize * height as usize * tiny_skia::BYTES_PER_PIXEL;
+    let pixmap: &mut [u8] = unsafe { std::slice::from_raw_parts_mut(pixmap as *mut u8, pixmap_len) };
+    let pixmap = tiny_skia::PixmapMut::from_bytes(pixmap, width, height);
+    if pixmap.is_none() {
+        log::warn!("Invalid pixmap size: {}x{}.", width, height);
+    }
+
+    pixmap
+}
+
 
 /// A simple stderr logger.
 static LOGGER: SimpleLogger = SimpleLogger;
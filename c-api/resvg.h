@@ -57,6 +57,14 @@ typedef enum {
      * Failed to parse an SVG data.
      */
     RESVG_ERROR_PARSING_FAILED,
+    /**
+     * A required pointer argument is NULL.
+     */
+    RESVG_ERROR_NULL_POINTER,
+    /**
+     * Multiple elements have the same ID.
+     */
+    RESVG_ERROR_DUPLICATE_ID,
 } resvg_error;
 
 /**
@@ -406,9 +414,9 @@ void resvg_options_destroy(resvg_options *opt);
  *
  * See #resvg_is_image_empty for details.
  *
- * @param file_path UTF-8 file path.
+ * @param file_path UTF-8 file path. Must not be NULL.
  * @param opt Rendering options. Must not be NULL.
- * @param tree Parsed render tree. Should be destroyed via #resvg_tree_destroy.
+ * @param tree Parsed render tree. Should be destroyed via #resvg_tree_destroy. Must not be NULL.
  * @return #resvg_error
  */
 int32_t resvg_parse_tree_from_file(const char *file_path,
@@ -423,7 +431,7 @@ int32_t resvg_parse_tree_from_file(const char *file_path,
  * @param data SVG data. Can contain SVG string or gzip compressed data. Must not be NULL.
  * @param len Data length.
  * @param opt Rendering options. Must not be NULL.
- * @param tree Parsed render tree. Should be destroyed via #resvg_tree_destroy.
+ * @param tree Parsed render tree. Should be destroyed via #resvg_tree_destroy. Must not be NULL.
  * @return #resvg_error
  */
 int32_t resvg_parse_tree_from_data(const char *data,
@@ -523,9 +531,12 @@ void resvg_tree_destroy(resvg_render_tree *tree);
  * @param width Pixmap width.
  * @param height Pixmap height.
  * @param pixmap Pixmap data. Should have width*height*4 size and contain
- *               premultiplied RGBA8888 pixels.
+ *               premultiplied RGBA8888 pixels. Must not be NULL.
+ * @return `false` when `tree` or `pixmap` is NULL.
+ * @return `false` when the pixmap size or `fit_to` is invalid.
+ * @return `false` when the image cannot be fit into the pixmap.
  */
-void resvg_render(const resvg_render_tree *tree,
+bool resvg_render(const resvg_render_tree *tree,
                   resvg_fit_to fit_to,
                   resvg_transform transform,
                   uint32_t width,
@@ -543,9 +554,11 @@ void resvg_render(const resvg_render_tree *tree,
  * @param height Pixmap height.
  * @param pixmap Pixmap data. Should have width*height*4 size and contain
  *               premultiplied RGBA8888 pixels.
+ * @return `false` when `tree`, `id` or `pixmap` is NULL.
  * @return `false` when `id` is not a non-empty UTF-8 string.
  * @return `false` when the selected `id` is not present.
  * @return `false` when an element has a zero bbox.
+ * @return `false` when the pixmap size or `fit_to` is invalid.
  */
 bool resvg_render_node(const resvg_render_tree *tree,
                        const char *id,
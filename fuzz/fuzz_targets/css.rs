@@ -0,0 +1,41 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// The CSS parser is not a part of the public API,
+// so we are passing the input both as a style sheet and as a `style` attribute.
+fuzz_target!(|data: &[u8]| {
+    let css = match std::str::from_utf8(data) {
+        Ok(v) => v,
+        Err(_) => return,
+    };
+
+    let opt = usvg::Options::default();
+
+    let text = format!(
+        "<svg xmlns='http://www.w3.org/2000/svg' width='100' height='100'>\
+            <style><![CDATA[{}]]></style>\
+            <g id='g1' class='a'><rect id='rect1' class='b' width='10' height='10'/></g>\
+        </svg>",
+        css.replace("]]>", ""),
+    );
+    let _ = usvg::Tree::from_str(&text, &opt.to_ref());
+
+    let mut attr = String::with_capacity(css.len());
+    for c in css.chars() {
+        match c {
+            '"' => attr.push_str("&quot;"),
+            '<' => attr.push_str("&lt;"),
+            '&' => attr.push_str("&amp;"),
+            _ => attr.push(c),
+        }
+    }
+
+    let text = format!(
+        "<svg xmlns='http://www.w3.org/2000/svg' width='100' height='100'>\
+            <rect id='rect1' width='10' height='10' style=\"{}\"/>\
+        </svg>",
+        attr,
+    );
+    let _ = usvg::Tree::from_str(&text, &opt.to_ref());
+});
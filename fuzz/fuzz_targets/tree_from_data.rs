@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let opt = usvg::Options::default();
+    let _ = usvg::Tree::from_data(data, &opt.to_ref());
+});
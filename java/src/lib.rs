@@ -0,0 +1,124 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! JNI bindings for `io.github.razrfalcon.resvg.ResvgRenderer`.
+
+use jni::JNIEnv;
+use jni::objects::{JClass, JString};
+use jni::sys::{jbyteArray, jdouble, jdoubleArray, jint, jobjectArray};
+
+use resvg::{tiny_skia, usvg};
+
+const EXCEPTION_CLASS: &str = "java/lang/IllegalArgumentException";
+
+/// Renders an SVG scaled to the specified size.
+///
+/// Returns non-premultiplied RGBA8888 pixels.
+/// Throws `IllegalArgumentException` on error.
+#[no_mangle]
+pub extern "system" fn Java_io_github_razrfalcon_resvg_ResvgRenderer_renderSvg(
+    env: JNIEnv,
+    _class: JClass,
+    svg: jbyteArray,
+    width: jint,
+    height: jint,
+    font_dirs: jobjectArray,
+    dpi: jdouble,
+) -> jbyteArray {
+    let result = parse(&env, svg, font_dirs, dpi)
+        .and_then(|tree| render(&tree, width, height))
+        .and_then(|data| env.byte_array_from_slice(&data).map_err(|e| e.to_string()));
+
+    match result {
+        Ok(array) => array,
+        Err(e) => {
+            let _ = env.throw_new(EXCEPTION_CLASS, e);
+            std::ptr::null_mut()
+        }
+    }
+}
+
+/// Returns the SVG size as `[width, height]`.
+///
+/// Throws `IllegalArgumentException` on error.
+#[no_mangle]
+pub extern "system" fn Java_io_github_razrfalcon_resvg_ResvgRenderer_getSize(
+    env: JNIEnv,
+    _class: JClass,
+    svg: jbyteArray,
+    font_dirs: jobjectArray,
+    dpi: jdouble,
+) -> jdoubleArray {
+    let result = parse(&env, svg, font_dirs, dpi).and_then(|tree| {
+        let size = tree.svg_node().size;
+        let array = env.new_double_array(2).map_err(|e| e.to_string())?;
+        env.set_double_array_region(array, 0, &[size.width(), size.height()])
+            .map_err(|e| e.to_string())?;
+        Ok(array)
+    });
+
+    match result {
+        Ok(array) => array,
+        Err(e) => {
+            let _ = env.throw_new(EXCEPTION_CLASS, e);
+            std::ptr::null_mut()
+        }
+    }
+}
+
+fn parse(
+    env: &JNIEnv,
+    svg: jbyteArray,
+    font_dirs: jobjectArray,
+    dpi: jdouble,
+) -> Result<usvg::Tree, String> {
+    if svg.is_null() {
+        return Err("SVG data must not be null".to_string());
+    }
+
+    let data = env.convert_byte_array(svg).map_err(|e| e.to_string())?;
+
+    let mut opt = usvg::Options {
+        dpi,
+        ..usvg::Options::default()
+    };
+    opt.fontdb.load_system_fonts();
+
+    if !font_dirs.is_null() {
+        let len = env.get_array_length(font_dirs).map_err(|e| e.to_string())?;
+        for i in 0..len {
+            let dir = env.get_object_array_element(font_dirs, i).map_err(|e| e.to_string())?;
+            let dir: String = env.get_string(JString::from(dir)).map_err(|e| e.to_string())?.into();
+            opt.fontdb.load_fonts_dir(dir);
+        }
+    }
+
+    usvg::Tree::from_data(&data, &opt.to_ref()).map_err(|e| e.to_string())
+}
+
+fn render(tree: &usvg::Tree, width: jint, height: jint) -> Result<Vec<u8>, String> {
+    let pixmap = if width > 0 && height > 0 {
+        tiny_skia::Pixmap::new(width as u32, height as u32)
+    } else {
+        None
+    };
+    let mut pixmap = pixmap.ok_or_else(|| format!("invalid image size: {}x{}", width, height))?;
+
+    let size = tree.svg_node().size;
+    let ts = tiny_skia::Transform::from_scale(
+        (width as f64 / size.width()) as f32,
+        (height as f64 / size.height()) as f32,
+    );
+
+    resvg::render(tree, usvg::FitTo::Original, ts, pixmap.as_mut())
+        .ok_or_else(|| "failed to render an image".to_string())?;
+
+    let mut data = Vec::with_capacity(pixmap.data().len());
+    for pixel in pixmap.pixels() {
+        let c = pixel.demultiply();
+        data.extend_from_slice(&[c.red(), c.green(), c.blue(), c.alpha()]);
+    }
+
+    Ok(data)
+}
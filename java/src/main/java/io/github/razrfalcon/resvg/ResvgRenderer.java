@@ -0,0 +1,126 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+package io.github.razrfalcon.resvg;
+
+import java.awt.image.BufferedImage;
+import java.io.File;
+import java.io.IOException;
+import java.io.InputStream;
+import java.nio.charset.StandardCharsets;
+import java.nio.file.Files;
+import java.nio.file.StandardCopyOption;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.Locale;
+
+/**
+ * An SVG renderer backed by the native resvg library.
+ *
+ * <p>The native library is loaded from {@code java.library.path} first
+ * and then from the {@code /native/<os>-<arch>/} JAR resource.
+ */
+public final class ResvgRenderer {
+    private static final String LIBRARY_NAME = "resvg_jni";
+
+    static {
+        loadLibrary();
+    }
+
+    /** Parsing options. */
+    public static final class Options {
+        /** Directories to load fonts from. System fonts are always loaded. */
+        public final List<String> fontDirs = new ArrayList<>();
+
+        /** A target DPI. Used to convert units like {@code mm}. */
+        public double dpi = 96.0;
+    }
+
+    private ResvgRenderer() {}
+
+    private static native byte[] renderSvg(byte[] svg, int width, int height, String[] fontDirs, double dpi);
+
+    private static native double[] getSize(byte[] svg, String[] fontDirs, double dpi);
+
+    /**
+     * Renders an SVG scaled to the specified size.
+     *
+     * @param svg SVG data. Can be gzip compressed.
+     * @return non-premultiplied RGBA8888 pixels, row by row, without padding
+     * @throws IllegalArgumentException when an SVG cannot be parsed or rendered
+     */
+    public static byte[] render(byte[] svg, int width, int height, Options options) {
+        return renderSvg(svg, width, height, options.fontDirs.toArray(new String[0]), options.dpi);
+    }
+
+    /**
+     * Returns the SVG size as {@code [width, height]}.
+     *
+     * @throws IllegalArgumentException when an SVG cannot be parsed
+     */
+    public static double[] size(byte[] svg, Options options) {
+        return getSize(svg, options.fontDirs.toArray(new String[0]), options.dpi);
+    }
+
+    /**
+     * Renders an SVG scaled to the specified size into a {@code TYPE_INT_ARGB} image.
+     *
+     * @throws IllegalArgumentException when an SVG cannot be parsed or rendered
+     */
+    public static BufferedImage renderToBufferedImage(String svg, int width, int height, Options options) {
+        byte[] rgba = render(svg.getBytes(StandardCharsets.UTF_8), width, height, options);
+
+        int[] argb = new int[width * height];
+        for (int i = 0; i < argb.length; i++) {
+            int r = rgba[i * 4] & 0xFF;
+            int g = rgba[i * 4 + 1] & 0xFF;
+            int b = rgba[i * 4 + 2] & 0xFF;
+            int a = rgba[i * 4 + 3] & 0xFF;
+            argb[i] = (a << 24) | (r << 16) | (g << 8) | b;
+        }
+
+        BufferedImage image = new BufferedImage(width, height, BufferedImage.TYPE_INT_ARGB);
+        image.setRGB(0, 0, width, height, argb, 0, width);
+        return image;
+    }
+
+    /** Same as {@link #renderToBufferedImage(String, int, int, Options)} with default options. */
+    public static BufferedImage renderToBufferedImage(String svg, int width, int height) {
+        return renderToBufferedImage(svg, width, height, new Options());
+    }
+
+    private static void loadLibrary() {
+        try {
+            System.loadLibrary(LIBRARY_NAME);
+            return;
+        } catch (UnsatisfiedLinkError e) {
+            // Fallback to the bundled library.
+        }
+
+        String os = System.getProperty("os.name").toLowerCase(Locale.ROOT);
+        if (os.startsWith("windows")) {
+            os = "windows";
+        } else if (os.startsWith("mac")) {
+            os = "macos";
+        }
+
+        String arch = System.getProperty("os.arch").toLowerCase(Locale.ROOT);
+        String fileName = System.mapLibraryName(LIBRARY_NAME);
+        String resource = "/native/" + os + "-" + arch + "/" + fileName;
+
+        try (InputStream in = ResvgRenderer.class.getResourceAsStream(resource)) {
+            if (in == null) {
+                throw new UnsatisfiedLinkError("no " + resource + " resource and no "
+                    + LIBRARY_NAME + " in java.library.path");
+            }
+
+            File file = File.createTempFile(LIBRARY_NAME, fileName);
+            file.deleteOnExit();
+            Files.copy(in, file.toPath(), StandardCopyOption.REPLACE_EXISTING);
+            System.load(file.getAbsolutePath());
+        } catch (IOException e) {
+            throw new UnsatisfiedLinkError("failed to extract " + resource + ": " + e.getMessage());
+        }
+    }
+}
@@ -0,0 +1,112 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Python bindings.
+
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict};
+
+use ::resvg::{tiny_skia, usvg};
+
+/// A parsed SVG image.
+//
+// `usvg::Tree` is based on `Rc`, so it cannot be shared between threads.
+#[pyclass(unsendable)]
+struct SvgTree(usvg::Tree);
+
+#[pymethods]
+impl SvgTree {
+    /// Parses an SVG image from a string.
+    ///
+    /// Supported `options` keys: `font_dirs`, `dpi`, `resource_dir` and `image_rendering`.
+    #[staticmethod]
+    #[args(options = "None")]
+    fn from_str(svg: &str, options: Option<&PyDict>) -> PyResult<Self> {
+        let opt = parse_options(options)?;
+        let tree = usvg::Tree::from_str(svg, &opt.to_ref())
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok(SvgTree(tree))
+    }
+
+    /// The image size as a `(width, height)` tuple.
+    #[getter]
+    fn size(&self) -> (f64, f64) {
+        let size = self.0.svg_node().size;
+        (size.width(), size.height())
+    }
+
+    /// Renders the image scaled to the specified size.
+    ///
+    /// Returns non-premultiplied RGBA8888 pixels, which can be passed directly
+    /// to `PIL.Image.frombytes('RGBA', (width, height), data)`.
+    fn render<'py>(&self, py: Python<'py>, width: u32, height: u32) -> PyResult<&'py PyBytes> {
+        let mut pixmap = tiny_skia::Pixmap::new(width, height)
+            .ok_or_else(|| PyValueError::new_err("invalid image size"))?;
+
+        let size = self.0.svg_node().size;
+        let ts = tiny_skia::Transform::from_scale(
+            (width as f64 / size.width()) as f32,
+            (height as f64 / size.height()) as f32,
+        );
+
+        ::resvg::render(&self.0, usvg::FitTo::Original, ts, pixmap.as_mut())
+            .ok_or_else(|| PyValueError::new_err("failed to render an image"))?;
+
+        let mut data = Vec::with_capacity(pixmap.data().len());
+        for pixel in pixmap.pixels() {
+            let c = pixel.demultiply();
+            data.extend_from_slice(&[c.red(), c.green(), c.blue(), c.alpha()]);
+        }
+
+        Ok(PyBytes::new(py, &data))
+    }
+}
+
+fn parse_options(options: Option<&PyDict>) -> PyResult<usvg::Options> {
+    let mut opt = usvg::Options::default();
+    opt.fontdb.load_system_fonts();
+
+    let options = match options {
+        Some(v) => v,
+        None => return Ok(opt),
+    };
+
+    for (key, value) in options.iter() {
+        let key: &str = key.extract()?;
+        match key {
+            "font_dirs" => {
+                let dirs: Vec<String> = value.extract()?;
+                for dir in dirs {
+                    opt.fontdb.load_fonts_dir(dir);
+                }
+            }
+            "dpi" => {
+                opt.dpi = value.extract()?;
+            }
+            "resource_dir" => {
+                let dir: String = value.extract()?;
+                opt.resources_dir = Some(dir.into());
+            }
+            "image_rendering" => {
+                let mode: &str = value.extract()?;
+                opt.image_rendering = match mode {
+                    "optimizeQuality" => usvg::ImageRendering::OptimizeQuality,
+                    "optimizeSpeed" => usvg::ImageRendering::OptimizeSpeed,
+                    _ => return Err(PyValueError::new_err(format!("invalid image_rendering: {}", mode))),
+                };
+            }
+            _ => return Err(PyTypeError::new_err(format!("unknown option: {}", key))),
+        }
+    }
+
+    Ok(opt)
+}
+
+/// resvg Python bindings.
+#[pymodule]
+fn resvg(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_class::<SvgTree>()?;
+    Ok(())
+}
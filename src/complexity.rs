@@ -0,0 +1,182 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+#[cfg(feature = "filter")]
+use usvg::{NodeExt, TransformFromBBox};
+
+/// A rough rendering complexity estimation.
+///
+/// See `analyze_complexity`.
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub struct ComplexityReport {
+    /// The total number of nodes in the tree.
+    ///
+    /// Doesn't include the root node and nodes inside `defs`.
+    pub nodes: usize,
+
+    /// The total number of filter primitives on all elements.
+    pub filter_primitives: usize,
+
+    /// The total area of all filter regions in pixels.
+    ///
+    /// Each region is clipped by the image bounds.
+    pub filter_area: u64,
+
+    /// The number of paths converted from text.
+    ///
+    /// Like all other values, doesn't include clip path, mask and pattern contents.
+    pub text_paths: usize,
+
+    /// The number of raster and SVG images.
+    pub images: usize,
+
+    /// The total number of clip paths and masks, including nested ones.
+    pub clip_mask_layers: usize,
+
+    /// The predicted rendering time in milliseconds.
+    ///
+    /// A linear model based on the other values and the image size.
+    /// It was fitted on the resvg test suite and should be used only to compare images
+    /// with each other. The actual time depends on the hardware and the build configuration.
+    /// `feMorphology` with a large radius is not accounted.
+    pub predicted_ms: f64,
+}
+
+/// Estimates how expensive an SVG is to render.
+///
+/// `fit_to` is the same as in `render` and affects the filters area.
+///
+/// Doesn't render anything and is much faster than the rendering itself.
+/// Can be used to decide whether the image should be rendered in a background thread.
+pub fn analyze_complexity(tree: &usvg::Tree, fit_to: usvg::FitTo) -> ComplexityReport {
+    let svg = tree.svg_node();
+    let size = fit_to.fit_to(svg.size.to_screen_size())
+        .unwrap_or_else(|| svg.size.to_screen_size());
+
+    let mut report = ComplexityReport::default();
+    // The first child is `defs`.
+    for node in tree.root().children().skip(1).flat_map(|n| n.descendants()) {
+        report.nodes += 1;
+
+        match *node.borrow() {
+            usvg::NodeKind::Path(ref path) if path.text_bbox.is_some() => {
+                report.text_paths += 1;
+            }
+            usvg::NodeKind::Image(_) => {
+                report.images += 1;
+            }
+            usvg::NodeKind::Group(ref g) => {
+                report.clip_mask_layers += count_clip_paths(tree, g.clip_path.as_deref());
+                report.clip_mask_layers += count_masks(tree, g.mask.as_deref());
+
+                #[cfg(feature = "filter")]
+                for id in &g.filter {
+                    if let Some(filter_node) = tree.defs_by_id(id) {
+                        if let usvg::NodeKind::Filter(ref filter) = *filter_node.borrow() {
+                            report.filter_primitives += filter.primitives.len();
+                            report.filter_area += filter_area(tree, &node, filter, size);
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    report.predicted_ms = predict(&report, size);
+    report
+}
+
+const MAX_LAYERS: usize = 64;
+
+fn count_clip_paths(tree: &usvg::Tree, id: Option<&str>) -> usize {
+    let mut count = 0;
+    let mut id = id.map(|s| s.to_string());
+    while let Some(node) = id.and_then(|id| tree.defs_by_id(&id)) {
+        id = match *node.borrow() {
+            usvg::NodeKind::ClipPath(ref cp) => cp.clip_path.clone(),
+            _ => None,
+        };
+        count += 1;
+
+        // Just in case. Recursive links should be already removed by usvg.
+        if count == MAX_LAYERS {
+            break;
+        }
+    }
+
+    count
+}
+
+fn count_masks(tree: &usvg::Tree, id: Option<&str>) -> usize {
+    let mut count = 0;
+    let mut id = id.map(|s| s.to_string());
+    while let Some(node) = id.and_then(|id| tree.defs_by_id(&id)) {
+        id = match *node.borrow() {
+            usvg::NodeKind::Mask(ref mask) => mask.mask.clone(),
+            _ => None,
+        };
+        count += 1;
+
+        // Just in case. Recursive links should be already removed by usvg.
+        if count == MAX_LAYERS {
+            break;
+        }
+    }
+
+    count
+}
+
+#[cfg(feature = "filter")]
+fn filter_area(
+    tree: &usvg::Tree,
+    node: &usvg::Node,
+    filter: &usvg::filter::Filter,
+    size: usvg::ScreenSize,
+) -> u64 {
+    // Unwrap is safe, because `size` is already valid.
+    let canvas_rect = usvg::ScreenRect::new(0, 0, size.width(), size.height()).unwrap();
+    let view_box = tree.svg_node().view_box;
+    let mut region_ts = usvg::utils::view_box_to_transform(view_box.rect, view_box.aspect, size.to_size());
+    if filter.units == usvg::Units::ObjectBoundingBox {
+        // `calculate_bbox` returns a bbox in the canvas coordinates,
+        // so rotation and skew are ignored. Which is fine for an estimation.
+        let bbox = match node.calculate_bbox().and_then(|r| r.to_rect()) {
+            Some(v) => v,
+            None => return 0,
+        };
+
+        region_ts.append(&usvg::Transform::from_bbox(bbox));
+    } else {
+        region_ts.append(&node.abs_transform());
+    }
+
+    let region = usvg::PathData::from_rect(filter.rect)
+        .bbox_with_transform(region_ts, None)
+        .and_then(|r| r.to_rect())
+        .map(|r| r.to_screen_rect().fit_to_rect(canvas_rect));
+
+    match region {
+        Some(r) => r.width() as u64 * r.height() as u64,
+        None => 0,
+    }
+}
+
+// Fitted via the least squares method on the resvg test suite rendered at 300px and 1000px width,
+// excluding `feMorphology` tests, since its performance depends mostly on the radius.
+//
+// `nodes` and `text_paths` had no measurable effect on the test suite,
+// because most of the tests have just a couple of simple elements.
+fn predict(report: &ComplexityReport, size: usvg::ScreenSize) -> f64 {
+    let pixels = size.width() as f64 * size.height() as f64;
+
+    let ms = -0.33
+        + pixels * 5.4e-6
+        + report.filter_primitives as f64 * 3.3
+        + report.filter_area as f64 * 6.7e-5
+        + report.images as f64 * 1.67
+        + report.clip_mask_layers as f64 * 1.02;
+
+    ms.max(0.0)
+}
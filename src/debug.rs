@@ -0,0 +1,238 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Debug overlays. See `render_debug`.
+
+use usvg::NodeExt;
+
+use crate::ConvTransform;
+
+/// Debug overlays options.
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub struct DebugOptions {
+    /// Outlines filter regions and fills filter primitive subregions.
+    ///
+    /// Each filter primitive type has its own color.
+    /// Requires the `filter` feature.
+    pub show_filter_regions: bool,
+
+    /// Outlines bounding boxes of all paths and images.
+    pub show_bounding_boxes: bool,
+}
+
+/// Renders an SVG to pixmap with debug overlays on top of it.
+///
+/// The same as `render`, but the overlays are drawn after the image.
+/// Mainly intended for checking filter regions.
+pub fn render_debug(
+    tree: &usvg::Tree,
+    fit_to: usvg::FitTo,
+    transform: tiny_skia::Transform,
+    options: &DebugOptions,
+    pixmap: tiny_skia::PixmapMut,
+) -> Option<()> {
+    crate::render_with_hook(tree, fit_to, transform, None, pixmap, |canvas| {
+        let ts = usvg::Transform::from_native(canvas.transform);
+        draw_node(&tree.root(), options, tree, ts, &mut canvas.pixmap);
+    })
+}
+
+fn draw_node(
+    node: &usvg::Node,
+    options: &DebugOptions,
+    tree: &usvg::Tree,
+    ts: usvg::Transform,
+    pixmap: &mut tiny_skia::PixmapMut,
+) {
+    match *node.borrow() {
+        usvg::NodeKind::Path(ref path) if options.show_bounding_boxes => {
+            if let Some(bbox) = path.data.bbox() {
+                draw_rect(bbox.to_rect(), BBOX_COLOR, false, ts, pixmap);
+            }
+        }
+        usvg::NodeKind::Image(ref img) if options.show_bounding_boxes => {
+            draw_rect(Some(img.view_box.rect), BBOX_COLOR, false, ts, pixmap);
+        }
+        usvg::NodeKind::Group(ref g) if options.show_filter_regions && !g.filter.is_empty() => {
+            draw_filter_regions(node, g, tree, ts, pixmap);
+        }
+        _ => {}
+    }
+
+    for child in node.children() {
+        let mut child_ts = ts;
+        child_ts.append(&child.transform());
+        draw_node(&child, options, tree, child_ts, pixmap);
+    }
+}
+
+#[cfg(feature = "filter")]
+fn draw_filter_regions(
+    node: &usvg::Node,
+    g: &usvg::Group,
+    tree: &usvg::Tree,
+    ts: usvg::Transform,
+    pixmap: &mut tiny_skia::PixmapMut,
+) {
+    let bbox = calc_bbox(node, usvg::Transform::default()).and_then(|r| r.to_rect());
+
+    for id in &g.filter {
+        let filter_node = match tree.defs_by_id(id) {
+            Some(v) => v,
+            None => continue,
+        };
+
+        if let usvg::NodeKind::Filter(ref filter) = *filter_node.borrow() {
+            let region = match filter.units {
+                usvg::Units::ObjectBoundingBox => bbox.map(|bbox| filter.rect.bbox_transform(bbox)),
+                usvg::Units::UserSpaceOnUse => Some(filter.rect),
+            };
+
+            let region = match region {
+                Some(v) => v,
+                None => continue,
+            };
+
+            for primitive in &filter.primitives {
+                let subregion = calc_subregion(filter, primitive, bbox, region);
+                draw_rect(subregion, primitive_color(&primitive.kind), true, ts, pixmap);
+            }
+
+            draw_rect(Some(region), FILTER_REGION_COLOR, false, ts, pixmap);
+        };
+    }
+}
+
+#[cfg(not(feature = "filter"))]
+fn draw_filter_regions(
+    _: &usvg::Node,
+    _: &usvg::Group,
+    _: &usvg::Tree,
+    _: usvg::Transform,
+    _: &mut tiny_skia::PixmapMut,
+) {
+}
+
+/// Returns the primitive subregion in user space.
+///
+/// Unlike the renderer, doesn't clip it and ignores `feOffset`/`feTile` specifics.
+#[cfg(feature = "filter")]
+fn calc_subregion(
+    filter: &usvg::filter::Filter,
+    primitive: &usvg::filter::Primitive,
+    bbox: Option<usvg::Rect>,
+    region: usvg::Rect,
+) -> Option<usvg::Rect> {
+    let (x, y, w, h) = match filter.primitive_units {
+        usvg::Units::ObjectBoundingBox => {
+            let bbox = bbox?;
+            (
+                primitive.x.map(|n| bbox.x() + n * bbox.width()),
+                primitive.y.map(|n| bbox.y() + n * bbox.height()),
+                primitive.width.map(|n| n * bbox.width()),
+                primitive.height.map(|n| n * bbox.height()),
+            )
+        }
+        usvg::Units::UserSpaceOnUse => (primitive.x, primitive.y, primitive.width, primitive.height),
+    };
+
+    usvg::Rect::new(
+        x.unwrap_or(region.x()),
+        y.unwrap_or(region.y()),
+        w.unwrap_or(region.width()),
+        h.unwrap_or(region.height()),
+    )
+}
+
+// The same as `NodeExt::calculate_bbox`, but with a custom transform and without stroke.
+#[cfg(feature = "filter")]
+fn calc_bbox(node: &usvg::Node, ts: usvg::Transform) -> Option<usvg::PathBbox> {
+    use usvg::FuzzyEq;
+
+    match *node.borrow() {
+        usvg::NodeKind::Path(ref path) => path.data.bbox_with_transform(ts, None),
+        usvg::NodeKind::Image(ref img) => {
+            usvg::PathData::from_rect(img.view_box.rect).bbox_with_transform(ts, None)
+        }
+        usvg::NodeKind::Svg(_) | usvg::NodeKind::Group(_) => {
+            let mut bbox = usvg::PathBbox::new_bbox();
+            for child in node.children() {
+                let mut child_ts = ts;
+                child_ts.append(&child.transform());
+                if let Some(c_bbox) = calc_bbox(&child, child_ts) {
+                    bbox = bbox.expand(c_bbox);
+                }
+            }
+
+            if bbox.fuzzy_eq(&usvg::PathBbox::new_bbox()) {
+                None
+            } else {
+                Some(bbox)
+            }
+        }
+        _ => None,
+    }
+}
+
+const BBOX_COLOR: (u8, u8, u8) = (0, 128, 255);
+#[cfg(feature = "filter")]
+const FILTER_REGION_COLOR: (u8, u8, u8) = (255, 0, 0);
+
+#[cfg(feature = "filter")]
+fn primitive_color(kind: &usvg::filter::Kind) -> (u8, u8, u8) {
+    use usvg::filter::Kind;
+
+    match kind {
+        Kind::Blend(..) => (230, 25, 75),
+        Kind::ColorMatrix(..) => (60, 180, 75),
+        Kind::ComponentTransfer(..) => (255, 225, 25),
+        Kind::Composite(..) => (67, 99, 216),
+        Kind::ConvolveMatrix(..) => (245, 130, 49),
+        Kind::DiffuseLighting(..) => (145, 30, 180),
+        Kind::DisplacementMap(..) => (70, 240, 240),
+        Kind::DropShadow(..) => (240, 50, 230),
+        Kind::Flood(..) => (188, 246, 12),
+        Kind::GaussianBlur(..) => (0, 128, 128),
+        Kind::Image(..) => (154, 99, 36),
+        Kind::Merge(..) => (128, 0, 0),
+        Kind::Morphology(..) => (170, 255, 195),
+        Kind::Offset(..) => (128, 128, 0),
+        Kind::SpecularLighting(..) => (0, 0, 117),
+        Kind::Tile(..) => (128, 128, 128),
+        Kind::Turbulence(..) => (255, 216, 177),
+    }
+}
+
+fn draw_rect(
+    rect: Option<usvg::Rect>,
+    (r, g, b): (u8, u8, u8),
+    fill: bool,
+    ts: usvg::Transform,
+    pixmap: &mut tiny_skia::PixmapMut,
+) -> Option<()> {
+    let rect = rect?;
+    let rect = tiny_skia::Rect::from_xywh(
+        rect.x() as f32, rect.y() as f32, rect.width() as f32, rect.height() as f32,
+    )?;
+
+    // Transform the path instead of the paint, so the outline always has the same width.
+    let path = tiny_skia::PathBuilder::from_rect(rect).transform(ts.to_native())?;
+
+    let mut paint = tiny_skia::Paint {
+        anti_alias: true,
+        ..tiny_skia::Paint::default()
+    };
+
+    if fill {
+        paint.set_color_rgba8(r, g, b, 64);
+        pixmap.fill_path(&path, &paint, tiny_skia::FillRule::Winding,
+                         tiny_skia::Transform::identity(), None);
+    }
+
+    paint.set_color_rgba8(r, g, b, 192);
+    let stroke = tiny_skia::Stroke::default();
+    pixmap.stroke_path(&path, &paint, &stroke, tiny_skia::Transform::identity(), None);
+
+    Some(())
+}
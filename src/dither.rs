@@ -0,0 +1,191 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Dithered gradients rendering. See `RenderOptions::dither_gradients`.
+
+use usvg::TransformFromBBox;
+
+use crate::{ConvTransform, render::Canvas};
+
+/// A 4x4 Bayer matrix.
+const BAYER_MATRIX: [[u8; 4]; 4] = [
+    [ 0,  8,  2, 10],
+    [12,  4, 14,  6],
+    [ 3, 11,  1,  9],
+    [15,  7, 13,  5],
+];
+
+enum Kind {
+    Linear { x1: f64, y1: f64, dx: f64, dy: f64, len2: f64 },
+    Radial { fx: f64, fy: f64, dx: f64, dy: f64, a: f64 },
+}
+
+/// A gradient that is evaluated per pixel instead of using a `tiny_skia` shader,
+/// because the shader output is already quantized to 8 bits.
+pub struct Gradient {
+    kind: Kind,
+    spread_method: usvg::SpreadMethod,
+    // Offset and non-premultiplied RGBA in a 0..1 range.
+    stops: Vec<(f64, [f64; 4])>,
+    transform: usvg::Transform,
+}
+
+impl Gradient {
+    /// Returns `None` for gradients that can be rendered only by `tiny_skia`.
+    pub fn linear(g: &usvg::LinearGradient, opacity: usvg::Opacity, bbox: usvg::PathBbox) -> Option<Self> {
+        let (dx, dy) = (g.x2 - g.x1, g.y2 - g.y1);
+        let len2 = dx * dx + dy * dy;
+        if !len2.is_normal() {
+            return None;
+        }
+
+        let kind = Kind::Linear { x1: g.x1, y1: g.y1, dx, dy, len2 };
+        Self::new(kind, g, opacity, bbox)
+    }
+
+    /// Returns `None` for gradients that can be rendered only by `tiny_skia`.
+    pub fn radial(g: &usvg::RadialGradient, opacity: usvg::Opacity, bbox: usvg::PathBbox) -> Option<Self> {
+        let (dx, dy) = (g.cx - g.fx, g.cy - g.fy);
+        let r = g.r.get();
+        // Only a focal point inside the circle is supported.
+        let a = dx * dx + dy * dy - r * r;
+        if a >= 0.0 {
+            return None;
+        }
+
+        let kind = Kind::Radial { fx: g.fx, fy: g.fy, dx, dy, a };
+        Self::new(kind, g, opacity, bbox)
+    }
+
+    fn new(kind: Kind, g: &usvg::BaseGradient, opacity: usvg::Opacity, bbox: usvg::PathBbox) -> Option<Self> {
+        let transform = if g.units == usvg::Units::ObjectBoundingBox {
+            let mut ts = usvg::Transform::from_bbox(bbox.to_rect()?);
+            ts.append(&g.transform);
+            ts
+        } else {
+            g.transform
+        };
+
+        if g.stops.is_empty() {
+            return None;
+        }
+
+        let stops = g.stops.iter().map(|stop| {
+            let c = stop.color;
+            let alpha = stop.opacity.get() * opacity.get();
+            (stop.offset.get(), [c.red as f64 / 255.0, c.green as f64 / 255.0, c.blue as f64 / 255.0, alpha])
+        }).collect();
+
+        Some(Gradient {
+            kind,
+            spread_method: g.spread_method,
+            stops,
+            transform,
+        })
+    }
+
+    /// Draws the gradient with ordered dithering.
+    ///
+    /// `bounds` are the shape bounds in device coordinates.
+    /// `draw_coverage` must draw the shape onto the provided pixmap using the provided paint
+    /// and transform.
+    pub fn draw<F>(
+        &self,
+        paint: &tiny_skia::Paint,
+        bounds: tiny_skia::Rect,
+        canvas: &mut Canvas,
+        draw_coverage: F,
+    ) -> Option<()>
+        where F: FnOnce(&mut tiny_skia::PixmapMut, &tiny_skia::Paint, tiny_skia::Transform)
+    {
+        let mut ts = usvg::Transform::from_native(canvas.transform);
+        ts.append(&self.transform);
+        let ts = ts.invert()?;
+
+        // The layer covers only the shape.
+        let rect = canvas.layer_rect(bounds)?;
+        let width = rect.width();
+        let mut layer = tiny_skia::Pixmap::new(width, rect.height())?;
+
+        let coverage_paint = tiny_skia::Paint {
+            anti_alias: paint.anti_alias,
+            ..tiny_skia::Paint::default()
+        };
+        let layer_ts = canvas.transform.post_translate(-rect.x() as f32, -rect.y() as f32);
+        draw_coverage(&mut layer.as_mut(), &coverage_paint, layer_ts);
+
+        for (idx, pixel) in layer.pixels_mut().iter_mut().enumerate() {
+            let coverage = pixel.alpha();
+            if coverage == 0 {
+                continue;
+            }
+
+            // In canvas coordinates, so the dithering pattern doesn't depend on the layer position.
+            let x = rect.x() as u32 + idx as u32 % width;
+            let y = rect.y() as u32 + idx as u32 / width;
+            let (gx, gy) = ts.apply(x as f64 + 0.5, y as f64 + 0.5);
+            let c = self.color_at(gx, gy);
+
+            let threshold = (BAYER_MATRIX[y as usize % 4][x as usize % 4] as f64 + 0.5) / 16.0;
+            let quantize = |v: f64| (v * 255.0 + threshold).floor().clamp(0.0, 255.0) as u8;
+
+            let alpha = c[3] * coverage as f64 / 255.0;
+            let a = quantize(alpha);
+            let r = quantize(c[0] * alpha).min(a);
+            let g = quantize(c[1] * alpha).min(a);
+            let b = quantize(c[2] * alpha).min(a);
+            *pixel = tiny_skia::PremultipliedColorU8::from_rgba(r, g, b, a)?;
+        }
+
+        let pixmap_paint = tiny_skia::PixmapPaint {
+            blend_mode: paint.blend_mode,
+            ..tiny_skia::PixmapPaint::default()
+        };
+
+        canvas.pixmap.draw_pixmap(rect.x(), rect.y(), layer.as_ref(), &pixmap_paint,
+                                  tiny_skia::Transform::identity(), canvas.clip.as_ref());
+
+        Some(())
+    }
+
+    fn color_at(&self, x: f64, y: f64) -> [f64; 4] {
+        let t = match self.kind {
+            Kind::Linear { x1, y1, dx, dy, len2 } => {
+                ((x - x1) * dx + (y - y1) * dy) / len2
+            }
+            Kind::Radial { fx, fy, dx, dy, a } => {
+                // Solves |p - f - t * d| = t * r for the largest `t`.
+                let (px, py) = (x - fx, y - fy);
+                let b = px * dx + py * dy;
+                let c = px * px + py * py;
+                (b - (b * b - a * c).sqrt()) / a
+            }
+        };
+
+        let t = match self.spread_method {
+            usvg::SpreadMethod::Pad => t.clamp(0.0, 1.0),
+            usvg::SpreadMethod::Repeat => t - t.floor(),
+            usvg::SpreadMethod::Reflect => {
+                let t = t.rem_euclid(2.0);
+                if t > 1.0 { 2.0 - t } else { t }
+            }
+        };
+
+        let idx = match self.stops.iter().position(|(offset, _)| *offset >= t) {
+            Some(0) => return self.stops[0].1,
+            Some(idx) => idx,
+            None => return self.stops[self.stops.len() - 1].1,
+        };
+
+        let (offset1, c1) = self.stops[idx - 1];
+        let (offset2, c2) = self.stops[idx];
+        let k = (t - offset1) / (offset2 - offset1);
+        let mut c = [0.0; 4];
+        for i in 0..4 {
+            c[i] = c1[i] + (c2[i] - c1[i]) * k;
+        }
+
+        c
+    }
+}
@@ -495,14 +495,20 @@ fn calc_subregion(
 
     // TODO: Wrong! Does not account rotate and skew.
     let subregion = if filter.primitive_units == usvg::Units::ObjectBoundingBox {
-        let subregion_bbox = usvg::Rect::new(
-            primitive.x.unwrap_or(0.0),
-            primitive.y.unwrap_or(0.0),
-            primitive.width.unwrap_or(1.0),
-            primitive.height.unwrap_or(1.0),
-        ).ok_or(Error::InvalidRegion)?;
+        let bbox = bbox.ok_or(Error::InvalidRegion)?;
 
-        region.to_rect().bbox_transform(subregion_bbox)
+        // TODO: wrong
+        let ts_bbox = usvg::Rect::new(ts.e, ts.f, ts.a, ts.d).unwrap();
+        let bbox = bbox.bbox_transform(ts_bbox);
+
+        // Fractions are relative to the element bbox,
+        // while unset values fall back to the filter region.
+        usvg::Rect::new(
+            primitive.x.map(|n| bbox.x() + n * bbox.width()).unwrap_or(region.x() as f64),
+            primitive.y.map(|n| bbox.y() + n * bbox.height()).unwrap_or(region.y() as f64),
+            primitive.width.map(|n| n * bbox.width()).unwrap_or(region.width() as f64),
+            primitive.height.map(|n| n * bbox.height()).unwrap_or(region.height() as f64),
+        ).ok_or(Error::InvalidRegion)?
     } else {
         let (dx, dy) = ts.get_translate();
         let (sx, sy) = ts.get_scale();
@@ -669,15 +675,77 @@ fn apply_blur(
 
     let mut pixmap = input.into_color_space(cs)?.take()?;
 
-    if box_blur {
-        svgfilters::box_blur(std_dx, std_dy, into_svgfilters_image_mut!(pixmap));
+    if fe.edge_mode == usvg::filter::EdgeMode::None {
+        if box_blur {
+            svgfilters::box_blur(std_dx, std_dy, into_svgfilters_image_mut!(pixmap));
+        } else {
+            svgfilters::iir_blur(std_dx, std_dy, into_svgfilters_image_mut!(pixmap));
+        }
     } else {
-        svgfilters::iir_blur(std_dx, std_dy, into_svgfilters_image_mut!(pixmap));
+        // Extend the image according to the edge mode, blur it and then crop it back.
+        // 3 sigma is enough to cover the blur kernel.
+        let pad_x = (std_dx * 3.0).ceil() as u32;
+        let pad_y = (std_dy * 3.0).ceil() as u32;
+        let mut extended = extend_pixmap(&pixmap, pad_x, pad_y, fe.edge_mode)?;
+
+        if box_blur {
+            svgfilters::box_blur(std_dx, std_dy, into_svgfilters_image_mut!(extended));
+        } else {
+            svgfilters::iir_blur(std_dx, std_dy, into_svgfilters_image_mut!(extended));
+        }
+
+        let paint = tiny_skia::PixmapPaint {
+            blend_mode: tiny_skia::BlendMode::Source,
+            ..tiny_skia::PixmapPaint::default()
+        };
+
+        pixmap.draw_pixmap(-(pad_x as i32), -(pad_y as i32), extended.as_ref(), &paint,
+                           tiny_skia::Transform::identity(), None);
     }
 
     Ok(Image::from_image(pixmap, cs))
 }
 
+/// Extends the pixmap by the specified padding on each side,
+/// filling new pixels according to the edge mode.
+fn extend_pixmap(
+    pixmap: &tiny_skia::Pixmap,
+    pad_x: u32,
+    pad_y: u32,
+    edge_mode: usvg::filter::EdgeMode,
+) -> Result<tiny_skia::Pixmap, Error> {
+    let width = pixmap.width() as i32;
+    let height = pixmap.height() as i32;
+    let mut extended = tiny_skia::Pixmap::try_create(
+        pixmap.width() + pad_x * 2, pixmap.height() + pad_y * 2,
+    )?;
+
+    let map = |n: i32, size: i32| -> Option<i32> {
+        if n >= 0 && n < size {
+            return Some(n);
+        }
+
+        match edge_mode {
+            usvg::filter::EdgeMode::None => None,
+            usvg::filter::EdgeMode::Duplicate => Some(n.max(0).min(size - 1)),
+            usvg::filter::EdgeMode::Wrap => Some(n.rem_euclid(size)),
+        }
+    };
+
+    let src = pixmap.pixels();
+    let extended_width = extended.width() as i32;
+    let dst = extended.pixels_mut();
+    for (i, p) in dst.iter_mut().enumerate() {
+        let x = i as i32 % extended_width - pad_x as i32;
+        let y = i as i32 / extended_width - pad_y as i32;
+        if let (Some(x), Some(y)) = (map(x, width), map(y, height)) {
+            *p = src[(y * width + x) as usize];
+        }
+    }
+
+    Ok(extended)
+}
+
 fn apply_offset(
     fe: &usvg::filter::Offset,
     units: usvg::Units,
@@ -23,6 +23,12 @@ pub fn draw_kind(
         usvg::ImageKind::SVG(ref subtree) => {
             draw_svg(subtree, view_box, canvas);
         }
+        usvg::ImageKind::RGBA8(size, ref data) => {
+            match tiny_skia::PixmapRef::from_bytes(data, size.width(), size.height()) {
+                Some(pixmap) => { draw_pixmap(pixmap, view_box, rendering_mode, canvas); }
+                None => log::warn!("Decoded image data doesn't match its size."),
+            }
+        }
         #[cfg(feature = "raster-images")]
         usvg::ImageKind::JPEG(ref data) => {
             match raster_images::read_jpeg(data) {
@@ -44,6 +50,13 @@ pub fn draw_kind(
                 None => log::warn!("Failed to decode a GIF image."),
             }
         }
+        #[cfg(feature = "raster-images")]
+        usvg::ImageKind::TIFF(ref data) => {
+            match raster_images::read_tiff(data) {
+                Some(image) => { raster_images::draw_raster(&image, view_box, rendering_mode, canvas); }
+                None => log::warn!("Failed to decode a TIFF image."),
+            }
+        }
         #[cfg(not(feature = "raster-images"))]
         _ => {
             log::warn!("Images decoding was disabled by a build feature.");
@@ -88,6 +101,76 @@ fn draw_svg(
     Some(())
 }
 
+fn draw_pixmap(
+    pixmap: tiny_skia::PixmapRef,
+    view_box: usvg::ViewBox,
+    rendering_mode: usvg::ImageRendering,
+    canvas: &mut Canvas,
+) -> Option<()> {
+    let mut filter = tiny_skia::FilterQuality::Bicubic;
+    if rendering_mode == usvg::ImageRendering::OptimizeSpeed {
+        filter = tiny_skia::FilterQuality::Nearest;
+    }
+
+    let img_size = usvg::ScreenSize::new(pixmap.width(), pixmap.height())?;
+    let r = image_rect(&view_box, img_size);
+    let rect = tiny_skia::Rect::from_xywh(
+        r.x() as f32, r.y() as f32,
+        r.width() as f32, r.height() as f32,
+    )?;
+
+    let ts = tiny_skia::Transform::from_row(
+        rect.width() as f32 / pixmap.width() as f32,
+        0.0,
+        0.0,
+        rect.height() as f32 / pixmap.height() as f32,
+        r.x() as f32,
+        r.y() as f32,
+    );
+
+    let pattern = tiny_skia::Pattern::new(
+        pixmap,
+        tiny_skia::SpreadMode::Pad,
+        filter,
+        1.0,
+        ts,
+    );
+    let mut paint = tiny_skia::Paint::default();
+    paint.shader = pattern;
+
+    if view_box.aspect.slice {
+        let r = view_box.rect;
+        let rect = tiny_skia::Rect::from_xywh(
+            r.x() as f32, r.y() as f32,
+            r.width() as f32, r.height() as f32,
+        )?;
+
+        canvas.set_clip_rect(rect);
+    }
+
+    canvas.pixmap.fill_rect(rect, &paint, canvas.transform, canvas.clip.as_ref());
+    canvas.clip = None;
+
+    Some(())
+}
+
+/// Calculates an image rect depending on the provided view box.
+fn image_rect(
+    view_box: &usvg::ViewBox,
+    img_size: usvg::ScreenSize,
+) -> usvg::Rect {
+    let new_size = img_size.to_size().fit_view_box(view_box);
+    let (x, y) = usvg::utils::aligned_pos(
+        view_box.aspect.align,
+        view_box.rect.x(),
+        view_box.rect.y(),
+        view_box.rect.width() - new_size.width(),
+        view_box.rect.height() - new_size.height(),
+    );
+
+    new_size.to_rect(x, y)
+}
+
 #[cfg(feature = "raster-images")]
 mod raster_images {
     use crate::render::Canvas;
@@ -101,51 +184,7 @@ mod raster_images {
         let (w, h) = img.size.dimensions();
         let mut pixmap = tiny_skia::Pixmap::new(w, h)?;
         image_to_pixmap(img, pixmap.data_mut());
-
-        let mut filter = tiny_skia::FilterQuality::Bicubic;
-        if rendering_mode == usvg::ImageRendering::OptimizeSpeed {
-            filter = tiny_skia::FilterQuality::Nearest;
-        }
-
-        let r = image_rect(&view_box, img.size);
-        let rect = tiny_skia::Rect::from_xywh(
-            r.x() as f32, r.y() as f32,
-            r.width() as f32, r.height() as f32,
-        )?;
-
-        let ts = tiny_skia::Transform::from_row(
-            rect.width() as f32 / pixmap.width() as f32,
-            0.0,
-            0.0,
-            rect.height() as f32 / pixmap.height() as f32,
-            r.x() as f32,
-            r.y() as f32,
-        );
-
-        let pattern = tiny_skia::Pattern::new(
-            pixmap.as_ref(),
-            tiny_skia::SpreadMode::Pad,
-            filter,
-            1.0,
-            ts,
-        );
-        let mut paint = tiny_skia::Paint::default();
-        paint.shader = pattern;
-
-        if view_box.aspect.slice {
-            let r = view_box.rect;
-            let rect = tiny_skia::Rect::from_xywh(
-                r.x() as f32, r.y() as f32,
-                r.width() as f32, r.height() as f32,
-            )?;
-
-            canvas.set_clip_rect(rect);
-        }
-
-        canvas.pixmap.fill_rect(rect, &paint, canvas.transform, canvas.clip.as_ref());
-        canvas.clip = None;
-
-        Some(())
+        super::draw_pixmap(pixmap.as_ref(), view_box, rendering_mode, canvas)
     }
 
     fn image_to_pixmap(image: &Image, pixmap: &mut [u8]) {
@@ -187,6 +226,21 @@ mod raster_images {
         RGBA(Vec<u8>),
     }
 
+    impl ImageData {
+        fn len(&self) -> usize {
+            match self {
+                ImageData::RGB(data) | ImageData::RGBA(data) => data.len(),
+            }
+        }
+
+        fn channels(&self) -> usize {
+            match self {
+                ImageData::RGB(_) => 3,
+                ImageData::RGBA(_) => 4,
+            }
+        }
+    }
+
     pub fn read_png(data: &[u8]) -> Option<Image> {
         let mut decoder = png::Decoder::new(data);
         decoder.set_transformations(png::Transformations::normalize_to_color8());
@@ -276,20 +330,65 @@ mod raster_images {
         })
     }
 
-    /// Calculates an image rect depending on the provided view box.
-    fn image_rect(
-        view_box: &usvg::ViewBox,
-        img_size: usvg::ScreenSize,
-    ) -> usvg::Rect {
-        let new_size = img_size.to_size().fit_view_box(view_box);
-        let (x, y) = usvg::utils::aligned_pos(
-            view_box.aspect.align,
-            view_box.rect.x(),
-            view_box.rect.y(),
-            view_box.rect.width() - new_size.width(),
-            view_box.rect.height() - new_size.height(),
-        );
-
-        new_size.to_rect(x, y)
+    /// Decodes the first page of a TIFF image.
+    pub fn read_tiff(data: &[u8]) -> Option<Image> {
+        let mut decoder = tiff::decoder::Decoder::new(std::io::Cursor::new(data)).ok()?;
+        let (width, height) = decoder.dimensions().ok()?;
+        let size = usvg::ScreenSize::new(width, height)?;
+        let color_type = decoder.colortype().ok()?;
+
+        let img_data = match decoder.read_image().ok()? {
+            tiff::decoder::DecodingResult::U8(data) => data,
+            // TIFF samples are already gamma-encoded, so a linear rescaling
+            // keeps the same transfer function.
+            tiff::decoder::DecodingResult::U16(data) => {
+                data.iter().map(|v| ((*v as u32 * 255 + 32767) / 65535) as u8).collect()
+            }
+            _ => {
+                log::warn!("Only 8 and 16 bit TIFF images are supported.");
+                return None;
+            }
+        };
+
+        let data = match color_type {
+            tiff::ColorType::RGB(8) | tiff::ColorType::RGB(16) => ImageData::RGB(img_data),
+            tiff::ColorType::RGBA(8) | tiff::ColorType::RGBA(16) => ImageData::RGBA(img_data),
+            tiff::ColorType::Gray(8) | tiff::ColorType::Gray(16) => {
+                let mut rgb_data = Vec::with_capacity(img_data.len() * 3);
+                for gray in img_data {
+                    rgb_data.push(gray);
+                    rgb_data.push(gray);
+                    rgb_data.push(gray);
+                }
+
+                ImageData::RGB(rgb_data)
+            }
+            tiff::ColorType::GrayA(8) | tiff::ColorType::GrayA(16) => {
+                let mut rgba_data = Vec::with_capacity(img_data.len() * 2);
+                for slice in img_data.chunks(2) {
+                    let gray = slice[0];
+                    let alpha = slice[1];
+                    rgba_data.push(gray);
+                    rgba_data.push(gray);
+                    rgba_data.push(gray);
+                    rgba_data.push(alpha);
+                }
+
+                ImageData::RGBA(rgba_data)
+            }
+            _ => {
+                log::warn!("{:?} TIFF images are not supported.", color_type);
+                return None;
+            }
+        };
+
+        if data.len() != width as usize * height as usize * data.channels() {
+            return None;
+        }
+
+        Some(Image {
+            data,
+            size,
+        })
     }
 }
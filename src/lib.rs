@@ -14,18 +14,26 @@
 
 pub use usvg;
 pub use tiny_skia;
+pub use usvg::FitTo;
 
 use usvg::NodeExt;
 
 mod clip;
+mod complexity;
+#[cfg(feature = "debug-rendering")] mod debug;
+mod dither;
 #[cfg(feature = "filter")] mod filter;
 mod image;
 mod mask;
 mod paint_server;
 mod path;
 mod render;
+#[cfg(feature = "png")] pub mod stream;
 
+pub use crate::complexity::{ComplexityReport, analyze_complexity};
 pub use crate::render::trim_transparency;
+#[cfg(feature = "debug-rendering")]
+pub use crate::debug::{DebugOptions, render_debug};
 
 trait OptionLog {
     fn log_none<F: FnOnce()>(self, f: F) -> Self;
@@ -76,10 +84,176 @@ pub fn render(
     transform: tiny_skia::Transform,
     pixmap: tiny_skia::PixmapMut,
 ) -> Option<()> {
+    render_with_hook(tree, fit_to, transform, None, pixmap, |_| {})
+}
+
+// The common part of all `render` functions.
+//
+// `after_render` is called after the tree has been rendered.
+// At this point, the canvas transform includes the root and the view box transforms.
+pub(crate) fn render_with_hook<'a, F>(
+    tree: &usvg::Tree,
+    fit_to: usvg::FitTo,
+    transform: tiny_skia::Transform,
+    options: Option<&'a RenderOptions>,
+    pixmap: tiny_skia::PixmapMut<'a>,
+    after_render: F,
+) -> Option<()>
+    where F: FnOnce(&mut render::Canvas<'a>)
+{
     let size = fit_to.fit_to(tree.svg_node().size.to_screen_size())?;
     let mut canvas = render::Canvas::from(pixmap);
     canvas.apply_transform(transform);
+    canvas.options = options;
     render::render_to_canvas(tree, size, &mut canvas);
+    after_render(&mut canvas);
+    Some(())
+}
+
+/// Returns the pixmap size required to render an SVG using `fit_to`.
+///
+/// Returns `None` when the resulting size is zero.
+pub fn fit_to_size(tree: &usvg::Tree, fit_to: usvg::FitTo) -> Option<usvg::ScreenSize> {
+    fit_to.fit_to(tree.svg_node().size.to_screen_size())
+}
+
+/// Renders an SVG to a new pixmap.
+///
+/// The same as `render`, but the pixmap will be allocated automatically
+/// using the size returned by `fit_to_size`.
+pub fn render_fit(tree: &usvg::Tree, fit_to: usvg::FitTo) -> Option<tiny_skia::Pixmap> {
+    let size = fit_to_size(tree, fit_to)?;
+    let mut pixmap = tiny_skia::Pixmap::new(size.width(), size.height())?;
+    render(tree, fit_to, tiny_skia::Transform::default(), pixmap.as_mut())?;
+    Some(pixmap)
+}
+
+/// Renders an SVG directly into an RGBA8 buffer.
+///
+/// The same as `render` with `FitTo::Original`, but renders into an existing buffer,
+/// like a `pixels` crate frame, without an intermediate pixmap.
+///
+/// `data` must be exactly `width * height * 4` bytes long. Rows are tightly packed,
+/// so the stride is `width * 4`, and there are no alignment requirements.
+/// The existing content is preserved and the SVG is drawn on top of it.
+///
+/// Colors are stored with premultiplied alpha, like in `tiny_skia::Pixmap`.
+/// This is the same as straight alpha for opaque pixels, which is usually the case
+/// for a window framebuffer.
+///
+/// Returns `None` when the buffer size doesn't match or when `width` or `height` is zero.
+pub fn render_into_slice(
+    tree: &usvg::Tree,
+    transform: tiny_skia::Transform,
+    width: u32,
+    height: u32,
+    data: &mut [u8],
+) -> Option<()> {
+    let pixmap = tiny_skia::PixmapMut::from_bytes(data, width, height)?;
+    render(tree, usvg::FitTo::Original, transform, pixmap)
+}
+
+/// Additional rendering options.
+///
+/// See `render_with_options`.
+#[derive(Default)]
+pub struct RenderOptions {
+    /// A callback that will be called after each top-level element has been rendered.
+    ///
+    /// Can be used to implement a progress bar.
+    pub progress: Option<Box<dyn Fn(ProgressEvent)>>,
+
+    /// A callback that can override a transform of an element with a non-empty ID.
+    ///
+    /// Receives an element ID and its absolute transform in user space,
+    /// as returned by `NodeExt::abs_transform`, and must return a new absolute transform.
+    /// Affects the element children as well, but they will still receive
+    /// the original, unaffected transforms.
+    ///
+    /// Can be used to animate elements without modifying the tree.
+    /// Note that `usvg` keeps group IDs only when `Options::keep_named_groups` is set.
+    pub transform_hook: Option<Box<TransformHook>>,
+
+    /// Enables ordered dithering of linear and radial gradients.
+    ///
+    /// Reduces color banding in large smooth gradients at the cost of a small amount of noise.
+    /// Gradients are evaluated per pixel in this mode, which is noticeably slower.
+    /// Radial gradients with a focal point outside the circle are not dithered.
+    pub dither_gradients: bool,
+}
+
+/// A `RenderOptions::transform_hook` callback type.
+pub type TransformHook = dyn Fn(&str, usvg::Transform) -> usvg::Transform;
+
+/// A rendering progress.
+///
+/// See `RenderOptions::progress`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ProgressEvent {
+    /// The number of already rendered nodes, including nested ones.
+    pub nodes_rendered: u32,
+
+    /// The total number of nodes in the tree, excluding the root one.
+    pub total_nodes: u32,
+
+    /// The ID of the just rendered top-level element.
+    ///
+    /// `None` when the element has no ID.
+    pub current_node_id: Option<String>,
+}
+
+/// Renders an SVG to pixmap using additional options.
+///
+/// The same as `render`, but allows to control the rendering process via `options`.
+pub fn render_with_options(
+    tree: &usvg::Tree,
+    fit_to: usvg::FitTo,
+    transform: tiny_skia::Transform,
+    options: &RenderOptions,
+    pixmap: tiny_skia::PixmapMut,
+) -> Option<()> {
+    render_with_hook(tree, fit_to, transform, Some(options), pixmap, |_| {})
+}
+
+/// Renders an SVG to pixmap at the specified pixel offset.
+///
+/// The same as `render`, but the image will be placed at `x`, `y`
+/// and clipped by the `pixmap` bounds.
+/// Can be used to compose multiple SVGs into a single pixmap.
+pub fn render_at_offset(
+    tree: &usvg::Tree,
+    fit_to: usvg::FitTo,
+    x: i32,
+    y: i32,
+    pixmap: tiny_skia::PixmapMut,
+) -> Option<()> {
+    let transform = tiny_skia::Transform::from_translate(x as f32, y as f32);
+    render(tree, fit_to, transform, pixmap)
+}
+
+/// Renders a region of an SVG to pixmap.
+///
+/// `rect` is in SVG user units and will be stretched to fill the whole `pixmap`.
+/// Everything outside of `rect` will be cropped.
+///
+/// Useful for thumbnails and tile-based rendering.
+pub fn render_viewport(
+    tree: &usvg::Tree,
+    rect: usvg::Rect,
+    pixmap: tiny_skia::PixmapMut,
+) -> Option<()> {
+    let view_box = usvg::ViewBox {
+        rect,
+        aspect: usvg::AspectRatio {
+            defer: false,
+            align: usvg::Align::None,
+            slice: false,
+        },
+    };
+
+    let size = usvg::ScreenSize::new(pixmap.width(), pixmap.height())?;
+    let mut canvas = render::Canvas::from(pixmap);
+    render::render_node_to_canvas(tree, &tree.root(), view_box, size, &mut render::RenderState::Ok, &mut canvas);
     Some(())
 }
 
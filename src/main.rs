@@ -118,6 +118,10 @@ OPTIONS:
   --image-rendering HINT        Selects the default image rendering method
                                 [default: optimizeQuality]
                                 [possible values: optimizeQuality, optimizeSpeed]
+  --profile PROFILE             Skips elements and attributes that are not
+                                a part of the specified SVG profile
+                                [default: svg-2]
+                                [possible values: svg-1.1, svg-tiny-1.2, svg-2]
   --resources-dir DIR           Sets a directory that will be used during
                                 relative paths resolving.
                                 Expected to be the same as the directory that
@@ -183,6 +187,7 @@ struct CliArgs {
     shape_rendering: usvg::ShapeRendering,
     text_rendering: usvg::TextRendering,
     image_rendering: usvg::ImageRendering,
+    profile: usvg::SvgProfile,
     resources_dir: Option<path::PathBuf>,
 
     font_family: Option<String>,
@@ -236,6 +241,7 @@ fn collect_args() -> Result<CliArgs, pico_args::Error> {
         shape_rendering:    input.opt_value_from_str("--shape-rendering")?.unwrap_or_default(),
         text_rendering:     input.opt_value_from_str("--text-rendering")?.unwrap_or_default(),
         image_rendering:    input.opt_value_from_str("--image-rendering")?.unwrap_or_default(),
+        profile:            input.opt_value_from_str("--profile")?.unwrap_or_default(),
         resources_dir:      input.opt_value_from_str("--resources-dir").unwrap_or_default(),
 
         font_family:        input.opt_value_from_str("--font-family")?,
@@ -410,7 +416,12 @@ fn parse_args() -> Result<Args, String> {
         keep_named_groups,
         default_size,
         fontdb,
-        image_href_resolver: usvg::ImageHrefResolver::default()
+        image_href_resolver: usvg::ImageHrefResolver::default(),
+        resource_loader: None,
+        html_mode: false,
+        duplicate_id_handling: usvg::DuplicateIdHandling::default(),
+        fix_invalid_xml: false,
+        profile: args.profile,
     };
 
     Ok(Args {
@@ -16,6 +16,8 @@ pub fn fill(
     canvas: &mut Canvas,
 ) -> Option<()> {
     let pattern_pixmap;
+    let mut dithered = None;
+    let dither = canvas.options.map(|opt| opt.dither_gradients).unwrap_or(false);
 
     let mut paint = tiny_skia::Paint::default();
 
@@ -29,9 +31,15 @@ pub fn fill(
                 match *node.borrow() {
                     usvg::NodeKind::LinearGradient(ref lg) => {
                         prepare_linear(lg, opacity, bbox, &mut paint);
+                        if dither {
+                            dithered = crate::dither::Gradient::linear(lg, opacity, bbox);
+                        }
                     }
                     usvg::NodeKind::RadialGradient(ref rg) => {
                         prepare_radial(rg, opacity, bbox, &mut paint);
+                        if dither {
+                            dithered = crate::dither::Gradient::radial(rg, opacity, bbox);
+                        }
                     }
                     usvg::NodeKind::Pattern(ref pattern) => {
                         let global_ts = usvg::Transform::from_native(canvas.transform);
@@ -41,6 +49,14 @@ pub fn fill(
                         pattern_pixmap = patt_pix;
                         paint.shader = prepare_pattern(&pattern_pixmap, patt_ts, opacity);
                     }
+                    usvg::NodeKind::Hatch(ref hatch) => {
+                        let global_ts = usvg::Transform::from_native(canvas.transform);
+                        let (patt_pix, patt_ts)
+                            = prepare_hatch_pixmap(tree, &node, hatch, &global_ts, bbox)?;
+
+                        pattern_pixmap = patt_pix;
+                        paint.shader = prepare_pattern(&pattern_pixmap, patt_ts, opacity);
+                    }
                     _ => {}
                 }
             }
@@ -56,6 +72,13 @@ pub fn fill(
         tiny_skia::FillRule::EvenOdd
     };
 
+    if let Some(gradient) = dithered {
+        let bounds = path.clone().transform(canvas.transform)?.bounds();
+        return gradient.draw(&paint, bounds, canvas, |pixmap, paint, ts| {
+            pixmap.fill_path(path, paint, rule, ts, None);
+        });
+    }
+
     canvas.pixmap.fill_path(path, &paint, rule, canvas.transform, canvas.clip.as_ref());
 
     Some(())
@@ -71,6 +94,8 @@ pub fn stroke(
     canvas: &mut Canvas,
 ) -> Option<()> {
     let pattern_pixmap;
+    let mut dithered = None;
+    let dither = canvas.options.map(|opt| opt.dither_gradients).unwrap_or(false);
 
     let mut paint = tiny_skia::Paint::default();
     let mut props = tiny_skia::Stroke::default();
@@ -86,9 +111,15 @@ pub fn stroke(
                     match *node.borrow() {
                         usvg::NodeKind::LinearGradient(ref lg) => {
                             prepare_linear(lg, opacity, bbox, &mut paint);
+                            if dither {
+                                dithered = crate::dither::Gradient::linear(lg, opacity, bbox);
+                            }
                         }
                         usvg::NodeKind::RadialGradient(ref rg) => {
                             prepare_radial(rg, opacity, bbox, &mut paint);
+                            if dither {
+                                dithered = crate::dither::Gradient::radial(rg, opacity, bbox);
+                            }
                         }
                         usvg::NodeKind::Pattern(ref pattern) => {
                             let global_ts = usvg::Transform::from_native(canvas.transform);
@@ -98,6 +129,14 @@ pub fn stroke(
                             pattern_pixmap = patt_pix;
                             paint.shader = prepare_pattern(&pattern_pixmap, patt_ts, opacity);
                         }
+                        usvg::NodeKind::Hatch(ref hatch) => {
+                            let global_ts = usvg::Transform::from_native(canvas.transform);
+                            let (patt_pix, patt_ts)
+                                = prepare_hatch_pixmap(tree, &node, hatch, &global_ts, bbox)?;
+
+                            pattern_pixmap = patt_pix;
+                            paint.shader = prepare_pattern(&pattern_pixmap, patt_ts, opacity);
+                        }
                         _ => {}
                     }
                 }
@@ -130,11 +169,42 @@ pub fn stroke(
     paint.anti_alias = anti_alias;
     paint.blend_mode = blend_mode;
 
+    if let Some(gradient) = dithered {
+        let bounds = stroke_bounds(path, &props, canvas.transform)?;
+        return gradient.draw(&paint, bounds, canvas, |pixmap, paint, ts| {
+            pixmap.stroke_path(path, paint, &props, ts, None);
+        });
+    }
+
     canvas.pixmap.stroke_path(path, &paint, &props, canvas.transform, canvas.clip.as_ref());
 
     Some(())
 }
 
+/// Returns the stroke bounds in device coordinates.
+///
+/// It's an approximation that is large enough for the longest miter join or square cap.
+pub fn stroke_bounds(
+    path: &tiny_skia::Path,
+    props: &tiny_skia::Stroke,
+    ts: tiny_skia::Transform,
+) -> Option<tiny_skia::Rect> {
+    let join = if props.line_join == tiny_skia::LineJoin::Miter {
+        props.miter_limit.max(std::f32::consts::SQRT_2)
+    } else {
+        std::f32::consts::SQRT_2
+    };
+
+    // An upper bound of the transform scale in any direction.
+    let scale = (ts.sx * ts.sx + ts.kx * ts.kx + ts.ky * ts.ky + ts.sy * ts.sy).sqrt();
+    let outset = props.width / 2.0 * join * scale;
+
+    let rect = path.clone().transform(ts)?.bounds();
+    tiny_skia::Rect::from_ltrb(
+        rect.left() - outset, rect.top() - outset, rect.right() + outset, rect.bottom() + outset,
+    )
+}
+
 fn prepare_linear(
     g: &usvg::LinearGradient,
     opacity: usvg::Opacity,
@@ -253,6 +323,11 @@ fn prepare_pattern_pixmap(
     let (sx, sy) = ts2.get_scale();
 
     let img_size = usvg::Size::new(r.width() * sx, r.height() * sy)?.to_screen_size();
+    // The tile size is rounded to whole pixels, so the scale has to be adjusted accordingly.
+    // Otherwise the tile content will be repeated with a wrong period
+    // and will not be clipped by the tile bounds.
+    let sx = img_size.width() as f64 / r.width();
+    let sy = img_size.height() as f64 / r.height();
     let mut pixmap = tiny_skia::Pixmap::new(img_size.width(), img_size.height())?;
     let mut canvas = Canvas::from(pixmap.as_mut());
 
@@ -278,6 +353,106 @@ fn prepare_pattern_pixmap(
     Some((pixmap, ts))
 }
 
+/// The maximum number of hatch content copies along the y axis.
+const MAX_HATCH_COPIES: f64 = 1000.0;
+
+/// The maximum number of pixels rendered into a single hatch tile, including overdraw.
+const MAX_HATCH_PIXELS: f64 = 64_000_000.0;
+
+fn prepare_hatch_pixmap(
+    tree: &usvg::Tree,
+    hatch_node: &usvg::Node,
+    hatch: &usvg::Hatch,
+    global_ts: &usvg::Transform,
+    bbox: usvg::PathBbox,
+) -> Option<(tiny_skia::Pixmap, usvg::Transform)> {
+    let bbox_rect = || bbox.to_rect()
+        .log_none(|| log::warn!("Hatch on zero-sized shapes is not allowed."));
+
+    let (x, y, pitch) = if hatch.units == usvg::Units::ObjectBoundingBox {
+        let bbox = bbox_rect()?;
+        (bbox.x() + hatch.x * bbox.width(),
+         bbox.y() + hatch.y * bbox.height(),
+         hatch.pitch.get() * bbox.width())
+    } else {
+        (hatch.x, hatch.y, hatch.pitch.get())
+    };
+
+    let (content_sx, content_sy) = if hatch.content_units == usvg::Units::ObjectBoundingBox {
+        let bbox = bbox_rect()?;
+        (bbox.width(), bbox.height())
+    } else {
+        (1.0, 1.0)
+    };
+
+    let mut ts2 = *global_ts;
+    ts2.append(&hatch.transform);
+    let (sx, sy) = ts2.get_scale();
+
+    // The tile is infinite along the y axis, so we can use any multiple of the period.
+    // Make it at least as tall as it is wide to keep the pixmap reasonably sized.
+    //
+    // Each period must start at a whole pixel row, otherwise the anti-aliased ends
+    // of the neighbor copies will produce visible seams.
+    let period = hatch.period.get();
+    let period_px = (period * content_sy * sy).round().max(1.0);
+    let sy = period_px / (period * content_sy);
+    let period_count = (pitch * sx / period_px).ceil().clamp(1.0, 1000.0);
+    let height = period * content_sy * period_count;
+
+    let img_size = usvg::Size::new(pitch * sx, height * sy)?.to_screen_size();
+    let sx = img_size.width() as f64 / pitch;
+    let mut pixmap = tiny_skia::Pixmap::new(img_size.width(), img_size.height())?;
+    let mut canvas = Canvas::from(pixmap.as_mut());
+    canvas.scale(sx as f32, sy as f32);
+    canvas.scale(content_sx as f32, content_sy as f32);
+
+    // Paths can cross the tile boundaries, so we have to render parts of the neighbor tiles too.
+    let mut content_bbox = usvg::PathBbox::new_bbox();
+    for child in hatch_node.children() {
+        if let usvg::NodeKind::Path(ref path) = *child.borrow() {
+            if let Some(path_bbox) = path.data.bbox() {
+                content_bbox = content_bbox.expand(path_bbox);
+            }
+        }
+    }
+
+    // Only the copies that intersect the tile are rendered.
+    let first = ((-content_bbox.bottom()) / period).floor();
+    let last = ((period * period_count - content_bbox.y()) / period).ceil();
+    if !first.is_finite() || !last.is_finite() {
+        log::warn!("Hatch content is too large.");
+        return None;
+    }
+
+    // Each copy is rendered three times, so limit the total number of rendered pixels.
+    let tile_pixels = img_size.width() as f64 * img_size.height() as f64;
+    let max_copies = (MAX_HATCH_PIXELS / (3.0 * tile_pixels)).clamp(1.0, MAX_HATCH_COPIES);
+    let copies = last - first + 1.0;
+    if copies > max_copies {
+        log::warn!("Hatch requires {} content copies, only {} will be rendered.", copies, max_copies);
+    }
+    let copies = copies.min(max_copies) as u32;
+
+    let pitch_in_content = pitch / content_sx;
+    let content_ts = canvas.transform;
+    for dx in &[-pitch_in_content, 0.0, pitch_in_content] {
+        for i in 0..copies {
+            canvas.transform = content_ts;
+            canvas.translate(*dx as f32, ((first + i as f64) * period) as f32);
+            crate::render::render_group(tree, hatch_node, &mut RenderState::Ok, &mut canvas);
+        }
+    }
+
+    let mut ts = usvg::Transform::default();
+    ts.append(&hatch.transform);
+    ts.translate(x, y);
+    ts.rotate(hatch.rotate);
+    ts.scale(1.0 / sx, 1.0 / sy);
+
+    Some((pixmap, ts))
+}
+
 fn prepare_pattern(
     pixmap: &tiny_skia::Pixmap,
     ts: usvg::Transform,
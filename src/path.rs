@@ -24,15 +24,125 @@ pub fn draw(
 
     let antialias = path.rendering_mode.use_shape_antialiasing();
 
+    if path.paint_order == usvg::PaintOrder::StrokeAndFill {
+        draw_stroke(tree, path, style_bbox, &skia_path, antialias, blend_mode, canvas);
+        draw_fill(tree, path, style_bbox, &skia_path, antialias, blend_mode, canvas);
+    } else {
+        draw_fill(tree, path, style_bbox, &skia_path, antialias, blend_mode, canvas);
+        draw_stroke(tree, path, style_bbox, &skia_path, antialias, blend_mode, canvas);
+    }
+
+    bbox
+}
+
+fn draw_fill(
+    tree: &usvg::Tree,
+    path: &usvg::Path,
+    bbox: usvg::PathBbox,
+    skia_path: &tiny_skia::Path,
+    anti_alias: bool,
+    blend_mode: tiny_skia::BlendMode,
+    canvas: &mut Canvas,
+) {
     if let Some(ref fill) = path.fill {
-        crate::paint_server::fill(tree, fill, style_bbox, &skia_path, antialias, blend_mode, canvas);
+        crate::paint_server::fill(tree, fill, bbox, skia_path, anti_alias, blend_mode, canvas);
+    }
+}
+
+fn draw_stroke(
+    tree: &usvg::Tree,
+    path: &usvg::Path,
+    bbox: usvg::PathBbox,
+    skia_path: &tiny_skia::Path,
+    anti_alias: bool,
+    blend_mode: tiny_skia::BlendMode,
+    canvas: &mut Canvas,
+) {
+    if let Some(ref stroke) = path.stroke {
+        if stroke.alignment == usvg::StrokeAlignment::Center {
+            crate::paint_server::stroke(tree, &path.stroke, bbox, skia_path, anti_alias, blend_mode, canvas);
+        } else {
+            let fill_rule = path.fill.as_ref().map(|f| f.rule).unwrap_or_default();
+            draw_aligned_stroke(tree, stroke, fill_rule, bbox, skia_path, anti_alias, blend_mode, canvas);
+        }
+    }
+}
+
+// An inner/outer stroke is rendered as a stroke with a double width
+// that is clipped by the path fill area or by its exterior.
+fn draw_aligned_stroke(
+    tree: &usvg::Tree,
+    stroke: &usvg::Stroke,
+    fill_rule: usvg::FillRule,
+    bbox: usvg::PathBbox,
+    path: &tiny_skia::Path,
+    anti_alias: bool,
+    blend_mode: tiny_skia::BlendMode,
+    canvas: &mut Canvas,
+) -> Option<()> {
+    let rule = if fill_rule == usvg::FillRule::NonZero {
+        tiny_skia::FillRule::Winding
+    } else {
+        tiny_skia::FillRule::EvenOdd
+    };
+
+    let is_inner = stroke.alignment == usvg::StrokeAlignment::Inner;
+
+    let mut stroke = stroke.clone();
+    stroke.width = usvg::StrokeWidth::new(stroke.width.get() * 2.0)?;
+
+    // The layer only has to cover the stroke. An inner stroke stays inside the fill area.
+    let bounds = if is_inner {
+        path.clone().transform(canvas.transform)?.bounds()
+    } else {
+        let props = tiny_skia::Stroke {
+            width: stroke.width.get() as f32,
+            miter_limit: stroke.miterlimit.get() as f32,
+            line_join: if stroke.linejoin == usvg::LineJoin::Miter {
+                tiny_skia::LineJoin::Miter
+            } else {
+                tiny_skia::LineJoin::Round
+            },
+            ..tiny_skia::Stroke::default()
+        };
+        crate::paint_server::stroke_bounds(path, &props, canvas.transform)?
+    };
+    let rect = canvas.layer_rect(bounds)?;
+
+    let mut sub_pixmap = tiny_skia::Pixmap::new(rect.width(), rect.height())?;
+    let mut sub_canvas = Canvas::from(sub_pixmap.as_mut());
+    sub_canvas.transform = canvas.transform.post_translate(-rect.x() as f32, -rect.y() as f32);
+
+    if is_inner {
+        let mut clip = tiny_skia::ClipMask::new();
+        clip.set_path(sub_canvas.pixmap.width(), sub_canvas.pixmap.height(),
+                      &path.clone().transform(sub_canvas.transform)?, rule, anti_alias);
+        sub_canvas.clip = Some(clip);
     }
 
-    if path.stroke.is_some() {
-        crate::paint_server::stroke(tree, &path.stroke, style_bbox, &skia_path, antialias, blend_mode, canvas);
+    crate::paint_server::stroke(
+        tree, &Some(stroke), bbox, path, anti_alias, tiny_skia::BlendMode::SourceOver, &mut sub_canvas,
+    );
+
+    if !is_inner {
+        // Remove everything inside the fill area.
+        let paint = tiny_skia::Paint {
+            anti_alias,
+            blend_mode: tiny_skia::BlendMode::DestinationOut,
+            ..tiny_skia::Paint::default()
+        };
+        sub_canvas.pixmap.fill_path(path, &paint, rule, sub_canvas.transform, None);
     }
 
-    bbox
+    let paint = tiny_skia::PixmapPaint {
+        blend_mode,
+        ..tiny_skia::PixmapPaint::default()
+    };
+
+    canvas.pixmap.draw_pixmap(rect.x(), rect.y(), sub_pixmap.as_ref(), &paint,
+                              tiny_skia::Transform::identity(), canvas.clip.as_ref());
+
+    Some(())
 }
 
 fn convert_path(
@@ -12,6 +12,11 @@ pub struct Canvas<'a> {
     pub pixmap: tiny_skia::PixmapMut<'a>,
     pub transform: tiny_skia::Transform,
     pub clip: Option<tiny_skia::ClipMask>,
+    /// Caller provided options.
+    ///
+    /// Set only for the main canvas and its groups.
+    /// Clip paths, masks, patterns and filters do not have access to it.
+    pub options: Option<&'a crate::RenderOptions>,
 }
 
 impl<'a> From<tiny_skia::PixmapMut<'a>> for Canvas<'a> {
@@ -20,6 +25,7 @@ impl<'a> From<tiny_skia::PixmapMut<'a>> for Canvas<'a> {
             pixmap,
             transform: tiny_skia::Transform::identity(),
             clip: None,
+            options: None,
         }
     }
 }
@@ -46,6 +52,21 @@ impl Canvas<'_> {
             self.clip = Some(clip);
         }
     }
+
+    /// Returns the canvas area covered by `bounds`, which are in device coordinates.
+    ///
+    /// Adds a pixel on each side for anti-aliasing.
+    pub fn layer_rect(&self, bounds: tiny_skia::Rect) -> Option<usvg::ScreenRect> {
+        let x = (bounds.left().floor() as i32 - 1).max(0);
+        let y = (bounds.top().floor() as i32 - 1).max(0);
+        let right = (bounds.right().ceil() as i32 + 1).min(self.pixmap.width() as i32);
+        let bottom = (bounds.bottom().ceil() as i32 + 1).min(self.pixmap.height() as i32);
+        if right <= x || bottom <= y {
+            return None;
+        }
+
+        usvg::ScreenRect::new(x, y, (right - x) as u32, (bottom - y) as u32)
+    }
 }
 
 
@@ -131,6 +152,20 @@ pub(crate) fn render_group(
     let curr_ts = canvas.transform;
     let mut g_bbox = usvg::PathBbox::new_bbox();
 
+    // Progress is reported only for the top-level elements of the main tree.
+    let progress = match canvas.options {
+        Some(opt) if parent.parent().is_none() => opt.progress.as_ref(),
+        _ => None,
+    };
+
+    // `defs` is not rendered, so it's not counted.
+    let is_defs = |node: &usvg::Node| matches!(*node.borrow(), usvg::NodeKind::Defs);
+    let total_nodes = match progress {
+        Some(_) => parent.children().filter(|n| !is_defs(n)).map(|n| n.descendants().count() as u32).sum(),
+        None => 0,
+    };
+    let mut nodes_rendered = 0;
+
     for node in parent.children() {
         match state {
             RenderState::Ok => {}
@@ -144,17 +179,31 @@ pub(crate) fn render_group(
             RenderState::BackgroundFinished => break,
         }
 
-        canvas.apply_transform(node.transform().to_native());
+        let ts = node_transform(parent, &node, canvas.options);
+        canvas.apply_transform(ts.to_native());
 
         let bbox = render_node(tree, &node, state, canvas);
         if let Some(bbox) = bbox {
-            if let Some(bbox) = bbox.transform(&node.transform()) {
+            if let Some(bbox) = bbox.transform(&ts) {
                 g_bbox = g_bbox.expand(bbox);
             }
         }
 
         // Revert transform.
         canvas.transform = curr_ts;
+
+        match progress {
+            Some(progress) if !is_defs(&node) => {
+                nodes_rendered += node.descendants().count() as u32;
+                let id = node.id();
+                progress(crate::ProgressEvent {
+                    nodes_rendered,
+                    total_nodes,
+                    current_node_id: if id.is_empty() { None } else { Some(id.to_string()) },
+                });
+            }
+            _ => {}
+        }
     }
 
     // Check that bbox was changed, otherwise we will have a rect with x/y set to f64::MAX.
@@ -165,6 +214,38 @@ pub(crate) fn render_group(
     }
 }
 
+/// Returns a node transform, which can be overridden via `RenderOptions::transform_hook`.
+fn node_transform(
+    parent: &usvg::Node,
+    node: &usvg::Node,
+    options: Option<&crate::RenderOptions>,
+) -> usvg::Transform {
+    let ts = node.transform();
+    let hook = match options.and_then(|opt| opt.transform_hook.as_ref()) {
+        Some(v) => v,
+        None => return ts,
+    };
+
+    let id = node.id();
+    if id.is_empty() {
+        return ts;
+    }
+
+    let parent_ts = parent.abs_transform();
+    let mut abs_ts = parent_ts;
+    abs_ts.append(&ts);
+    let new_abs_ts = hook(&id, abs_ts);
+
+    // The hook returns an absolute transform, while we need a relative one.
+    match parent_ts.invert() {
+        Some(mut new_ts) => {
+            new_ts.append(&new_abs_ts);
+            new_ts
+        }
+        None => ts,
+    }
+}
+
 fn render_group_impl(
     tree: &usvg::Tree,
     node: &usvg::Node,
@@ -178,9 +259,16 @@ fn render_group_impl(
     let bbox = {
         let mut sub_canvas = Canvas::from(sub_pixmap.as_mut());
         sub_canvas.transform = curr_ts;
+        sub_canvas.options = canvas.options;
         render_group(tree, node, state, &mut sub_canvas)
     };
 
+    // The background should be rendered before the children,
+    // but we know the bbox only after they were rendered.
+    if let (Some(color), Some(bbox)) = (g.background_color, bbox) {
+        render_background(color, bbox, curr_ts, &mut sub_pixmap);
+    }
+
     // At this point, `sub_pixmap` has probably the same size as the viewbox.
     // So instead of clipping, masking and blending the whole viewbox, which can be very expensive,
     // we're trying to reduce `sub_pixmap` to it's actual content trimming
@@ -266,6 +354,25 @@ fn render_group_impl(
     bbox
 }
 
+fn render_background(
+    color: usvg::Color,
+    bbox: usvg::PathBbox,
+    ts: tiny_skia::Transform,
+    pixmap: &mut tiny_skia::Pixmap,
+) -> Option<()> {
+    let rect = tiny_skia::Rect::from_xywh(
+        bbox.x() as f32, bbox.y() as f32, bbox.width() as f32, bbox.height() as f32,
+    )?;
+
+    let mut paint = tiny_skia::Paint::default();
+    paint.set_color_rgba8(color.red, color.green, color.blue, 255);
+    paint.anti_alias = true;
+    // Render under the already rendered children.
+    paint.blend_mode = tiny_skia::BlendMode::DestinationOver;
+
+    pixmap.fill_rect(rect, &paint, ts, None)
+}
+
 /// Removes transparent borders from the image leaving only a tight bbox content.
 ///
 /// Detects graphics element bbox on the raster images in absolute coordinates.
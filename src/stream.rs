@@ -0,0 +1,114 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! SVG to PNG conversion between I/O streams.
+//!
+//! Requires the `png` feature, which is enabled by `raster-images`.
+
+use std::io::{Read, Write};
+
+/// List of all streaming errors.
+#[derive(Debug)]
+pub enum Error {
+    /// Failed to read an SVG or to write a PNG.
+    Io(std::io::Error),
+
+    /// Failed to parse an SVG.
+    Parsing(usvg::Error),
+
+    /// The target image size is zero or too big.
+    InvalidSize,
+
+    /// Failed to encode a PNG.
+    Encoding(png::EncodingError),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<usvg::Error> for Error {
+    fn from(e: usvg::Error) -> Self {
+        Error::Parsing(e)
+    }
+}
+
+impl From<png::EncodingError> for Error {
+    fn from(e: png::EncodingError) -> Self {
+        Error::Encoding(e)
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            Error::Io(ref e) => {
+                write!(f, "I/O operation failed cause {}", e)
+            }
+            Error::Parsing(ref e) => {
+                write!(f, "{}", e)
+            }
+            Error::InvalidSize => {
+                write!(f, "target image size is invalid")
+            }
+            Error::Encoding(ref e) => {
+                write!(f, "PNG encoding failed cause {}", e)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Reads an SVG from `reader`, renders it and writes a PNG to `writer`.
+///
+/// Supports SVGZ as well.
+///
+/// The SVG data is still read into memory as a whole, because the XML parser requires it.
+/// But the PNG is encoded row by row directly from the render target,
+/// without encoding the whole image into an intermediate buffer first.
+///
+/// Neither `reader` nor `writer` are buffered, so wrapping them in
+/// `BufReader`/`BufWriter` is recommended.
+pub fn render<R: Read, W: Write>(
+    mut reader: R,
+    writer: W,
+    opt: &usvg::OptionsRef,
+    fit_to: usvg::FitTo,
+) -> Result<(), Error> {
+    let mut data = Vec::new();
+    reader.read_to_end(&mut data)?;
+    let tree = usvg::Tree::from_data(&data, opt)?;
+    // Free the SVG data before allocating the pixmap.
+    drop(data);
+
+    let size = crate::fit_to_size(&tree, fit_to).ok_or(Error::InvalidSize)?;
+    let mut pixmap = tiny_skia::Pixmap::new(size.width(), size.height()).ok_or(Error::InvalidSize)?;
+    crate::render(&tree, fit_to, tiny_skia::Transform::default(), pixmap.as_mut())
+        .ok_or(Error::InvalidSize)?;
+    drop(tree);
+
+    let mut encoder = png::Encoder::new(writer, size.width(), size.height());
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut png_writer = encoder.write_header()?;
+    let mut stream = png_writer.stream_writer()?;
+
+    // PNG stores non-premultiplied colors, so we have to demultiply each row.
+    let mut row = Vec::with_capacity(size.width() as usize * 4);
+    for pixels in pixmap.pixels().chunks(size.width() as usize) {
+        row.clear();
+        for pixel in pixels {
+            let c = pixel.demultiply();
+            row.extend_from_slice(&[c.red(), c.green(), c.blue(), c.alpha()]);
+        }
+
+        stream.write_all(&row)?;
+    }
+
+    stream.finish()?;
+    Ok(())
+}
@@ -0,0 +1,413 @@
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+fn is_filled(pixmap: &tiny_skia::Pixmap, x: u32, y: u32) -> bool {
+    pixmap.pixel(x, y).unwrap().alpha() == 255
+}
+
+#[test]
+fn transform_hook() {
+    let svg = "\
+    <svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 200 200'>
+        <rect id='spinner' x='100' y='95' width='80' height='10'/>
+        <rect id='static' x='0' y='0' width='20' height='20'/>
+    </svg>";
+    let tree = usvg::Tree::from_str(svg, &usvg::Options::default().to_ref()).unwrap();
+
+    let angle = Rc::new(Cell::new(0.0));
+    let hook_angle = angle.clone();
+    let options = resvg::RenderOptions {
+        transform_hook: Some(Box::new(move |id, mut ts| {
+            if id == "spinner" {
+                ts.rotate_at(hook_angle.get(), 100.0, 100.0);
+            }
+
+            ts
+        })),
+        ..resvg::RenderOptions::default()
+    };
+
+    // The spinner end position for each frame.
+    let frames = [(170, 100), (100, 170), (30, 100), (100, 30)];
+    for frame in 0..frames.len() {
+        angle.set(frame as f64 * 90.0);
+
+        let mut pixmap = tiny_skia::Pixmap::new(200, 200).unwrap();
+        resvg::render_with_options(&tree, usvg::FitTo::Original, tiny_skia::Transform::default(),
+                                   &options, pixmap.as_mut()).unwrap();
+
+        for (i, &(x, y)) in frames.iter().enumerate() {
+            assert_eq!(is_filled(&pixmap, x, y), i == frame);
+        }
+
+        // Elements without a transform override are not affected.
+        assert!(is_filled(&pixmap, 10, 10));
+    }
+}
+
+#[test]
+fn render_fit() {
+    let svg = "\
+    <svg xmlns='http://www.w3.org/2000/svg' width='200' height='100'>
+        <rect x='100' y='0' width='100' height='100'/>
+    </svg>";
+    let tree = usvg::Tree::from_str(svg, &usvg::Options::default().to_ref()).unwrap();
+
+    let size = resvg::fit_to_size(&tree, resvg::FitTo::Height(50)).unwrap();
+    assert_eq!((size.width(), size.height()), (100, 50));
+    assert!(resvg::fit_to_size(&tree, resvg::FitTo::Zoom(0.0)).is_none());
+
+    let pixmap = resvg::render_fit(&tree, resvg::FitTo::Width(400)).unwrap();
+    assert_eq!((pixmap.width(), pixmap.height()), (400, 200));
+    assert!(!is_filled(&pixmap, 100, 100));
+    assert!(is_filled(&pixmap, 300, 100));
+}
+
+#[test]
+fn render_into_slice() {
+    let svg = "\
+    <svg xmlns='http://www.w3.org/2000/svg' width='20' height='10'>
+        <rect x='10' y='0' width='10' height='10' fill='#0000ff'/>
+    </svg>";
+    let tree = usvg::Tree::from_str(svg, &usvg::Options::default().to_ref()).unwrap();
+
+    // A framebuffer with an opaque red background.
+    let mut frame = [255, 0, 0, 255].repeat(20 * 10);
+    resvg::render_into_slice(&tree, tiny_skia::Transform::default(), 20, 10, &mut frame).unwrap();
+    assert_eq!(frame[0..4], [255, 0, 0, 255]);
+    assert_eq!(frame[(5 * 20 + 15) * 4..][..4], [0, 0, 255, 255]);
+
+    // The buffer size must match.
+    assert!(resvg::render_into_slice(&tree, tiny_skia::Transform::default(), 20, 11, &mut frame).is_none());
+    assert!(resvg::render_into_slice(&tree, tiny_skia::Transform::default(), 0, 0, &mut []).is_none());
+}
+
+#[test]
+fn dither_gradients() {
+    // The largest difference between 4x4 blocks average and the exact gradient value.
+    let max_error = |from: f64, to: f64, dither_gradients| {
+        let svg = format!("\
+        <svg xmlns='http://www.w3.org/2000/svg' width='1000' height='8'>
+            <linearGradient id='lg1'>
+                <stop offset='0' stop-color='rgb({0},{0},{0})'/>
+                <stop offset='1' stop-color='rgb({1},{1},{1})'/>
+            </linearGradient>
+            <rect width='1000' height='8' fill='url(#lg1)'/>
+        </svg>", from, to);
+        let tree = usvg::Tree::from_str(&svg, &usvg::Options::default().to_ref()).unwrap();
+
+        let options = resvg::RenderOptions { dither_gradients, ..resvg::RenderOptions::default() };
+        let mut pixmap = tiny_skia::Pixmap::new(1000, 8).unwrap();
+        resvg::render_with_options(&tree, usvg::FitTo::Original, tiny_skia::Transform::default(),
+                                   &options, pixmap.as_mut()).unwrap();
+
+        let mut max_error: f64 = 0.0;
+        for bx in (0..1000).step_by(4) {
+            let mut sum = 0.0;
+            for y in 0..4 {
+                for x in bx..bx + 4 {
+                    sum += pixmap.pixel(x, y).unwrap().red() as f64;
+                }
+            }
+
+            let exact = from + (to - from) * (bx as f64 + 2.0) / 1000.0;
+            max_error = max_error.max((sum / 16.0 - exact).abs());
+        }
+
+        max_error
+    };
+
+    // A slow gradient has wide bands of the same color.
+    assert!(max_error(128.0, 132.0, false) > 0.4);
+    assert!(max_error(128.0, 132.0, true) < 0.1);
+
+    let dithered = max_error(255.0, 0.0, true);
+    assert!(dithered < 0.1);
+    assert!(dithered < max_error(255.0, 0.0, false));
+}
+
+#[test]
+fn dither_gradients_on_a_small_shape() {
+    let render = |rect: &str| {
+        let svg = format!("\
+        <svg xmlns='http://www.w3.org/2000/svg' width='100' height='20'>
+            <linearGradient id='lg1' x2='100' gradientUnits='userSpaceOnUse'>
+                <stop offset='0' stop-color='rgb(128,128,128)'/>
+                <stop offset='1' stop-color='rgb(132,132,132)'/>
+            </linearGradient>
+            <rect {} fill='url(#lg1)' stroke='url(#lg1)' stroke-width='2'/>
+        </svg>", rect);
+        let tree = usvg::Tree::from_str(&svg, &usvg::Options::default().to_ref()).unwrap();
+
+        let options = resvg::RenderOptions { dither_gradients: true, ..resvg::RenderOptions::default() };
+        let mut pixmap = tiny_skia::Pixmap::new(100, 20).unwrap();
+        resvg::render_with_options(&tree, usvg::FitTo::Original, tiny_skia::Transform::default(),
+                                   &options, pixmap.as_mut()).unwrap();
+        pixmap
+    };
+
+    // The dithering pattern must not depend on the shape position.
+    let full = render("x='-10' y='-10' width='120' height='40'");
+    let small = render("x='33' y='7' width='20' height='6'");
+    for y in 6..14 {
+        for x in 32..54 {
+            assert_eq!(small.pixel(x, y), full.pixel(x, y));
+        }
+    }
+
+    assert_eq!(small.pixel(30, 10).unwrap().alpha(), 0);
+    assert_eq!(small.pixel(56, 10).unwrap().alpha(), 0);
+}
+
+#[test]
+fn analyze_complexity() {
+    let svg = "\
+    <svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink' width='200' height='200'>
+        <clipPath id='clip1'>
+            <rect width='100' height='100'/>
+            <image id='image1' width='10' height='10' xlink:href='data:image/svg+xml;base64,PHN2ZyB4bWxucz0naHR0cDovL3d3dy53My5vcmcvMjAwMC9zdmcnIHdpZHRoPScxMCcgaGVpZ2h0PScxMCc+PHJlY3Qgd2lkdGg9JzEwJyBoZWlnaHQ9JzEwJy8+PC9zdmc+'/>
+        </clipPath>
+        <rect width='200' height='200' clip-path='url(#clip1)'/>
+        <use xlink:href='#image1'/>
+    </svg>";
+    let tree = usvg::Tree::from_str(svg, &usvg::Options::default().to_ref()).unwrap();
+
+    let report = resvg::analyze_complexity(&tree, usvg::FitTo::Original);
+    // A clipped group with a path and an image.
+    // Nodes inside the clip path are not counted.
+    assert_eq!(report.nodes, 3);
+    assert_eq!(report.images, 1);
+    assert_eq!(report.text_paths, 0);
+    assert_eq!(report.clip_mask_layers, 1);
+    assert_eq!(report.filter_primitives, 0);
+}
+
+#[test]
+fn component_transfer_uniform() {
+    let svg = "\
+    <svg xmlns='http://www.w3.org/2000/svg' width='20' height='20'>
+        <filter id='filter1' color-interpolation-filters='sRGB'>
+            <feComponentTransfer/>
+        </filter>
+        <rect width='20' height='20' fill-opacity='0.5' filter='url(#filter1)'/>
+    </svg>";
+    let tree = usvg::Tree::from_str(svg, &usvg::Options::default().to_ref()).unwrap();
+
+    // Turns the black rect white.
+    let func = usvg::filter::TransferFunction::Linear { slope: 0.0, intercept: 1.0 };
+    if let usvg::NodeKind::Filter(ref mut filter) = *tree.defs_by_id("filter1").unwrap().borrow_mut() {
+        filter.primitives[0].kind = usvg::filter::Kind::ComponentTransfer(
+            usvg::filter::ComponentTransfer::uniform(func)
+        );
+    }
+
+    let mut pixmap = tiny_skia::Pixmap::new(20, 20).unwrap();
+    resvg::render(&tree, usvg::FitTo::Original, tiny_skia::Transform::default(), pixmap.as_mut()).unwrap();
+
+    // The alpha channel is not affected.
+    let pixel = pixmap.pixel(10, 10).unwrap();
+    assert_eq!(pixel.alpha(), 128);
+    assert_eq!((pixel.red(), pixel.green(), pixel.blue()), (128, 128, 128));
+}
+
+#[test]
+fn render_viewport() {
+    let svg = "\
+    <svg xmlns='http://www.w3.org/2000/svg' width='200' height='200'>
+        <rect x='100' y='0' width='100' height='100'/>
+    </svg>";
+    let tree = usvg::Tree::from_str(svg, &usvg::Options::default().to_ref()).unwrap();
+
+    // The region is stretched to fill the whole pixmap.
+    let mut pixmap = tiny_skia::Pixmap::new(50, 25).unwrap();
+    let rect = usvg::Rect::new(100.0, 0.0, 100.0, 100.0).unwrap();
+    resvg::render_viewport(&tree, rect, pixmap.as_mut()).unwrap();
+    assert!(pixmap.pixels().iter().all(|p| p.alpha() == 255));
+
+    // Everything outside the region is cropped.
+    let mut pixmap = tiny_skia::Pixmap::new(50, 50).unwrap();
+    let rect = usvg::Rect::new(50.0, 50.0, 100.0, 100.0).unwrap();
+    resvg::render_viewport(&tree, rect, pixmap.as_mut()).unwrap();
+    assert!(is_filled(&pixmap, 30, 10));
+    assert!(!is_filled(&pixmap, 20, 10));
+    assert!(!is_filled(&pixmap, 30, 30));
+}
+
+#[test]
+fn render_at_offset() {
+    let svg = "\
+    <svg xmlns='http://www.w3.org/2000/svg' width='20' height='20'>
+        <rect width='20' height='20'/>
+    </svg>";
+    let tree = usvg::Tree::from_str(svg, &usvg::Options::default().to_ref()).unwrap();
+
+    let mut pixmap = tiny_skia::Pixmap::new(50, 50).unwrap();
+    resvg::render_at_offset(&tree, usvg::FitTo::Original, 30, 10, pixmap.as_mut()).unwrap();
+    assert!(is_filled(&pixmap, 30, 10));
+    assert!(is_filled(&pixmap, 49, 29));
+    assert!(!is_filled(&pixmap, 29, 10));
+    assert!(!is_filled(&pixmap, 30, 30));
+
+    // Clipped by the pixmap bounds.
+    let mut pixmap = tiny_skia::Pixmap::new(50, 50).unwrap();
+    resvg::render_at_offset(&tree, usvg::FitTo::Original, -10, 40, pixmap.as_mut()).unwrap();
+    assert!(is_filled(&pixmap, 0, 49));
+    assert!(is_filled(&pixmap, 9, 40));
+    assert!(!is_filled(&pixmap, 10, 40));
+    assert!(!is_filled(&pixmap, 0, 39));
+}
+
+#[test]
+fn render_progress() {
+    let svg = "\
+    <svg xmlns='http://www.w3.org/2000/svg' width='20' height='20'>
+        <linearGradient id='lg1'>
+            <stop offset='0' stop-color='green'/>
+        </linearGradient>
+        <g id='g1'>
+            <rect width='10' height='10' fill='url(#lg1)'/>
+            <rect x='10' width='10' height='10'/>
+        </g>
+        <rect y='10' width='20' height='10'/>
+    </svg>";
+    let opt = usvg::Options { keep_named_groups: true, ..usvg::Options::default() };
+    let tree = usvg::Tree::from_str(svg, &opt.to_ref()).unwrap();
+
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let progress_events = events.clone();
+    let options = resvg::RenderOptions {
+        progress: Some(Box::new(move |event| progress_events.borrow_mut().push(event))),
+        ..resvg::RenderOptions::default()
+    };
+
+    let mut pixmap = tiny_skia::Pixmap::new(20, 20).unwrap();
+    resvg::render_with_options(&tree, usvg::FitTo::Original, tiny_skia::Transform::default(),
+                               &options, pixmap.as_mut()).unwrap();
+
+    // Reported once per top-level element. `defs` are not counted.
+    let events: Vec<_> = events.borrow().iter()
+        .map(|e| (e.nodes_rendered, e.total_nodes, e.current_node_id.clone()))
+        .collect();
+    assert_eq!(events, vec![
+        (3, 4, Some("g1".to_string())),
+        (4, 4, None),
+    ]);
+}
+
+#[test]
+fn hatch_with_a_far_away_content() {
+    let svg = "\
+    <svg xmlns='http://www.w3.org/2000/svg' width='20' height='20'>
+        <hatch id='hatch1' pitch='10'>
+            <hatchpath stroke='black' d='M 0 -3000000000 L 5 -3000000000'/>
+        </hatch>
+        <rect width='20' height='20' fill='url(#hatch1)'/>
+    </svg>";
+    let tree = usvg::Tree::from_str(svg, &usvg::Options::default().to_ref()).unwrap();
+
+    // Must not panic.
+    let mut pixmap = tiny_skia::Pixmap::new(20, 20).unwrap();
+    resvg::render(&tree, usvg::FitTo::Original, tiny_skia::Transform::default(), pixmap.as_mut()).unwrap();
+}
+
+#[cfg(feature = "png")]
+#[test]
+fn stream_render() {
+    let svg = "\
+    <svg xmlns='http://www.w3.org/2000/svg' width='20' height='10'>
+        <rect width='10' height='10' fill='green' fill-opacity='0.5'/>
+        <circle cx='15' cy='5' r='4' fill='blue'/>
+    </svg>";
+    let opt = usvg::Options::default();
+
+    let mut png_data = Vec::new();
+    resvg::stream::render(svg.as_bytes(), &mut png_data, &opt.to_ref(), usvg::FitTo::Zoom(2.0)).unwrap();
+
+    let decoder = png::Decoder::new(png_data.as_slice());
+    let mut reader = decoder.read_info().unwrap();
+    let mut img_data = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut img_data).unwrap();
+    assert_eq!((info.width, info.height), (40, 20));
+    assert_eq!(info.color_type, png::ColorType::Rgba);
+
+    let tree = usvg::Tree::from_str(svg, &opt.to_ref()).unwrap();
+    let pixmap = resvg::render_fit(&tree, usvg::FitTo::Zoom(2.0)).unwrap();
+    for (pixel, png_pixel) in pixmap.pixels().iter().zip(img_data.chunks(4)) {
+        let c = pixel.demultiply();
+        assert_eq!([c.red(), c.green(), c.blue(), c.alpha()], png_pixel);
+    }
+
+    // Invalid SVG.
+    let mut png_data = Vec::new();
+    let res = resvg::stream::render(&b"<svg"[..], &mut png_data, &opt.to_ref(), usvg::FitTo::Original);
+    assert!(matches!(res, Err(resvg::stream::Error::Parsing(_))));
+}
+
+#[cfg(feature = "debug-rendering")]
+#[test]
+fn render_debug() {
+    let svg = "\
+    <svg xmlns='http://www.w3.org/2000/svg' width='100' height='100'>
+        <filter id='filter1'>
+            <feOffset dx='1'/>
+        </filter>
+        <rect x='20' y='20' width='60' height='60' filter='url(#filter1)'/>
+    </svg>";
+    let tree = usvg::Tree::from_str(svg, &usvg::Options::default().to_ref()).unwrap();
+
+    let render = |options: &resvg::DebugOptions| {
+        let mut pixmap = tiny_skia::Pixmap::new(100, 100).unwrap();
+        resvg::render_debug(&tree, usvg::FitTo::Original, tiny_skia::Transform::default(),
+                            options, pixmap.as_mut()).unwrap();
+        pixmap
+    };
+
+    // Without overlays, the output is the same as `render`.
+    let pixmap = render(&resvg::DebugOptions::default());
+    let expected = resvg::render_fit(&tree, usvg::FitTo::Original).unwrap();
+    assert!(pixmap.data() == expected.data());
+
+    // A blue bounding box outline.
+    let pixmap = render(&resvg::DebugOptions { show_bounding_boxes: true, ..resvg::DebugOptions::default() });
+    let pixel = pixmap.pixel(19, 50).unwrap();
+    assert!(pixel.alpha() > 0 && pixel.blue() > pixel.red());
+    assert_eq!(pixmap.pixel(13, 50).unwrap().alpha(), 0);
+    assert_eq!(pixmap.pixel(5, 5).unwrap().alpha(), 0);
+
+    // A red filter region outline, which is 10% larger than the bbox,
+    // and a semi-transparent primitive subregion.
+    let pixmap = render(&resvg::DebugOptions { show_filter_regions: true, ..resvg::DebugOptions::default() });
+    let pixel = pixmap.pixel(13, 50).unwrap();
+    assert!(pixel.alpha() > 0 && pixel.red() > pixel.green() && pixel.red() > pixel.blue());
+    assert_eq!(pixmap.pixel(16, 16).unwrap().alpha(), 64);
+    assert_eq!(pixmap.pixel(5, 5).unwrap().alpha(), 0);
+}
+
+#[test]
+fn rgba8_image() {
+    let svg = "\
+    <svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink' width='2' height='1'>
+        <image width='2' height='1' image-rendering='optimizeSpeed' xlink:href='pixels'/>
+    </svg>";
+
+    // Premultiplied semi-transparent red and opaque blue.
+    let pixels = std::sync::Arc::new(vec![128, 0, 0, 128, 0, 0, 255, 255]);
+    let opt = usvg::Options {
+        image_href_resolver: usvg::ImageHrefResolver {
+            resolve_string: Box::new(move |href, _| {
+                assert_eq!(href, "pixels");
+                Some(usvg::ImageKind::RGBA8(usvg::ScreenSize::new(2, 1).unwrap(), pixels.clone()))
+            }),
+            ..usvg::ImageHrefResolver::default()
+        },
+        ..usvg::Options::default()
+    };
+    let tree = usvg::Tree::from_str(svg, &opt.to_ref()).unwrap();
+
+    let pixmap = resvg::render_fit(&tree, usvg::FitTo::Original).unwrap();
+    let colors: Vec<_> = pixmap.pixels().iter().map(|p| {
+        let c = p.demultiply();
+        (c.red(), c.green(), c.blue(), c.alpha())
+    }).collect();
+    assert_eq!(colors, vec![(255, 0, 0, 128), (0, 0, 255, 255)]);
+}
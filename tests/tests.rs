@@ -6,6 +6,13 @@ mod render;
 use render::render;
 
 #[test] fn a_alignment_baseline_001() { assert_eq!(render("a-alignment-baseline-001"), 0); }
+#[test] fn a_alignment_baseline_002() { assert_eq!(render("a-alignment-baseline-002"), 0); }
+#[test] fn a_alignment_baseline_003() { assert_eq!(render("a-alignment-baseline-003"), 0); }
+#[test] fn a_alignment_baseline_004() { assert_eq!(render("a-alignment-baseline-004"), 0); }
+#[test] fn a_alignment_baseline_005() { assert_eq!(render("a-alignment-baseline-005"), 0); }
+#[test] fn a_background_color_001() { assert_eq!(render("a-background-color-001"), 0); }
+#[test] fn a_background_color_002() { assert_eq!(render("a-background-color-002"), 0); }
+#[test] fn a_background_color_003() { assert_eq!(render("a-background-color-003"), 0); }
 #[test] fn a_baseline_shift_001() { assert_eq!(render("a-baseline-shift-001"), 0); }
 #[test] fn a_baseline_shift_002() { assert_eq!(render("a-baseline-shift-002"), 0); }
 #[test] fn a_baseline_shift_003() { assert_eq!(render("a-baseline-shift-003"), 0); }
@@ -30,9 +37,12 @@ use render::render;
 #[test] fn a_baseline_shift_022() { assert_eq!(render("a-baseline-shift-022"), 0); }
 #[test] fn a_clip_001() { assert_eq!(render("a-clip-001"), 0); }
 #[test] fn a_clip_path_001() { assert_eq!(render("a-clip-path-001"), 0); }
+#[test] fn a_clip_path_002() { assert_eq!(render("a-clip-path-002"), 0); }
+#[test] fn a_clip_path_003() { assert_eq!(render("a-clip-path-003"), 0); }
 #[test] fn a_clip_rule_001() { assert_eq!(render("a-clip-rule-001"), 0); }
 #[test] fn a_color_001() { assert_eq!(render("a-color-001"), 0); }
 #[test] fn a_color_002() { assert_eq!(render("a-color-002"), 0); }
+#[test] fn a_color_003() { assert_eq!(render("a-color-003"), 0); }
 #[test] fn a_color_interpolation_filters_001() { assert_eq!(render("a-color-interpolation-filters-001"), 0); }
 #[test] fn a_direction_001() { assert_eq!(render("a-direction-001"), 0); }
 #[test] fn a_direction_002() { assert_eq!(render("a-direction-002"), 0); }
@@ -45,7 +55,23 @@ use render::render;
 #[test] fn a_display_007() { assert_eq!(render("a-display-007"), 0); }
 #[test] fn a_display_008() { assert_eq!(render("a-display-008"), 0); }
 #[test] fn a_display_009() { assert_eq!(render("a-display-009"), 0); }
+#[test] fn a_display_010() { assert_eq!(render("a-display-010"), 0); }
+#[test] fn a_display_011() { assert_eq!(render("a-display-011"), 0); }
+#[test] fn a_display_012() { assert_eq!(render("a-display-012"), 0); }
+#[test] fn a_display_013() { assert_eq!(render("a-display-013"), 0); }
+#[test] fn a_display_014() { assert_eq!(render("a-display-014"), 0); }
 #[test] fn a_dominant_baseline_001() { assert_eq!(render("a-dominant-baseline-001"), 0); }
+#[test] fn a_dominant_baseline_002() { assert_eq!(render("a-dominant-baseline-002"), 0); }
+#[test] fn a_dominant_baseline_003() { assert_eq!(render("a-dominant-baseline-003"), 0); }
+#[test] fn a_dominant_baseline_004() { assert_eq!(render("a-dominant-baseline-004"), 0); }
+#[test] fn a_dominant_baseline_005() { assert_eq!(render("a-dominant-baseline-005"), 0); }
+#[test] fn a_dominant_baseline_006() { assert_eq!(render("a-dominant-baseline-006"), 0); }
+#[test] fn a_dominant_baseline_007() { assert_eq!(render("a-dominant-baseline-007"), 0); }
+#[test] fn a_dominant_baseline_008() { assert_eq!(render("a-dominant-baseline-008"), 0); }
+#[test] fn a_dominant_baseline_009() { assert_eq!(render("a-dominant-baseline-009"), 0); }
+#[test] fn a_dominant_baseline_010() { assert_eq!(render("a-dominant-baseline-010"), 0); }
+#[test] fn a_dominant_baseline_011() { assert_eq!(render("a-dominant-baseline-011"), 0); }
+#[test] fn a_dominant_baseline_012() { assert_eq!(render("a-dominant-baseline-012"), 0); }
 #[test] fn a_enable_background_001() { assert_eq!(render("a-enable-background-001"), 0); }
 #[test] fn a_enable_background_002() { assert_eq!(render("a-enable-background-002"), 0); }
 #[test] fn a_enable_background_003() { assert_eq!(render("a-enable-background-003"), 0); }
@@ -126,6 +152,12 @@ use render::render;
 #[test] fn a_fill_057() { assert_eq!(render("a-fill-057"), 0); }
 #[test] fn a_fill_058() { assert_eq!(render("a-fill-058"), 0); }
 #[test] fn a_fill_059() { assert_eq!(render("a-fill-059"), 0); }
+#[test] fn a_fill_060() { assert_eq!(render("a-fill-060"), 0); }
+#[test] fn a_fill_061() { assert_eq!(render("a-fill-061"), 0); }
+#[test] fn a_fill_062() { assert_eq!(render("a-fill-062"), 0); }
+#[test] fn a_fill_063() { assert_eq!(render("a-fill-063"), 0); }
+#[test] fn a_fill_064() { assert_eq!(render("a-fill-064"), 0); }
+#[test] fn a_fill_065() { assert_eq!(render("a-fill-065"), 0); }
 #[test] fn a_fill_opacity_001() { assert_eq!(render("a-fill-opacity-001"), 0); }
 #[test] fn a_fill_opacity_002() { assert_eq!(render("a-fill-opacity-002"), 0); }
 #[test] fn a_fill_opacity_003() { assert_eq!(render("a-fill-opacity-003"), 0); }
@@ -134,6 +166,7 @@ use render::render;
 #[test] fn a_fill_opacity_006() { assert_eq!(render("a-fill-opacity-006"), 0); }
 #[test] fn a_fill_rule_001() { assert_eq!(render("a-fill-rule-001"), 0); }
 #[test] fn a_fill_rule_002() { assert_eq!(render("a-fill-rule-002"), 0); }
+#[test] fn a_fill_rule_003() { assert_eq!(render("a-fill-rule-003"), 0); }
 #[test] fn a_filter_001() { assert_eq!(render("a-filter-001"), 0); }
 #[test] fn a_filter_002() { assert_eq!(render("a-filter-002"), 0); }
 #[test] fn a_filter_003() { assert_eq!(render("a-filter-003"), 0); }
@@ -177,6 +210,10 @@ use render::render;
 #[test] fn a_filter_041() { assert_eq!(render("a-filter-041"), 0); }
 #[test] fn a_filter_042() { assert_eq!(render("a-filter-042"), 0); }
 #[test] fn a_filter_043() { assert_eq!(render("a-filter-043"), 0); }
+#[test] fn a_filter_044() { assert_eq!(render("a-filter-044"), 0); }
+#[test] fn a_filter_045() { assert_eq!(render("a-filter-045"), 0); }
+#[test] fn a_filter_046() { assert_eq!(render("a-filter-046"), 0); }
+#[test] fn a_filter_047() { assert_eq!(render("a-filter-047"), 0); }
 #[test] fn a_flood_color_001() { assert_eq!(render("a-flood-color-001"), 0); }
 #[test] fn a_flood_color_002() { assert_eq!(render("a-flood-color-002"), 0); }
 #[test] fn a_flood_color_003() { assert_eq!(render("a-flood-color-003"), 0); }
@@ -217,6 +254,7 @@ use render::render;
 #[test] fn a_font_size_018() { assert_eq!(render("a-font-size-018"), 0); }
 #[test] fn a_font_size_019() { assert_eq!(render("a-font-size-019"), 0); }
 #[test] fn a_font_size_020() { assert_eq!(render("a-font-size-020"), 0); }
+#[test] fn a_font_size_021() { assert_eq!(render("a-font-size-021"), 0); }
 #[test] fn a_font_size_adjust_001() { assert_eq!(render("a-font-size-adjust-001"), 0); }
 #[test] fn a_font_stretch_001() { assert_eq!(render("a-font-stretch-001"), 0); }
 #[test] fn a_font_stretch_002() { assert_eq!(render("a-font-stretch-002"), 0); }
@@ -226,6 +264,8 @@ use render::render;
 #[test] fn a_font_style_003() { assert_eq!(render("a-font-style-003"), 0); }
 #[test] fn a_font_variant_001() { assert_eq!(render("a-font-variant-001"), 0); }
 #[test] fn a_font_variant_002() { assert_eq!(render("a-font-variant-002"), 0); }
+#[test] fn a_font_variant_numeric_001() { assert_eq!(render("a-font-variant-numeric-001"), 0); }
+#[test] fn a_font_variant_numeric_002() { assert_eq!(render("a-font-variant-numeric-002"), 0); }
 #[test] fn a_font_weight_001() { assert_eq!(render("a-font-weight-001"), 0); }
 #[test] fn a_font_weight_002() { assert_eq!(render("a-font-weight-002"), 0); }
 #[test] fn a_font_weight_003() { assert_eq!(render("a-font-weight-003"), 0); }
@@ -238,8 +278,16 @@ use render::render;
 #[test] fn a_font_weight_010() { assert_eq!(render("a-font-weight-010"), 0); }
 #[test] fn a_font_weight_011() { assert_eq!(render("a-font-weight-011"), 0); }
 #[test] fn a_font_weight_012() { assert_eq!(render("a-font-weight-012"), 0); }
+#[test] fn a_font_weight_013() { assert_eq!(render("a-font-weight-013"), 0); }
+#[test] fn a_font_weight_014() { assert_eq!(render("a-font-weight-014"), 0); }
+#[test] fn a_font_weight_015() { assert_eq!(render("a-font-weight-015"), 0); }
 #[test] fn a_glyph_orientation_horizontal_001() { assert_eq!(render("a-glyph-orientation-horizontal-001"), 0); }
 #[test] fn a_glyph_orientation_vertical_001() { assert_eq!(render("a-glyph-orientation-vertical-001"), 0); }
+#[test] fn a_glyph_orientation_vertical_002() { assert_eq!(render("a-glyph-orientation-vertical-002"), 0); }
+#[test] fn a_glyph_orientation_vertical_003() { assert_eq!(render("a-glyph-orientation-vertical-003"), 0); }
+#[test] fn a_glyph_orientation_vertical_004() { assert_eq!(render("a-glyph-orientation-vertical-004"), 0); }
+#[test] fn a_glyph_orientation_vertical_005() { assert_eq!(render("a-glyph-orientation-vertical-005"), 0); }
+#[test] fn a_glyph_orientation_vertical_006() { assert_eq!(render("a-glyph-orientation-vertical-006"), 0); }
 #[test] fn a_image_rendering_001() { assert_eq!(render("a-image-rendering-001"), 0); }
 #[test] fn a_image_rendering_002() { assert_eq!(render("a-image-rendering-002"), 0); }
 #[test] fn a_image_rendering_003() { assert_eq!(render("a-image-rendering-003"), 0); }
@@ -291,11 +339,19 @@ use render::render;
 #[test] fn a_opacity_007() { assert_eq!(render("a-opacity-007"), 0); }
 #[test] fn a_opacity_008() { assert_eq!(render("a-opacity-008"), 0); }
 #[test] fn a_opacity_009() { assert_eq!(render("a-opacity-009"), 0); }
+#[test] fn a_opacity_010() { assert_eq!(render("a-opacity-010"), 0); }
 #[test] fn a_overflow_001() { assert_eq!(render("a-overflow-001"), 0); }
 #[test] fn a_overflow_002() { assert_eq!(render("a-overflow-002"), 0); }
 #[test] fn a_overflow_003() { assert_eq!(render("a-overflow-003"), 0); }
 #[test] fn a_overflow_004() { assert_eq!(render("a-overflow-004"), 0); }
 #[test] fn a_overflow_005() { assert_eq!(render("a-overflow-005"), 0); }
+#[test] fn a_paint_order_001() { assert_eq!(render("a-paint-order-001"), 0); }
+#[test] fn a_paint_order_002() { assert_eq!(render("a-paint-order-002"), 0); }
+#[test] fn a_paint_order_003() { assert_eq!(render("a-paint-order-003"), 0); }
+#[test] fn a_paint_order_004() { assert_eq!(render("a-paint-order-004"), 0); }
+#[test] fn a_paint_order_005() { assert_eq!(render("a-paint-order-005"), 0); }
+#[test] fn a_paint_order_006() { assert_eq!(render("a-paint-order-006"), 0); }
+#[test] fn a_paint_order_007() { assert_eq!(render("a-paint-order-007"), 0); }
 #[test] fn a_shape_rendering_001() { assert_eq!(render("a-shape-rendering-001"), 0); }
 #[test] fn a_shape_rendering_002() { assert_eq!(render("a-shape-rendering-002"), 0); }
 #[test] fn a_shape_rendering_003() { assert_eq!(render("a-shape-rendering-003"), 0); }
@@ -306,6 +362,8 @@ use render::render;
 #[test] fn a_shape_rendering_008() { assert_eq!(render("a-shape-rendering-008"), 0); }
 #[test] fn a_stop_color_001() { assert_eq!(render("a-stop-color-001"), 0); }
 #[test] fn a_stop_opacity_001() { assert_eq!(render("a-stop-opacity-001"), 0); }
+#[test] fn a_stop_opacity_002() { assert_eq!(render("a-stop-opacity-002"), 0); }
+#[test] fn a_stop_opacity_003() { assert_eq!(render("a-stop-opacity-003"), 0); }
 #[test] fn a_stroke_001() { assert_eq!(render("a-stroke-001"), 0); }
 #[test] fn a_stroke_002() { assert_eq!(render("a-stroke-002"), 0); }
 #[test] fn a_stroke_003() { assert_eq!(render("a-stroke-003"), 0); }
@@ -326,6 +384,10 @@ use render::render;
 #[test] fn a_stroke_018() { assert_eq!(render("a-stroke-018"), 0); }
 #[test] fn a_stroke_019() { assert_eq!(render("a-stroke-019"), 0); }
 #[test] fn a_stroke_020() { assert_eq!(render("a-stroke-020"), 0); }
+#[test] fn a_stroke_alignment_001() { assert_eq!(render("a-stroke-alignment-001"), 0); }
+#[test] fn a_stroke_alignment_002() { assert_eq!(render("a-stroke-alignment-002"), 0); }
+#[test] fn a_stroke_alignment_003() { assert_eq!(render("a-stroke-alignment-003"), 0); }
+#[test] fn a_stroke_alignment_004() { assert_eq!(render("a-stroke-alignment-004"), 0); }
 #[test] fn a_stroke_dasharray_001() { assert_eq!(render("a-stroke-dasharray-001"), 0); }
 #[test] fn a_stroke_dasharray_002() { assert_eq!(render("a-stroke-dasharray-002"), 0); }
 #[test] fn a_stroke_dasharray_003() { assert_eq!(render("a-stroke-dasharray-003"), 0); }
@@ -339,12 +401,14 @@ use render::render;
 #[test] fn a_stroke_dasharray_011() { assert_eq!(render("a-stroke-dasharray-011"), 0); }
 #[test] fn a_stroke_dasharray_012() { assert_eq!(render("a-stroke-dasharray-012"), 0); }
 #[test] fn a_stroke_dasharray_013() { assert_eq!(render("a-stroke-dasharray-013"), 0); }
+#[test] fn a_stroke_dasharray_014() { assert_eq!(render("a-stroke-dasharray-014"), 0); }
 #[test] fn a_stroke_dashoffset_001() { assert_eq!(render("a-stroke-dashoffset-001"), 0); }
 #[test] fn a_stroke_dashoffset_002() { assert_eq!(render("a-stroke-dashoffset-002"), 0); }
 #[test] fn a_stroke_dashoffset_003() { assert_eq!(render("a-stroke-dashoffset-003"), 0); }
 #[test] fn a_stroke_dashoffset_004() { assert_eq!(render("a-stroke-dashoffset-004"), 0); }
 #[test] fn a_stroke_dashoffset_005() { assert_eq!(render("a-stroke-dashoffset-005"), 0); }
 #[test] fn a_stroke_dashoffset_006() { assert_eq!(render("a-stroke-dashoffset-006"), 0); }
+#[test] fn a_stroke_dashoffset_007() { assert_eq!(render("a-stroke-dashoffset-007"), 0); }
 #[test] fn a_stroke_linecap_001() { assert_eq!(render("a-stroke-linecap-001"), 0); }
 #[test] fn a_stroke_linecap_002() { assert_eq!(render("a-stroke-linecap-002"), 0); }
 #[test] fn a_stroke_linecap_003() { assert_eq!(render("a-stroke-linecap-003"), 0); }
@@ -354,6 +418,7 @@ use render::render;
 #[test] fn a_stroke_linecap_007() { assert_eq!(render("a-stroke-linecap-007"), 0); }
 #[test] fn a_stroke_linecap_008() { assert_eq!(render("a-stroke-linecap-008"), 0); }
 #[test] fn a_stroke_linecap_009() { assert_eq!(render("a-stroke-linecap-009"), 0); }
+#[test] fn a_stroke_linecap_010() { assert_eq!(render("a-stroke-linecap-010"), 0); }
 #[test] fn a_stroke_linejoin_001() { assert_eq!(render("a-stroke-linejoin-001"), 0); }
 #[test] fn a_stroke_linejoin_002() { assert_eq!(render("a-stroke-linejoin-002"), 0); }
 #[test] fn a_stroke_linejoin_003() { assert_eq!(render("a-stroke-linejoin-003"), 0); }
@@ -364,6 +429,7 @@ use render::render;
 #[test] fn a_stroke_miterlimit_003() { assert_eq!(render("a-stroke-miterlimit-003"), 0); }
 #[test] fn a_stroke_miterlimit_004() { assert_eq!(render("a-stroke-miterlimit-004"), 0); }
 #[test] fn a_stroke_miterlimit_005() { assert_eq!(render("a-stroke-miterlimit-005"), 0); }
+#[test] fn a_stroke_miterlimit_006() { assert_eq!(render("a-stroke-miterlimit-006"), 0); }
 #[test] fn a_stroke_opacity_001() { assert_eq!(render("a-stroke-opacity-001"), 0); }
 #[test] fn a_stroke_opacity_002() { assert_eq!(render("a-stroke-opacity-002"), 0); }
 #[test] fn a_stroke_opacity_003() { assert_eq!(render("a-stroke-opacity-003"), 0); }
@@ -454,6 +520,13 @@ use render::render;
 #[test] fn a_transform_017() { assert_eq!(render("a-transform-017"), 0); }
 #[test] fn a_transform_018() { assert_eq!(render("a-transform-018"), 0); }
 #[test] fn a_transform_019() { assert_eq!(render("a-transform-019"), 0); }
+#[test] fn a_transform_020() { assert_eq!(render("a-transform-020"), 0); }
+#[test] fn a_transform_021() { assert_eq!(render("a-transform-021"), 0); }
+#[test] fn a_transform_origin_001() { assert_eq!(render("a-transform-origin-001"), 0); }
+#[test] fn a_transform_origin_002() { assert_eq!(render("a-transform-origin-002"), 0); }
+#[test] fn a_transform_origin_003() { assert_eq!(render("a-transform-origin-003"), 0); }
+#[test] fn a_transform_origin_004() { assert_eq!(render("a-transform-origin-004"), 0); }
+#[test] fn a_transform_origin_005() { assert_eq!(render("a-transform-origin-005"), 0); }
 #[test] fn a_unicode_bidi_001() { assert_eq!(render("a-unicode-bidi-001"), 0); }
 #[test] fn a_visibility_001() { assert_eq!(render("a-visibility-001"), 0); }
 #[test] fn a_visibility_002() { assert_eq!(render("a-visibility-002"), 0); }
@@ -503,6 +576,7 @@ use render::render;
 #[test] fn e_circle_004() { assert_eq!(render("e-circle-004"), 0); }
 #[test] fn e_circle_005() { assert_eq!(render("e-circle-005"), 0); }
 #[test] fn e_circle_006() { assert_eq!(render("e-circle-006"), 0); }
+#[test] fn e_circle_007() { assert_eq!(render("e-circle-007"), 0); }
 #[test] fn e_clipPath_001() { assert_eq!(render("e-clipPath-001"), 0); }
 #[test] fn e_clipPath_002() { assert_eq!(render("e-clipPath-002"), 0); }
 #[test] fn e_clipPath_003() { assert_eq!(render("e-clipPath-003"), 0); }
@@ -579,6 +653,7 @@ use render::render;
 #[test] fn e_feBlend_008() { assert_eq!(render("e-feBlend-008"), 0); }
 #[test] fn e_feBlend_009() { assert_eq!(render("e-feBlend-009"), 0); }
 #[test] fn e_feBlend_010() { assert_eq!(render("e-feBlend-010"), 0); }
+#[test] fn e_feBlend_011() { assert_eq!(render("e-feBlend-011"), 0); }
 #[test] fn e_feColorMatrix_001() { assert_eq!(render("e-feColorMatrix-001"), 0); }
 #[test] fn e_feColorMatrix_002() { assert_eq!(render("e-feColorMatrix-002"), 0); }
 #[test] fn e_feColorMatrix_003() { assert_eq!(render("e-feColorMatrix-003"), 0); }
@@ -617,6 +692,7 @@ use render::render;
 #[test] fn e_feComponentTransfer_020() { assert_eq!(render("e-feComponentTransfer-020"), 0); }
 #[test] fn e_feComponentTransfer_021() { assert_eq!(render("e-feComponentTransfer-021"), 0); }
 #[test] fn e_feComponentTransfer_022() { assert_eq!(render("e-feComponentTransfer-022"), 0); }
+#[test] fn e_feComponentTransfer_023() { assert_eq!(render("e-feComponentTransfer-023"), 0); }
 #[test] fn e_feComposite_001() { assert_eq!(render("e-feComposite-001"), 0); }
 #[test] fn e_feComposite_002() { assert_eq!(render("e-feComposite-002"), 0); }
 #[test] fn e_feComposite_003() { assert_eq!(render("e-feComposite-003"), 0); }
@@ -715,6 +791,9 @@ use render::render;
 #[test] fn e_feGaussianBlur_011() { assert_eq!(render("e-feGaussianBlur-011"), 0); }
 #[test] fn e_feGaussianBlur_012() { assert_eq!(render("e-feGaussianBlur-012"), 0); }
 #[test] fn e_feGaussianBlur_013() { assert_eq!(render("e-feGaussianBlur-013"), 0); }
+#[test] fn e_feGaussianBlur_014() { assert_eq!(render("e-feGaussianBlur-014"), 0); }
+#[test] fn e_feGaussianBlur_015() { assert_eq!(render("e-feGaussianBlur-015"), 0); }
+#[test] fn e_feGaussianBlur_016() { assert_eq!(render("e-feGaussianBlur-016"), 0); }
 #[test] fn e_feImage_001() { assert_eq!(render("e-feImage-001"), 0); }
 #[test] fn e_feImage_002() { assert_eq!(render("e-feImage-002"), 0); }
 #[test] fn e_feImage_003() { assert_eq!(render("e-feImage-003"), 0); }
@@ -878,8 +957,29 @@ use render::render;
 #[test] fn e_filter_063() { assert_eq!(render("e-filter-063"), 0); }
 #[test] fn e_filter_064() { assert_eq!(render("e-filter-064"), 0); }
 #[test] fn e_filter_065() { assert_eq!(render("e-filter-065"), 0); }
+#[test] fn e_filter_087() { assert_eq!(render("e-filter-087"), 0); }
+#[test] fn e_filter_088() { assert_eq!(render("e-filter-088"), 0); }
+#[test] fn e_filter_089() { assert_eq!(render("e-filter-089"), 0); }
+#[test] fn e_filter_090() { assert_eq!(render("e-filter-090"), 0); }
+#[test] fn e_filter_091() { assert_eq!(render("e-filter-091"), 0); }
+#[test] fn e_filter_092() { assert_eq!(render("e-filter-092"), 0); }
+#[test] fn e_filter_093() { assert_eq!(render("e-filter-093"), 0); }
+#[test] fn e_filter_094() { assert_eq!(render("e-filter-094"), 0); }
+#[test] fn e_foreignObject_001() { assert_eq!(render("e-foreignObject-001"), 0); }
+#[test] fn e_foreignObject_002() { assert_eq!(render("e-foreignObject-002"), 0); }
+#[test] fn e_foreignObject_003() { assert_eq!(render("e-foreignObject-003"), 0); }
+#[test] fn e_foreignObject_004() { assert_eq!(render("e-foreignObject-004"), 0); }
 #[test] fn e_g_001() { assert_eq!(render("e-g-001"), 0); }
 #[test] fn e_g_002() { assert_eq!(render("e-g-002"), 0); }
+#[test] fn e_hatch_001() { assert_eq!(render("e-hatch-001"), 0); }
+#[test] fn e_hatch_002() { assert_eq!(render("e-hatch-002"), 0); }
+#[test] fn e_hatch_003() { assert_eq!(render("e-hatch-003"), 0); }
+#[test] fn e_hatch_004() { assert_eq!(render("e-hatch-004"), 0); }
+#[test] fn e_hatch_005() { assert_eq!(render("e-hatch-005"), 0); }
+#[test] fn e_hatch_006() { assert_eq!(render("e-hatch-006"), 0); }
+#[test] fn e_hatch_007() { assert_eq!(render("e-hatch-007"), 0); }
+#[test] fn e_hatch_008() { assert_eq!(render("e-hatch-008"), 0); }
+#[test] fn e_hatch_009() { assert_eq!(render("e-hatch-009"), 0); }
 #[test] fn e_image_001() { assert_eq!(render("e-image-001"), 0); }
 #[test] fn e_image_002() { assert_eq!(render("e-image-002"), 0); }
 #[test] fn e_image_003() { assert_eq!(render("e-image-003"), 0); }
@@ -925,6 +1025,10 @@ use render::render;
 #[test] fn e_image_043() { assert_eq!(render("e-image-043"), 0); }
 #[test] fn e_image_045() { assert_eq!(render("e-image-045"), 0); }
 #[test] fn e_image_046() { assert_eq!(render("e-image-046"), 0); }
+#[test] fn e_image_047() { assert_eq!(render("e-image-047"), 0); }
+#[test] fn e_image_048() { assert_eq!(render("e-image-048"), 0); }
+#[test] fn e_image_049() { assert_eq!(render("e-image-049"), 0); }
+#[test] fn e_image_050() { assert_eq!(render("e-image-050"), 0); }
 #[test] fn e_line_001() { assert_eq!(render("e-line-001"), 0); }
 #[test] fn e_line_002() { assert_eq!(render("e-line-002"), 0); }
 #[test] fn e_line_003() { assert_eq!(render("e-line-003"), 0); }
@@ -934,6 +1038,7 @@ use render::render;
 #[test] fn e_line_007() { assert_eq!(render("e-line-007"), 0); }
 #[test] fn e_line_008() { assert_eq!(render("e-line-008"), 0); }
 #[test] fn e_line_009() { assert_eq!(render("e-line-009"), 0); }
+#[test] fn e_line_010() { assert_eq!(render("e-line-010"), 0); }
 #[test] fn e_linearGradient_001() { assert_eq!(render("e-linearGradient-001"), 0); }
 #[test] fn e_linearGradient_002() { assert_eq!(render("e-linearGradient-002"), 0); }
 #[test] fn e_linearGradient_003() { assert_eq!(render("e-linearGradient-003"), 0); }
@@ -972,6 +1077,9 @@ use render::render;
 #[test] fn e_linearGradient_036() { assert_eq!(render("e-linearGradient-036"), 0); }
 #[test] fn e_linearGradient_037() { assert_eq!(render("e-linearGradient-037"), 0); }
 #[test] fn e_linearGradient_038() { assert_eq!(render("e-linearGradient-038"), 0); }
+#[test] fn e_linearGradient_039() { assert_eq!(render("e-linearGradient-039"), 0); }
+#[test] fn e_linearGradient_040() { assert_eq!(render("e-linearGradient-040"), 0); }
+#[test] fn e_linearGradient_041() { assert_eq!(render("e-linearGradient-041"), 0); }
 #[test] fn e_marker_001() { assert_eq!(render("e-marker-001"), 0); }
 #[test] fn e_marker_002() { assert_eq!(render("e-marker-002"), 0); }
 #[test] fn e_marker_003() { assert_eq!(render("e-marker-003"), 0); }
@@ -1032,6 +1140,8 @@ use render::render;
 #[test] fn e_marker_058() { assert_eq!(render("e-marker-058"), 0); }
 #[test] fn e_marker_059() { assert_eq!(render("e-marker-059"), 0); }
 #[test] fn e_marker_060() { assert_eq!(render("e-marker-060"), 0); }
+#[test] fn e_marker_061() { assert_eq!(render("e-marker-061"), 0); }
+#[test] fn e_marker_062() { assert_eq!(render("e-marker-062"), 0); }
 #[test] fn e_mask_001() { assert_eq!(render("e-mask-001"), 0); }
 #[test] fn e_mask_002() { assert_eq!(render("e-mask-002"), 0); }
 #[test] fn e_mask_003() { assert_eq!(render("e-mask-003"), 0); }
@@ -1063,6 +1173,7 @@ use render::render;
 #[test] fn e_mask_029() { assert_eq!(render("e-mask-029"), 0); }
 #[test] fn e_mask_030() { assert_eq!(render("e-mask-030"), 0); }
 #[test] fn e_mask_031() { assert_eq!(render("e-mask-031"), 0); }
+#[test] fn e_mask_032() { assert_eq!(render("e-mask-032"), 0); }
 #[test] fn e_path_001() { assert_eq!(render("e-path-001"), 0); }
 #[test] fn e_path_002() { assert_eq!(render("e-path-002"), 0); }
 #[test] fn e_path_003() { assert_eq!(render("e-path-003"), 0); }
@@ -1114,6 +1225,8 @@ use render::render;
 #[test] fn e_path_049() { assert_eq!(render("e-path-049"), 0); }
 #[test] fn e_path_050() { assert_eq!(render("e-path-050"), 0); }
 #[test] fn e_path_051() { assert_eq!(render("e-path-051"), 0); }
+#[test] fn e_path_052() { assert_eq!(render("e-path-052"), 0); }
+#[test] fn e_path_053() { assert_eq!(render("e-path-053"), 0); }
 #[test] fn e_pattern_001() { assert_eq!(render("e-pattern-001"), 0); }
 #[test] fn e_pattern_002() { assert_eq!(render("e-pattern-002"), 0); }
 #[test] fn e_pattern_003() { assert_eq!(render("e-pattern-003"), 0); }
@@ -1145,16 +1258,23 @@ use render::render;
 #[test] fn e_pattern_029() { assert_eq!(render("e-pattern-029"), 0); }
 #[test] fn e_pattern_030() { assert_eq!(render("e-pattern-030"), 0); }
 #[test] fn e_pattern_031() { assert_eq!(render("e-pattern-031"), 0); }
+#[test] fn e_pattern_032() { assert_eq!(render("e-pattern-032"), 0); }
+#[test] fn e_pattern_033() { assert_eq!(render("e-pattern-033"), 0); }
 #[test] fn e_polygon_001() { assert_eq!(render("e-polygon-001"), 0); }
 #[test] fn e_polygon_002() { assert_eq!(render("e-polygon-002"), 0); }
 #[test] fn e_polygon_003() { assert_eq!(render("e-polygon-003"), 0); }
 #[test] fn e_polygon_004() { assert_eq!(render("e-polygon-004"), 0); }
 #[test] fn e_polygon_005() { assert_eq!(render("e-polygon-005"), 0); }
+#[test] fn e_polygon_006() { assert_eq!(render("e-polygon-006"), 0); }
+#[test] fn e_polygon_007() { assert_eq!(render("e-polygon-007"), 0); }
+#[test] fn e_polygon_008() { assert_eq!(render("e-polygon-008"), 0); }
 #[test] fn e_polyline_001() { assert_eq!(render("e-polyline-001"), 0); }
 #[test] fn e_polyline_002() { assert_eq!(render("e-polyline-002"), 0); }
 #[test] fn e_polyline_003() { assert_eq!(render("e-polyline-003"), 0); }
 #[test] fn e_polyline_004() { assert_eq!(render("e-polyline-004"), 0); }
 #[test] fn e_polyline_005() { assert_eq!(render("e-polyline-005"), 0); }
+#[test] fn e_polyline_006() { assert_eq!(render("e-polyline-006"), 0); }
+#[test] fn e_polyline_007() { assert_eq!(render("e-polyline-007"), 0); }
 #[test] fn e_radialGradient_001() { assert_eq!(render("e-radialGradient-001"), 0); }
 #[test] fn e_radialGradient_002() { assert_eq!(render("e-radialGradient-002"), 0); }
 #[test] fn e_radialGradient_003() { assert_eq!(render("e-radialGradient-003"), 0); }
@@ -1200,6 +1320,7 @@ use render::render;
 #[test] fn e_radialGradient_043() { assert_eq!(render("e-radialGradient-043"), 0); }
 #[test] fn e_radialGradient_044() { assert_eq!(render("e-radialGradient-044"), 0); }
 #[test] fn e_radialGradient_045() { assert_eq!(render("e-radialGradient-045"), 0); }
+#[test] fn e_radialGradient_046() { assert_eq!(render("e-radialGradient-046"), 0); }
 #[test] fn e_rect_001() { assert_eq!(render("e-rect-001"), 0); }
 #[test] fn e_rect_002() { assert_eq!(render("e-rect-002"), 0); }
 #[test] fn e_rect_003() { assert_eq!(render("e-rect-003"), 0); }
@@ -1237,6 +1358,8 @@ use render::render;
 #[test] fn e_rect_035() { assert_eq!(render("e-rect-035"), 0); }
 #[test] fn e_rect_036() { assert_eq!(render("e-rect-036"), 0); }
 #[test] fn e_rect_037() { assert_eq!(render("e-rect-037"), 0); }
+#[test] fn e_rect_038() { assert_eq!(render("e-rect-038"), 0); }
+#[test] fn e_rect_039() { assert_eq!(render("e-rect-039"), 0); }
 #[test] fn e_stop_001() { assert_eq!(render("e-stop-001"), 0); }
 #[test] fn e_stop_002() { assert_eq!(render("e-stop-002"), 0); }
 #[test] fn e_stop_003() { assert_eq!(render("e-stop-003"), 0); }
@@ -1269,6 +1392,7 @@ use render::render;
 #[test] fn e_stop_030() { assert_eq!(render("e-stop-030"), 0); }
 #[test] fn e_stop_031() { assert_eq!(render("e-stop-031"), 0); }
 #[test] fn e_stop_032() { assert_eq!(render("e-stop-032"), 0); }
+#[test] fn e_stop_033() { assert_eq!(render("e-stop-033"), 0); }
 #[test] fn e_style_001() { assert_eq!(render("e-style-001"), 0); }
 #[test] fn e_style_002() { assert_eq!(render("e-style-002"), 0); }
 #[test] fn e_style_003() { assert_eq!(render("e-style-003"), 0); }
@@ -1285,6 +1409,14 @@ use render::render;
 #[test] fn e_style_014() { assert_eq!(render("e-style-014"), 0); }
 #[test] fn e_style_015() { assert_eq!(render("e-style-015"), 0); }
 #[test] fn e_style_016() { assert_eq!(render("e-style-016"), 0); }
+#[test] fn e_style_017() { assert_eq!(render("e-style-017"), 0); }
+#[test] fn e_style_018() { assert_eq!(render("e-style-018"), 0); }
+#[test] fn e_style_019() { assert_eq!(render("e-style-019"), 0); }
+#[test] fn e_style_020() { assert_eq!(render("e-style-020"), 0); }
+#[test] fn e_style_021() { assert_eq!(render("e-style-021"), 0); }
+#[test] fn e_style_022() { assert_eq!(render("e-style-022"), 0); }
+#[test] fn e_style_023() { assert_eq!(render("e-style-023"), 0); }
+#[test] fn e_style_024() { assert_eq!(render("e-style-024"), 0); }
 #[test] fn e_svg_001() { assert_eq!(render("e-svg-001"), 0); }
 #[test] fn e_svg_002() { assert_eq!(render("e-svg-002"), 0); }
 #[test] fn e_svg_003() { assert_eq!(render("e-svg-003"), 0); }
@@ -1315,6 +1447,10 @@ use render::render;
 #[test] fn e_svg_037() { assert_eq!(render("e-svg-037"), 0); }
 #[test] fn e_svg_038() { assert_eq!(render("e-svg-038"), 0); }
 #[test] fn e_svg_039() { assert_eq!(render("e-svg-039"), 0); }
+#[test] fn e_svg_040() { assert_eq!(render("e-svg-040"), 0); }
+#[test] fn e_svg_041() { assert_eq!(render("e-svg-041"), 0); }
+#[test] fn e_svg_042() { assert_eq!(render("e-svg-042"), 0); }
+#[test] fn e_svg_043() { assert_eq!(render("e-svg-043"), 0); }
 #[test] fn e_switch_001() { assert_eq!(render("e-switch-001"), 0); }
 #[test] fn e_switch_002() { assert_eq!(render("e-switch-002"), 0); }
 #[test] fn e_switch_003() { assert_eq!(render("e-switch-003"), 0); }
@@ -1342,6 +1478,14 @@ use render::render;
 #[test] fn e_symbol_012() { assert_eq!(render("e-symbol-012"), 0); }
 #[test] fn e_symbol_013() { assert_eq!(render("e-symbol-013"), 0); }
 #[test] fn e_symbol_014() { assert_eq!(render("e-symbol-014"), 0); }
+#[test] fn e_symbol_015() { assert_eq!(render("e-symbol-015"), 0); }
+#[test] fn e_symbol_016() { assert_eq!(render("e-symbol-016"), 0); }
+#[test] fn e_symbol_017() { assert_eq!(render("e-symbol-017"), 0); }
+#[test] fn e_symbol_018() { assert_eq!(render("e-symbol-018"), 0); }
+#[test] fn e_symbol_019() { assert_eq!(render("e-symbol-019"), 0); }
+#[test] fn e_symbol_020() { assert_eq!(render("e-symbol-020"), 0); }
+#[test] fn e_symbol_021() { assert_eq!(render("e-symbol-021"), 0); }
+#[test] fn e_symbol_022() { assert_eq!(render("e-symbol-022"), 0); }
 #[test] fn e_text_001() { assert_eq!(render("e-text-001"), 0); }
 #[test] fn e_text_002() { assert_eq!(render("e-text-002"), 0); }
 #[test] fn e_text_003() { assert_eq!(render("e-text-003"), 0); }
@@ -1383,6 +1527,10 @@ use render::render;
 #[test] fn e_text_040() { assert_eq!(render("e-text-040"), 0); }
 #[test] fn e_text_041() { assert_eq!(render("e-text-041"), 0); }
 #[test] fn e_text_042() { assert_eq!(render("e-text-042"), 0); }
+#[test] fn e_text_043() { assert_eq!(render("e-text-043"), 0); }
+#[test] fn e_text_044() { assert_eq!(render("e-text-044"), 0); }
+#[test] fn e_text_045() { assert_eq!(render("e-text-045"), 0); }
+#[test] fn e_text_046() { assert_eq!(render("e-text-046"), 0); }
 #[test] fn e_textPath_001() { assert_eq!(render("e-textPath-001"), 0); }
 #[test] fn e_textPath_002() { assert_eq!(render("e-textPath-002"), 0); }
 #[test] fn e_textPath_003() { assert_eq!(render("e-textPath-003"), 0); }
@@ -1469,6 +1617,7 @@ use render::render;
 #[test] fn e_tspan_029() { assert_eq!(render("e-tspan-029"), 0); }
 #[test] fn e_tspan_030() { assert_eq!(render("e-tspan-030"), 0); }
 #[test] fn e_tspan_031() { assert_eq!(render("e-tspan-031"), 0); }
+#[test] fn e_tspan_032() { assert_eq!(render("e-tspan-032"), 0); }
 #[test] fn e_use_001() { assert_eq!(render("e-use-001"), 0); }
 #[test] fn e_use_002() { assert_eq!(render("e-use-002"), 0); }
 #[test] fn e_use_003() { assert_eq!(render("e-use-003"), 0); }
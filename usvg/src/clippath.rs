@@ -3,7 +3,7 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
 use crate::svgtree::{self, EId, AId};
-use crate::{converter, Tree, NodeKind, Units, Transform};
+use crate::{converter, style, Tree, NodeExt, NodeKind, Path, SharedPathData, Units, Transform};
 
 
 /// A clip-path element.
@@ -83,7 +83,7 @@ pub(crate) fn convert(
         NodeKind::ClipPath(ClipPath {
             id: node.element_id().to_string(),
             units,
-            transform: node.attribute(AId::Transform).unwrap_or_default(),
+            transform: node.resolve_transform(state),
             clip_path,
         })
     );
@@ -100,3 +100,29 @@ pub(crate) fn convert(
         None
     }
 }
+
+/// Converts an inline path, like `clip-path: path('...')`, into a `clipPath`.
+///
+/// Path data is in the element's user space.
+/// `clip-rule` doesn't apply to `path()` and the `nonzero` fill rule is used instead.
+pub(crate) fn convert_path(
+    path: SharedPathData,
+    id_generator: &mut converter::NodeIdGenerator,
+    tree: &mut Tree,
+) -> String {
+    let id = id_generator.gen_clip_path_id();
+    let mut clip = tree.append_to_defs(
+        NodeKind::ClipPath(ClipPath {
+            id: id.clone(),
+            .. ClipPath::default()
+        })
+    );
+
+    clip.append_kind(NodeKind::Path(Path {
+        fill: Some(style::Fill::default()),
+        data: path,
+        .. Path::default()
+    }));
+
+    id
+}
@@ -15,6 +15,8 @@ pub struct State<'a> {
     pub(crate) parent_clip_path: Option<svgtree::Node<'a>>,
     pub(crate) parent_marker: Option<svgtree::Node<'a>>,
     pub(crate) fe_image_link: bool,
+    /// Ids of elements referenced by `feImage`.
+    pub(crate) fe_image_targets: &'a HashSet<String>,
     /// The size of the root SVG element.
     /// Right now, used only by use_node::get_clip_rect.
     pub(crate) size: Size,
@@ -24,6 +26,12 @@ pub struct State<'a> {
     /// Used only during nested `svg` size resolving.
     /// Width and height can be set independently.
     pub(crate) use_size: (Option<f64>, Option<f64>),
+    /// A resolved fill and stroke of the context element.
+    /// Used by `context-fill` and `context-stroke`.
+    ///
+    /// The context element is either a shape that references a `marker`
+    /// or a `use` element.
+    pub(crate) context_element: Option<(Option<style::Fill>, Option<style::Stroke>)>,
     pub(crate) opt: &'a OptionsRef<'a>,
 }
 
@@ -107,13 +115,21 @@ pub(crate) fn convert_doc(
         return Ok(tree);
     }
 
+    let fe_image_targets: HashSet<_> = svg_doc.descendants()
+        .filter(|n| n.has_tag_name(EId::FeImage))
+        .filter_map(|n| n.attribute::<svgtree::Node>(AId::Href))
+        .map(|n| n.element_id().to_string())
+        .collect();
+
     let state = State {
         parent_clip_path: None,
         parent_marker: None,
         fe_image_link: false,
+        fe_image_targets: &fe_image_targets,
         size,
         view_box: view_box.rect,
         use_size: (None, None),
+        context_element: None,
         opt,
     };
 
@@ -154,13 +170,16 @@ fn resolve_svg_size(
     svg: &svgtree::Node,
     opt: &OptionsRef,
 ) -> (Result<Size, Error>, bool) {
+    let fe_image_targets = HashSet::new();
     let mut state = State {
         parent_clip_path: None,
         parent_marker: None,
         fe_image_link: false,
+        fe_image_targets: &fe_image_targets,
         size: Size::new(100.0, 100.0).unwrap(),
         view_box: Rect::new(0.0, 0.0, 100.0, 100.0).unwrap(),
         use_size: (None, None),
+        context_element: None,
         opt,
     };
 
@@ -269,6 +288,23 @@ pub(crate) fn convert_element(
         return None;
     }
 
+    // A container with `display="contents"` doesn't produce a box,
+    // so its children are rendered as if they were placed directly in the parent.
+    // The container's own opacity, transform, clip-path, mask and filter are ignored,
+    // as well as `use` position and nested `svg` viewport,
+    // but its presentation attributes are still inherited.
+    if node.attribute(AId::Display) == Some("contents") {
+        // `use` has a single child, which is the referenced element.
+        // A referenced `symbol` is unwrapped as well.
+        let container = match node.first_child() {
+            Some(child) if tag_name == EId::Use && child.tag_name() == Some(EId::Symbol) => child,
+            _ => node,
+        };
+
+        convert_children(container, state, id_generator, parent, tree);
+        return None;
+    }
+
     if tag_name == EId::Use {
         use_node::convert(node, state, id_generator, parent, tree);
         return None;
@@ -279,7 +315,9 @@ pub(crate) fn convert_element(
         return None;
     }
 
-    let parent = &mut match convert_group(node, state, false, id_generator, parent, tree) {
+    let group_kind = convert_group(node, state, false, id_generator, parent, tree);
+    let is_new_group = matches!(group_kind, GroupKind::Create(_));
+    let parent = &mut match group_kind {
         GroupKind::Create(g) => g,
         GroupKind::Skip => parent.clone(),
         GroupKind::Ignore => return None,
@@ -320,6 +358,23 @@ pub(crate) fn convert_element(
         _ => {}
     }
 
+    // When a group has received an element id because of `feImage`,
+    // remove the id from the element itself to prevent duplicates.
+    let is_fe_image_group = is_new_group
+        && !matches!(tag_name, EId::G | EId::Svg)
+        && matches!(*parent.borrow(), NodeKind::Group(ref g) if !g.id.is_empty());
+    if is_fe_image_group {
+        for mut child in parent.children() {
+            match *child.borrow_mut() {
+                NodeKind::Path(ref mut p) => p.id.clear(),
+                NodeKind::Image(ref mut p) => p.id.clear(),
+                // A group with text paths created by `keep_named_groups`.
+                NodeKind::Group(ref mut g) => g.id.clear(),
+                _ => {}
+            }
+        }
+    }
+
     Some(parent.clone())
 }
 
@@ -429,7 +484,11 @@ pub(crate) fn convert_group(
     // `mask` and `filter` cannot be set on `clipPath` children.
     // But `clip-path` can.
 
-    let clip_path = resolve_link!(AId::ClipPath, clippath::convert);
+    let clip_path = if let Some(path) = node.attribute::<SharedPathData>(AId::ClipPath) {
+        Some(clippath::convert_path(path, id_generator, tree))
+    } else {
+        resolve_link!(AId::ClipPath, clippath::convert)
+    };
 
     let mask = if state.parent_clip_path.is_none() {
         resolve_link!(AId::Mask, mask::convert)
@@ -474,11 +533,24 @@ pub(crate) fn convert_group(
     #[cfg(not(feature = "filter"))]
     let filter_stroke = None;
 
-    let transform: Transform = node.attribute(AId::Transform).unwrap_or_default();
+    let transform = node.resolve_transform(state);
 
     let enable_background = node.attribute(AId::EnableBackground);
 
+    let background_color = if node.has_tag_name(EId::G) {
+        convert_background_color(node)
+    } else {
+        None
+    };
+
     let is_g_or_use = node.has_tag_name(EId::G) || node.has_tag_name(EId::Use);
+    // An element referenced by `feImage` should be rendered with its opacity, clip-path, etc.,
+    // so the group should be referenced instead.
+    // Except when a filter is set, to prevent `feImage` recursion. See e-feImage-016.
+    let is_fe_image_target = filter.is_empty()
+        && !node.has_tag_name(EId::Svg)
+        && node.has_element_id()
+        && state.fe_image_targets.contains(node.element_id());
     let required =
            opacity.get().fuzzy_ne(&1.0)
         || clip_path.is_some()
@@ -486,13 +558,14 @@ pub(crate) fn convert_group(
         || !filter.is_empty()
         || !transform.is_default()
         || enable_background.is_some()
+        || background_color.is_some()
         || (is_g_or_use
             && node.has_element_id()
             && (state.opt.keep_named_groups || state.fe_image_link))
         || force;
 
     if required {
-        let id = if is_g_or_use {
+        let id = if is_g_or_use || is_fe_image_target {
             node.element_id().to_string()
         } else {
             String::new()
@@ -508,6 +581,7 @@ pub(crate) fn convert_group(
             filter_fill,
             filter_stroke,
             enable_background,
+            background_color,
         }));
 
         GroupKind::Create(g)
@@ -516,6 +590,18 @@ pub(crate) fn convert_group(
     }
 }
 
+fn convert_background_color(node: svgtree::Node) -> Option<Color> {
+    // The background is always opaque. Simply skip the alpha.
+    match node.attribute::<&svgtree::AttributeValue>(AId::BackgroundColor)? {
+        svgtree::AttributeValue::CurrentColor => {
+            let color = node.find_attribute(AId::Color).unwrap_or_else(svgtypes::Color::black);
+            Some(color.split_alpha().0)
+        }
+        svgtree::AttributeValue::Color(c) => Some(c.split_alpha().0),
+        _ => None,
+    }
+}
+
 #[cfg(feature = "filter")]
 fn resolve_filter_fill(
     node: svgtree::Node,
@@ -630,6 +716,7 @@ fn ungroup_groups(
                 && g.mask.is_none()
                 && g.filter.is_empty()
                 && g.enable_background.is_none()
+                && g.background_color.is_none()
                 && !(opt.keep_named_groups && !g.id.is_empty())
                 && !is_id_used(tree, &g.id)
             } else {
@@ -890,27 +977,91 @@ fn convert_path(
         visibility = Visibility::Hidden;
     }
 
+    let raw_paint_order: svgtypes::PaintOrder = node.find_attribute(AId::PaintOrder).unwrap_or_default();
+    let paint_order = convert_paint_order(raw_paint_order);
+
     let mut markers_group = None;
     if marker::is_valid(node) && visibility == Visibility::Visible {
         let mut g = parent.append_kind(NodeKind::Group(Group::default()));
-        marker::convert(node, &path, state, id_generator, &mut g, tree);
+        marker::convert(node, &path, (&fill, &stroke), state, id_generator, &mut g, tree);
+        g.detach();
         markers_group = Some(g);
     }
 
-    parent.append_kind(NodeKind::Path(Path {
+    let path = Path {
         id: node.element_id().to_string(),
         transform: Default::default(),
         visibility,
         fill,
         stroke,
+        paint_order,
         rendering_mode,
         text_bbox: None,
+        glyph_positions: Vec::new(),
         data: path,
-    }));
+    };
 
-    // Insert markers group after `path`.
-    if let Some(mut g) = markers_group {
-        g.detach();
-        parent.append(g);
+    use svgtypes::PaintOrderKind;
+    let markers_first = match raw_paint_order.order {
+        [PaintOrderKind::Markers, _, _] => true,
+        [PaintOrderKind::Fill, PaintOrderKind::Markers, _] => path.fill.is_none(),
+        [PaintOrderKind::Stroke, PaintOrderKind::Markers, _] => path.stroke.is_none(),
+        _ => false,
+    };
+
+    match raw_paint_order.order {
+        [first, PaintOrderKind::Markers, last]
+            if markers_group.is_some() && path.fill.is_some() && path.stroke.is_some() =>
+        {
+            // Markers are painted between the fill and the stroke,
+            // so the path has to be split into two.
+            let first_path = split_paint(&path, first);
+            let mut last_path = split_paint(&path, last);
+            last_path.id = String::new();
+
+            parent.append_kind(NodeKind::Path(first_path));
+
+            if let Some(g) = markers_group {
+                parent.append(g);
+            }
+
+            parent.append_kind(NodeKind::Path(last_path));
+        }
+        _ if markers_first => {
+            if let Some(g) = markers_group {
+                parent.append(g);
+            }
+
+            parent.append_kind(NodeKind::Path(path));
+        }
+        _ => {
+            parent.append_kind(NodeKind::Path(path));
+
+            if let Some(g) = markers_group {
+                parent.append(g);
+            }
+        }
+    }
+}
+
+// Creates a copy of the path with only the fill or only the stroke.
+fn split_paint(path: &Path, kind: svgtypes::PaintOrderKind) -> Path {
+    let mut path = path.clone();
+    match kind {
+        svgtypes::PaintOrderKind::Fill => path.stroke = None,
+        svgtypes::PaintOrderKind::Stroke => path.fill = None,
+        svgtypes::PaintOrderKind::Markers => {}
+    }
+
+    path
+}
+
+pub(crate) fn convert_paint_order(order: svgtypes::PaintOrder) -> PaintOrder {
+    let fill = order.order.iter().position(|k| *k == svgtypes::PaintOrderKind::Fill);
+    let stroke = order.order.iter().position(|k| *k == svgtypes::PaintOrderKind::Stroke);
+    if stroke < fill {
+        PaintOrder::StrokeAndFill
+    } else {
+        PaintOrder::FillAndStroke
     }
 }
@@ -23,6 +23,11 @@ pub enum Error {
 
     /// Failed to parse an SVG data.
     ParsingFailed(roxmltree::Error),
+
+    /// Multiple elements have the same `id`.
+    ///
+    /// Occurs only with [`DuplicateIdHandling::Error`](crate::DuplicateIdHandling::Error).
+    DuplicateId(String),
 }
 
 impl From<roxmltree::Error> for Error {
@@ -49,6 +54,9 @@ impl std::fmt::Display for Error {
             Error::ParsingFailed(ref e) => {
                 write!(f, "SVG data parsing failed cause {}", e)
             }
+            Error::DuplicateId(ref id) => {
+                write!(f, "element ID '{}' is not unique", id)
+            }
         }
     }
 }
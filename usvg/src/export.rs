@@ -102,6 +102,34 @@ fn conv_defs(tree: &Tree, opt: &XmlOptions, xml: &mut XmlWriter) {
 
                 xml.end_element();
             }
+            NodeKind::Hatch(ref hatch) => {
+                xml.start_svg_element(EId::Hatch);
+                xml.write_id_attribute(&hatch.id, opt);
+                xml.write_svg_attribute(AId::X, &hatch.x);
+                xml.write_svg_attribute(AId::Y, &hatch.y);
+                xml.write_svg_attribute(AId::Pitch, &hatch.pitch.get());
+                xml.write_svg_attribute(AId::Rotate, &hatch.rotate);
+                xml.write_units(AId::HatchUnits, hatch.units, Units::ObjectBoundingBox);
+                xml.write_units(AId::HatchContentUnits, hatch.content_units, Units::UserSpaceOnUse);
+                xml.write_transform(AId::Transform, hatch.transform);
+
+                // `period` is not an SVG attribute,
+                // but it will be resolved from the same paths once again.
+                for child in n.children() {
+                    if let NodeKind::Path(ref path) = *child.borrow() {
+                        xml.start_svg_element(EId::Hatchpath);
+                        if !path.id.is_empty() {
+                            xml.write_id_attribute(&path.id, opt);
+                        }
+
+                        write_stroke(&path.stroke, opt, xml);
+                        write_path_data(&path.data, xml);
+                        xml.end_element();
+                    }
+                }
+
+                xml.end_element();
+            }
             #[cfg(feature = "filter")]
             NodeKind::Filter(ref filter) => {
                 xml.start_svg_element(EId::Filter);
@@ -135,6 +163,13 @@ fn conv_defs(tree: &Tree, opt: &XmlOptions, xml: &mut XmlWriter) {
                                 AId::StdDeviation.to_str(),
                                 format_args!("{} {}", blur.std_dev_x.get(), blur.std_dev_y.get()),
                             );
+                            if blur.edge_mode != filter::EdgeMode::None {
+                                xml.write_svg_attribute(AId::EdgeMode, match blur.edge_mode {
+                                    filter::EdgeMode::None => "none",
+                                    filter::EdgeMode::Duplicate => "duplicate",
+                                    filter::EdgeMode::Wrap => "wrap",
+                                });
+                            }
                             xml.write_svg_attribute(AId::Result, &fe.result);
                             xml.end_element();
                         }
@@ -510,6 +545,10 @@ fn conv_element(
                 xml.write_enable_background(eb);
             }
 
+            if let Some(c) = g.background_color {
+                xml.write_color(AId::BackgroundColor, c);
+            }
+
             conv_elements(node, false, opt, xml);
 
             xml.end_element();
@@ -765,10 +804,17 @@ impl XmlWriterExt for XmlWriter {
             crate::ImageKind::GIF(ref data) => {
                 ("gif", data.as_slice())
             }
+            crate::ImageKind::TIFF(ref data) => {
+                ("tiff", data.as_slice())
+            }
             crate::ImageKind::SVG(ref tree) => {
                 svg_string = tree.to_string(&XmlOptions::default());
                 ("svg+xml", svg_string.as_bytes())
             }
+            crate::ImageKind::RGBA8(..) => {
+                log::warn!("Decoded images cannot be exported. Skipped.");
+                return;
+            }
         };
 
         self.write_attribute_raw("xlink:href", |buf| {
@@ -846,6 +892,10 @@ fn write_path(
 
     xml.write_visibility(path.visibility);
 
+    if path.paint_order == PaintOrder::StrokeAndFill {
+        xml.write_svg_attribute(AId::PaintOrder, "stroke");
+    }
+
     match path.rendering_mode {
         ShapeRendering::OptimizeSpeed => {
             xml.write_svg_attribute(AId::ShapeRendering, "optimizeSpeed");
@@ -861,9 +911,14 @@ fn write_path(
     }
 
     xml.write_transform(AId::Transform, path.transform);
+    write_path_data(&path.data, xml);
 
+    xml.end_element();
+}
+
+fn write_path_data(data: &PathData, xml: &mut XmlWriter) {
     xml.write_attribute_raw("d", |buf| {
-        for seg in path.data.iter() {
+        for seg in data.iter() {
             match *seg {
                 PathSegment::MoveTo { x, y } => {
                     buf.extend_from_slice(b"M ");
@@ -900,12 +955,10 @@ fn write_path(
             }
         }
 
-        if !path.data.is_empty() {
+        if !data.is_empty() {
             buf.pop();
         }
     });
-
-    xml.end_element();
 }
 
 fn write_fill(
@@ -971,6 +1024,12 @@ fn write_stroke(
             LineJoin::Bevel => xml.write_svg_attribute(AId::StrokeLinejoin, "bevel"),
         }
 
+        match stroke.alignment {
+            StrokeAlignment::Center => {}
+            StrokeAlignment::Inner => xml.write_svg_attribute(AId::StrokeAlignment, "inner"),
+            StrokeAlignment::Outer => xml.write_svg_attribute(AId::StrokeAlignment, "outer"),
+        }
+
         if let Some(ref array) = stroke.dasharray {
             xml.write_numbers(AId::StrokeDasharray, array);
         }
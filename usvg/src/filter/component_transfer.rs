@@ -28,6 +28,22 @@ pub struct ComponentTransfer {
     pub func_a: TransferFunction,
 }
 
+impl ComponentTransfer {
+    /// Creates a new `ComponentTransfer` that applies the same function
+    /// to the red, green and blue channels.
+    ///
+    /// The alpha channel is left as is and `input` is set to `SourceGraphic`.
+    pub fn uniform(func: TransferFunction) -> Self {
+        ComponentTransfer {
+            input: Input::SourceGraphic,
+            func_r: func.clone(),
+            func_g: func.clone(),
+            func_b: func,
+            func_a: TransferFunction::Identity,
+        }
+    }
+}
+
 /// A transfer function used by `FeComponentTransfer`.
 ///
 /// <https://www.w3.org/TR/SVG11/filters.html#transferFuncElements>
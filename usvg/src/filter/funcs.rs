@@ -7,8 +7,100 @@ use strict_num::PositiveF64;
 
 use crate::svgtree::{self, AId};
 use crate::{converter, Units, SvgColorExt};
-use super::{ColorMatrix, ColorMatrixKind, ComponentTransfer, DropShadow};
-use super::{GaussianBlur, Input, Kind, TransferFunction};
+use super::{ColorInterpolation, ColorMatrix, ColorMatrixKind, ComponentTransfer, Composite};
+use super::{CompositeOperator, DropShadow, EdgeMode, Flood, GaussianBlur, Input, Kind, Merge};
+use super::{Morphology, MorphologyOperator, Offset, Primitive, TransferFunction};
+
+/// Creates a filter function primitive that covers the whole filter region.
+pub fn base_primitive(kind: Kind) -> Primitive {
+    primitive("result", kind)
+}
+
+fn primitive(result: &str, kind: Kind) -> Primitive {
+    Primitive {
+        x: None,
+        y: None,
+        width: None,
+        height: None,
+        // Unlike `filter` elements, filter functions use sRGB colors by default.
+        color_interpolation: ColorInterpolation::SRGB,
+        result: result.to_string(),
+        kind,
+    }
+}
+
+/// Removes the spread radius from `drop-shadow()` functions.
+///
+/// Returns the remaining filter value and the spread radius of each `drop-shadow()` in order.
+/// A function without a spread radius will have zero.
+pub fn split_drop_shadow_spread(value: &str) -> (String, Vec<Length>) {
+    const PREFIX: &str = "drop-shadow(";
+
+    let mut text = String::with_capacity(value.len());
+    let mut spreads = Vec::new();
+    let mut rest = value;
+    while let Some(idx) = rest.find(PREFIX) {
+        text.push_str(&rest[..idx + PREFIX.len()]);
+        rest = &rest[idx + PREFIX.len()..];
+
+        let (args, func_end) = split_func_args(rest);
+        let lengths: Vec<_> = args.iter()
+            .filter_map(|&(start, end)| rest[start..end].parse::<Length>().ok().map(|l| (l, start, end)))
+            .collect();
+
+        // `drop-shadow(color? dx dy std_dev? spread?)`
+        // The spread radius can be set only when the standard deviation is set too.
+        if let [_, _, _, (spread, start, end)] = lengths[..] {
+            text.push_str(&rest[..start]);
+            text.push_str(&rest[end..func_end]);
+            spreads.push(spread);
+        } else {
+            text.push_str(&rest[..func_end]);
+            spreads.push(Length::zero());
+        }
+
+        rest = &rest[func_end..];
+    }
+
+    text.push_str(rest);
+    (text, spreads)
+}
+
+// Returns byte ranges of whitespace separated arguments and the closing parenthesis position.
+fn split_func_args(text: &str) -> (Vec<(usize, usize)>, usize) {
+    let mut args = Vec::new();
+    let mut depth = 0;
+    let mut start = None;
+    for (i, c) in text.char_indices() {
+        if depth == 0 && (c == ')' || c.is_ascii_whitespace()) {
+            if let Some(start) = start.take() {
+                args.push((start, i));
+            }
+
+            if c == ')' {
+                return (args, i);
+            }
+
+            continue;
+        }
+
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ => {}
+        }
+
+        if start.is_none() {
+            start = Some(i);
+        }
+    }
+
+    if let Some(start) = start {
+        args.push((start, text.len()));
+    }
+
+    (args, text.len())
+}
 
 #[inline(never)]
 pub fn convert_grayscale(mut amount: f64) -> Kind {
@@ -88,13 +180,9 @@ pub fn convert_hue_rotate(amount: svgtypes::Angle) -> Kind {
 #[inline(never)]
 pub fn convert_invert(mut amount: f64) -> Kind {
     amount = amount.min(1.0);
-    Kind::ComponentTransfer(ComponentTransfer {
-        input: Input::SourceGraphic,
-        func_r: TransferFunction::Table(vec![amount, 1.0 - amount]),
-        func_g: TransferFunction::Table(vec![amount, 1.0 - amount]),
-        func_b: TransferFunction::Table(vec![amount, 1.0 - amount]),
-        func_a: TransferFunction::Identity,
-    })
+    Kind::ComponentTransfer(ComponentTransfer::uniform(
+        TransferFunction::Table(vec![amount, 1.0 - amount])
+    ))
 }
 
 #[inline(never)]
@@ -111,24 +199,16 @@ pub fn convert_opacity(mut amount: f64) -> Kind {
 
 #[inline(never)]
 pub fn convert_brightness(amount: f64) -> Kind {
-    Kind::ComponentTransfer(ComponentTransfer {
-        input: Input::SourceGraphic,
-        func_r: TransferFunction::Linear { slope: amount, intercept: 0.0 },
-        func_g: TransferFunction::Linear { slope: amount, intercept: 0.0 },
-        func_b: TransferFunction::Linear { slope: amount, intercept: 0.0 },
-        func_a: TransferFunction::Identity,
-    })
+    Kind::ComponentTransfer(ComponentTransfer::uniform(
+        TransferFunction::Linear { slope: amount, intercept: 0.0 }
+    ))
 }
 
 #[inline(never)]
 pub fn convert_contrast(amount: f64) -> Kind {
-    Kind::ComponentTransfer(ComponentTransfer {
-        input: Input::SourceGraphic,
-        func_r: TransferFunction::Linear { slope: amount, intercept: -(0.5 * amount) + 0.5 },
-        func_g: TransferFunction::Linear { slope: amount, intercept: -(0.5 * amount) + 0.5 },
-        func_b: TransferFunction::Linear { slope: amount, intercept: -(0.5 * amount) + 0.5 },
-        func_a: TransferFunction::Identity,
-    })
+    Kind::ComponentTransfer(ComponentTransfer::uniform(
+        TransferFunction::Linear { slope: amount, intercept: -(0.5 * amount) + 0.5 }
+    ))
 }
 
 #[inline(never)]
@@ -144,6 +224,7 @@ pub fn convert_blur(
         input: Input::SourceGraphic,
         std_dev_x: std_dev,
         std_dev_y: std_dev,
+        edge_mode: EdgeMode::None,
     })
 }
 
@@ -154,8 +235,9 @@ pub fn convert_drop_shadow(
     dx: Length,
     dy: Length,
     std_dev: Length,
+    spread: f64,
     state: &converter::State,
-) -> Kind {
+) -> Vec<Primitive> {
     let std_dev = PositiveF64::new(
         crate::units::convert_length(std_dev, node, AId::Dx, Units::UserSpaceOnUse, state)
     ).unwrap_or(PositiveF64::ZERO);
@@ -163,13 +245,56 @@ pub fn convert_drop_shadow(
     let (color, opacity) = color.unwrap_or_else(||
         node.find_attribute(AId::Color).unwrap_or_else(svgtypes::Color::black)).split_alpha();
 
-    Kind::DropShadow(DropShadow {
-        input: Input::SourceGraphic,
-        dx: crate::units::convert_length(dx, node, AId::Dx, Units::UserSpaceOnUse, state),
-        dy: crate::units::convert_length(dy, node, AId::Dy, Units::UserSpaceOnUse, state),
-        std_dev_x: std_dev,
-        std_dev_y: std_dev,
-        color,
-        opacity,
-    })
+    let dx = crate::units::convert_length(dx, node, AId::Dx, Units::UserSpaceOnUse, state);
+    let dy = crate::units::convert_length(dy, node, AId::Dy, Units::UserSpaceOnUse, state);
+
+    if !spread.is_normal() {
+        return vec![base_primitive(Kind::DropShadow(DropShadow {
+            input: Input::SourceGraphic,
+            dx,
+            dy,
+            std_dev_x: std_dev,
+            std_dev_y: std_dev,
+            color,
+            opacity,
+        }))];
+    }
+
+    // `feDropShadow` can't change the shadow size, so we have to build it manually,
+    // growing or shrinking the source alpha before blurring it.
+    let operator = if spread > 0.0 { MorphologyOperator::Dilate } else { MorphologyOperator::Erode };
+    let radius = PositiveF64::new(spread.abs()).unwrap_or(PositiveF64::ZERO);
+    let reference = |name: &str| Input::Reference(name.to_string());
+
+    vec![
+        primitive("spread", Kind::Morphology(Morphology {
+            input: Input::SourceAlpha,
+            operator,
+            radius_x: radius,
+            radius_y: radius,
+        })),
+        primitive("blur", Kind::GaussianBlur(GaussianBlur {
+            input: reference("spread"),
+            std_dev_x: std_dev,
+            std_dev_y: std_dev,
+            edge_mode: EdgeMode::None,
+        })),
+        primitive("offset", Kind::Offset(Offset {
+            input: reference("blur"),
+            dx,
+            dy,
+        })),
+        primitive("flood", Kind::Flood(Flood {
+            color,
+            opacity,
+        })),
+        primitive("shadow", Kind::Composite(Composite {
+            input1: reference("flood"),
+            input2: reference("offset"),
+            operator: CompositeOperator::In,
+        })),
+        primitive("result", Kind::Merge(Merge {
+            inputs: vec![reference("shadow"), Input::SourceGraphic],
+        })),
+    ]
 }
@@ -5,7 +5,7 @@
 use strict_num::PositiveF64;
 
 use crate::svgtree::{self, AId};
-use super::{Input, Kind, Primitive};
+use super::{EdgeMode, Input, Kind, Primitive};
 
 /// A Gaussian blur filter primitive.
 ///
@@ -26,14 +26,28 @@ pub struct GaussianBlur {
     ///
     /// `stdDeviation` in the SVG.
     pub std_dev_y: PositiveF64,
+
+    /// An edges processing mode.
+    ///
+    /// `edgeMode` in the SVG 2.
+    pub edge_mode: EdgeMode,
 }
 
 pub(crate) fn convert(fe: svgtree::Node, primitives: &[Primitive]) -> Kind {
     let (std_dev_x, std_dev_y) = convert_std_dev_attr(fe, "0 0");
+
+    // Unlike `feConvolveMatrix`, the default value is `none`.
+    let edge_mode = match fe.attribute(AId::EdgeMode).unwrap_or("none") {
+        "duplicate" => EdgeMode::Duplicate,
+        "wrap"      => EdgeMode::Wrap,
+        _           => EdgeMode::None,
+    };
+
     Kind::GaussianBlur(GaussianBlur {
         input: super::resolve_input(fe, AId::In, primitives),
         std_dev_x,
         std_dev_y,
+        edge_mode,
     })
 }
 
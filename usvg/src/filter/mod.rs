@@ -201,7 +201,7 @@ pub(crate) fn convert(
     let mut has_invalid_urls = false;
     let mut ids = Vec::new();
 
-    let mut create_base_filter_func = |kind, ids: &mut Vec<String>, tree: &mut Tree| {
+    let mut create_filter_func = |primitives: Vec<Primitive>, ids: &mut Vec<String>, tree: &mut Tree| {
         let id = id_generator.gen_filter_id();
         ids.push(id.clone());
 
@@ -209,11 +209,11 @@ pub(crate) fn convert(
         // We're currently do not support an unlimited region, so we simply use a fairly large one.
         // This if far from ideal, but good for now.
         // TODO: Should be fixed eventually.
-        let rect = match kind {
-            Kind::DropShadow(_) | Kind::GaussianBlur(_) => {
-                Rect::new(-1.0, -1.0, 2.0, 2.0).unwrap()
-            }
-            _ => Rect::new(-0.1, -0.1, 1.2, 1.2).unwrap(),
+        let is_blur = primitives.iter().any(|p| matches!(p.kind, Kind::DropShadow(_) | Kind::GaussianBlur(_)));
+        let rect = if is_blur {
+            Rect::new(-1.0, -1.0, 2.0, 2.0).unwrap()
+        } else {
+            Rect::new(-0.1, -0.1, 1.2, 1.2).unwrap()
         };
 
         tree.append_to_defs(NodeKind::Filter(Filter {
@@ -221,22 +221,15 @@ pub(crate) fn convert(
             units: Units::ObjectBoundingBox,
             primitive_units: Units::UserSpaceOnUse,
             rect,
-            primitives: vec![
-                Primitive {
-                    x: None,
-                    y: None,
-                    width: None,
-                    height: None,
-                    // Unlike `filter` elements, filter functions use sRGB colors by default.
-                    color_interpolation: ColorInterpolation::SRGB,
-                    result: "result".to_string(),
-                    kind,
-                },
-            ],
+            primitives,
         }));
     };
 
-    for func in svgtypes::FilterValueListParser::from(value) {
+    // `svgtypes` doesn't support the `drop-shadow()` spread radius, so we have to extract it first.
+    let (value, spreads) = funcs::split_drop_shadow_spread(value);
+    let mut spreads = spreads.into_iter();
+
+    for func in svgtypes::FilterValueListParser::from(value.as_str()) {
         let func = match func {
             Ok(v) => v,
             Err(e) => {
@@ -246,41 +239,23 @@ pub(crate) fn convert(
             }
         };
 
-        match func {
-            svgtypes::FilterValue::Blur(std_dev) => {
-                create_base_filter_func(funcs::convert_blur(node, std_dev, state), &mut ids, tree)
-            }
+        let kind = match func {
+            svgtypes::FilterValue::Blur(std_dev) => funcs::convert_blur(node, std_dev, state),
             svgtypes::FilterValue::DropShadow { color, dx, dy, std_dev } => {
-                create_base_filter_func(
-                    funcs::convert_drop_shadow(node, color, dx, dy, std_dev, state),
-                    &mut ids,
-                    tree,
-                )
-            }
-            svgtypes::FilterValue::Brightness(amount) => {
-                create_base_filter_func(funcs::convert_brightness(amount), &mut ids, tree)
-            }
-            svgtypes::FilterValue::Contrast(amount) => {
-                create_base_filter_func(funcs::convert_contrast(amount), &mut ids, tree)
-            }
-            svgtypes::FilterValue::Grayscale(amount) => {
-                create_base_filter_func(funcs::convert_grayscale(amount), &mut ids, tree)
-            }
-            svgtypes::FilterValue::HueRotate(angle) => {
-                create_base_filter_func(funcs::convert_hue_rotate(angle), &mut ids, tree)
-            }
-            svgtypes::FilterValue::Invert(amount) => {
-                create_base_filter_func(funcs::convert_invert(amount), &mut ids, tree)
-            }
-            svgtypes::FilterValue::Opacity(amount) => {
-                create_base_filter_func(funcs::convert_opacity(amount), &mut ids, tree)
-            }
-            svgtypes::FilterValue::Sepia(amount) => {
-                create_base_filter_func(funcs::convert_sepia(amount), &mut ids, tree)
-            }
-            svgtypes::FilterValue::Saturate(amount) => {
-                create_base_filter_func(funcs::convert_saturate(amount), &mut ids, tree)
+                let spread = spreads.next().unwrap_or_else(Length::zero);
+                let spread = crate::units::convert_length(spread, node, AId::Dx, Units::UserSpaceOnUse, state);
+                let primitives = funcs::convert_drop_shadow(node, color, dx, dy, std_dev, spread, state);
+                create_filter_func(primitives, &mut ids, tree);
+                continue;
             }
+            svgtypes::FilterValue::Brightness(amount) => funcs::convert_brightness(amount),
+            svgtypes::FilterValue::Contrast(amount) => funcs::convert_contrast(amount),
+            svgtypes::FilterValue::Grayscale(amount) => funcs::convert_grayscale(amount),
+            svgtypes::FilterValue::HueRotate(angle) => funcs::convert_hue_rotate(angle),
+            svgtypes::FilterValue::Invert(amount) => funcs::convert_invert(amount),
+            svgtypes::FilterValue::Opacity(amount) => funcs::convert_opacity(amount),
+            svgtypes::FilterValue::Sepia(amount) => funcs::convert_sepia(amount),
+            svgtypes::FilterValue::Saturate(amount) => funcs::convert_saturate(amount),
             svgtypes::FilterValue::Url(url) => {
                 if let Some(link) = node.document().element_by_id(url) {
                     if let Ok(res) = convert_url(link, state, tree) {
@@ -293,8 +268,12 @@ pub(crate) fn convert(
                 } else {
                     has_invalid_urls = true;
                 }
+
+                continue;
             }
-        }
+        };
+
+        create_filter_func(vec![funcs::base_primitive(kind)], &mut ids, tree);
     }
 
     // If a `filter` attribute had urls pointing to a missing elements
@@ -475,6 +454,13 @@ fn resolve_input(
             // to previous result or `SourceGraphic`.
             if let Input::Reference(ref name) = input {
                 if !primitives.iter().any(|p| p.result == *name) {
+                    // A reference cycle is handled like any other unknown reference,
+                    // as required by the spec. It's still likely an error, so report it.
+                    if is_cyclic_reference(node, name) {
+                        log::warn!("Filter primitive input '{}' creates a reference cycle. \
+                                    The previous result will be used instead.", name);
+                    }
+
                     return if let Some(prev) = primitives.last() {
                         Input::Reference(prev.result.clone())
                     } else {
@@ -499,6 +485,75 @@ fn resolve_input(
     }
 }
 
+// Checks that `name` references a following filter primitive,
+// which inputs depend on the primitive of the `node` itself.
+fn is_cyclic_reference(node: svgtree::Node, name: &str) -> bool {
+    // `feMergeNode` inputs belong to the parent `feMerge`.
+    let fe = if node.has_tag_name(EId::FeMergeNode) { node.parent_element() } else { Some(node) };
+    let filter = match fe.and_then(|fe| fe.parent_element()) {
+        Some(v) => v,
+        None => return false,
+    };
+
+    let primitives: Vec<_> = filter.children()
+        .filter(|n| n.tag_name().map(|eid| eid.is_filter_primitive()).unwrap_or(false))
+        .collect();
+
+    let start = match primitives.iter().position(|n| Some(*n) == fe) {
+        Some(v) => v,
+        None => return false,
+    };
+
+    let target = match find_result(&primitives, start, name) {
+        Some(idx) if idx > start => idx,
+        _ => return false,
+    };
+
+    let mut visited = vec![false; primitives.len()];
+    let mut stack = vec![target];
+    while let Some(idx) = stack.pop() {
+        if idx == start {
+            return true;
+        }
+
+        if !std::mem::replace(&mut visited[idx], true) {
+            stack.extend(primitive_inputs(&primitives, idx));
+        }
+    }
+
+    false
+}
+
+// Returns indices of primitives that are used as inputs by the primitive at `idx`.
+fn primitive_inputs(primitives: &[svgtree::Node], idx: usize) -> Vec<usize> {
+    let fe = primitives[idx];
+    let names: Vec<Option<&str>> = match fe.tag_name() {
+        Some(EId::FeFlood) | Some(EId::FeImage) | Some(EId::FeTurbulence) => Vec::new(),
+        Some(EId::FeBlend) | Some(EId::FeComposite) | Some(EId::FeDisplacementMap) => {
+            vec![fe.attribute(AId::In), fe.attribute(AId::In2)]
+        }
+        Some(EId::FeMerge) => fe.children().map(|n| n.attribute(AId::In)).collect(),
+        _ => vec![fe.attribute(AId::In)],
+    };
+
+    names.into_iter().filter_map(|name| match name {
+        // An implicit input is the previous result.
+        None => idx.checked_sub(1),
+        Some(name) => match parse_in(name) {
+            Input::Reference(_) => find_result(primitives, idx, name),
+            _ => None,
+        }
+    }).collect()
+}
+
+// Finds the closest previous primitive with the specified `result`
+// or the first next one when there are no previous.
+fn find_result(primitives: &[svgtree::Node], idx: usize, name: &str) -> Option<usize> {
+    let has_result = |n: &svgtree::Node| n.attribute::<&str>(AId::Result) == Some(name);
+    primitives[..idx].iter().rposition(has_result)
+        .or_else(|| primitives[idx + 1..].iter().position(has_result).map(|i| idx + 1 + i))
+}
+
 fn parse_in(
     s: &str,
 ) -> Input {
@@ -0,0 +1,247 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Fixing of common XML errors. See `Options::fix_invalid_xml`.
+
+/// A list of changes made by `fix`.
+#[derive(Clone, Copy, Default, Debug)]
+pub(crate) struct Fixes {
+    ampersands: usize,
+    attribute_values: usize,
+    void_elements: usize,
+}
+
+impl Fixes {
+    pub fn is_empty(&self) -> bool {
+        self.ampersands == 0 && self.attribute_values == 0 && self.void_elements == 0
+    }
+}
+
+impl std::fmt::Display for Fixes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let list = [
+            (self.ampersands, "escaped '&'"),
+            (self.attribute_values, "quoted attribute values"),
+            (self.void_elements, "closed void elements"),
+        ];
+
+        let mut is_first = true;
+        for &(count, name) in list.iter().filter(|(count, _)| *count != 0) {
+            if !is_first {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{} {}", count, name)?;
+            is_first = false;
+        }
+
+        Ok(())
+    }
+}
+
+/// Fixes common errors in an XML produced by HTML tools.
+///
+/// - Escapes `&` that doesn't start a character or entity reference.
+/// - Quotes unquoted attribute values.
+/// - Closes HTML void elements, like `<br>`.
+///
+/// Comments, CDATA sections, processing instructions and DTD are preserved as is.
+pub(crate) fn fix(text: &str) -> (String, Fixes) {
+    let mut out = String::with_capacity(text.len() + 64);
+    let mut fixes = Fixes::default();
+
+    let mut pos = 0;
+    while pos < text.len() {
+        let rest = &text[pos..];
+        if rest.starts_with("<!--") {
+            pos += copy_until(rest, "-->", &mut out);
+        } else if rest.starts_with("<![CDATA[") {
+            pos += copy_until(rest, "]]>", &mut out);
+        } else if rest.starts_with("<?") {
+            pos += copy_until(rest, "?>", &mut out);
+        } else if rest.starts_with("<!") {
+            pos += copy_doctype(rest, &mut out);
+        } else if rest.starts_with("</") {
+            pos += copy_until(rest, ">", &mut out);
+        } else if rest.starts_with('<') && rest[1..].starts_with(is_name_start) {
+            pos += fix_start_tag(text, pos, &mut out, &mut fixes);
+        } else if rest.starts_with('&') {
+            fixes.ampersands += push_ampersand(rest, &mut out);
+            pos += 1;
+        } else {
+            // The first char can be a multi-byte one.
+            let first = rest.chars().next().unwrap().len_utf8();
+            let len = rest[first..].find(&['<', '&'][..]).map_or(rest.len(), |i| i + first);
+            out.push_str(&rest[..len]);
+            pos += len;
+        }
+    }
+
+    (out, fixes)
+}
+
+// Copies `text` up to and including `end`, or the whole `text` when `end` is missing.
+fn copy_until(text: &str, end: &str, out: &mut String) -> usize {
+    let len = text.find(end).map_or(text.len(), |i| i + end.len());
+    out.push_str(&text[..len]);
+    len
+}
+
+// `<!DOCTYPE` can have an internal subset with its own `>`.
+fn copy_doctype(text: &str, out: &mut String) -> usize {
+    let mut depth = 0;
+    let mut len = text.len();
+    for (i, c) in text.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => depth -= 1,
+            '>' if depth <= 0 => {
+                len = i + 1;
+                break;
+            }
+            _ => {}
+        }
+    }
+
+    out.push_str(&text[..len]);
+    len
+}
+
+fn fix_start_tag(text: &str, start: usize, out: &mut String, fixes: &mut Fixes) -> usize {
+    let rest = &text[start..];
+    let name_len = rest.find(|c: char| c.is_ascii_whitespace() || c == '/' || c == '>')
+        .unwrap_or(rest.len());
+    let name = &rest[1..name_len];
+    out.push_str(&rest[..name_len]);
+
+    let mut pos = name_len;
+    while pos < rest.len() {
+        let s = &rest[pos..];
+        let c = s.chars().next().unwrap();
+        if c.is_ascii_whitespace() {
+            out.push(c);
+            pos += 1;
+        } else if s.starts_with("/>") {
+            out.push_str("/>");
+            return pos + 2;
+        } else if c == '>' {
+            pos += 1;
+            let is_closed = rest[pos..].trim_start().starts_with(&format!("</{}", name));
+            if is_void_element(name) && !is_closed {
+                out.push_str("/>");
+                fixes.void_elements += 1;
+            } else {
+                out.push('>');
+            }
+
+            return pos;
+        } else if c == '=' {
+            out.push('=');
+            pos += 1;
+
+            let ws_len = rest[pos..].len() - rest[pos..].trim_start().len();
+            out.push_str(&rest[pos..pos + ws_len]);
+            pos += ws_len;
+
+            pos += fix_attribute_value(&rest[pos..], out, fixes);
+        } else {
+            // An attribute name or some garbage.
+            let len = s.find(|c: char| c.is_ascii_whitespace() || c == '=' || c == '>' || c == '/')
+                .unwrap_or(s.len())
+                .max(c.len_utf8());
+            out.push_str(&s[..len]);
+            pos += len;
+        }
+    }
+
+    rest.len()
+}
+
+fn fix_attribute_value(text: &str, out: &mut String, fixes: &mut Fixes) -> usize {
+    let quote = match text.chars().next() {
+        Some(c) if c == '"' || c == '\'' => Some(c),
+        Some(_) => None,
+        None => return 0,
+    };
+
+    let (value, len) = match quote {
+        Some(quote) => {
+            match text[1..].find(quote) {
+                Some(i) => (&text[1..i + 1], i + 2),
+                // An unclosed quote cannot be fixed.
+                None => {
+                    out.push_str(text);
+                    return text.len();
+                }
+            }
+        }
+        None => {
+            let mut len = text.find(|c: char| c.is_ascii_whitespace() || c == '>')
+                .unwrap_or(text.len());
+            // `<rect width=10/>` is more likely a self-closing tag than a value with a slash.
+            if text[..len].ends_with('/') && text[len..].starts_with('>') {
+                len -= 1;
+            }
+
+            fixes.attribute_values += 1;
+            (&text[..len], len)
+        }
+    };
+
+    let quote = quote.unwrap_or(if value.contains('"') { '\'' } else { '"' });
+    out.push(quote);
+    let mut pos = 0;
+    while let Some(i) = value[pos..].find('&') {
+        out.push_str(&value[pos..pos + i]);
+        fixes.ampersands += push_ampersand(&value[pos + i..], out);
+        pos += i + 1;
+    }
+    out.push_str(&value[pos..]);
+    out.push(quote);
+
+    len
+}
+
+// Pushes `&` or `&amp;` when `text` doesn't start with a valid reference.
+fn push_ampersand(text: &str, out: &mut String) -> usize {
+    if is_reference(text) {
+        out.push('&');
+        0
+    } else {
+        out.push_str("&amp;");
+        1
+    }
+}
+
+fn is_reference(text: &str) -> bool {
+    let end = match text.find(';') {
+        Some(i) => i,
+        None => return false,
+    };
+
+    let name = &text[1..end];
+    if let Some(code) = name.strip_prefix("#x") {
+        !code.is_empty() && code.chars().all(|c| c.is_ascii_hexdigit())
+    } else if let Some(code) = name.strip_prefix('#') {
+        !code.is_empty() && code.chars().all(|c| c.is_ascii_digit())
+    } else {
+        name.starts_with(is_name_start)
+            && name.chars().all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | ':'))
+    }
+}
+
+fn is_name_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_' || c == ':'
+}
+
+fn is_void_element(name: &str) -> bool {
+    // Elements can have a namespace prefix, like `xhtml:br`.
+    let name = name.rsplit(':').next().unwrap_or(name);
+    const VOID_ELEMENTS: &[&str] = &[
+        "area", "base", "br", "col", "embed", "hr", "img", "input",
+        "link", "meta", "param", "source", "track", "wbr",
+    ];
+
+    VOID_ELEMENTS.iter().any(|n| n.eq_ignore_ascii_case(name))
+}
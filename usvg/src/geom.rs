@@ -1061,6 +1061,26 @@ impl Transform {
         *x = self.a * tx + self.c * ty + self.e;
         *y = self.b * tx + self.d * ty + self.f;
     }
+
+    /// Returns an inverted transform.
+    ///
+    /// Returns `None` when the transform is not invertible.
+    pub fn invert(&self) -> Option<Self> {
+        let det = self.a * self.d - self.b * self.c;
+        if det.is_fuzzy_zero() || !det.is_finite() {
+            return None;
+        }
+
+        let inv_det = 1.0 / det;
+        Some(Transform {
+            a: self.d * inv_det,
+            b: -self.b * inv_det,
+            c: -self.c * inv_det,
+            d: self.a * inv_det,
+            e: (self.c * self.f - self.d * self.e) * inv_det,
+            f: (self.b * self.e - self.a * self.f) * inv_det,
+        })
+    }
 }
 
 #[inline(never)]
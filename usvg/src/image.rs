@@ -5,7 +5,10 @@
 use std::sync::Arc;
 use svgtypes::Length;
 
-use crate::{ImageRendering, Node, NodeExt, NodeKind, OptionLog, OptionsRef, Tree, Visibility, converter};
+const SVG_NS: &str = "http://www.w3.org/2000/svg";
+const XLINK_NS: &str = "http://www.w3.org/1999/xlink";
+
+use crate::{ImageRendering, ScreenSize, Node, NodeExt, NodeKind, OptionLog, OptionsRef, Tree, Visibility, converter};
 use crate::geom::{Rect, Transform, ViewBox};
 use crate::svgtree::{self, AId};
 
@@ -14,6 +17,7 @@ enum ImageFormat {
     PNG,
     JPEG,
     GIF,
+    TIFF,
     SVG,
 }
 
@@ -26,8 +30,15 @@ pub enum ImageKind {
     PNG(Arc<Vec<u8>>),
     /// A reference to raw GIF data. Should be decoded by the caller.
     GIF(Arc<Vec<u8>>),
+    /// A reference to raw TIFF data. Should be decoded by the caller.
+    TIFF(Arc<Vec<u8>>),
     /// A preprocessed SVG tree. Can be rendered as is.
     SVG(crate::Tree),
+    /// Already decoded, premultiplied RGBA8 pixels. Can be rendered as is.
+    ///
+    /// Uses the same layout as `tiny_skia::Pixmap`, so a decoded image can be shared
+    /// between multiple trees and renders without copying or decoding it again.
+    RGBA8(ScreenSize, Arc<Vec<u8>>),
 }
 
 impl std::fmt::Debug for ImageKind {
@@ -36,7 +47,9 @@ impl std::fmt::Debug for ImageKind {
             ImageKind::JPEG(_) => f.write_str("ImageKind::JPEG(..)"),
             ImageKind::PNG(_) => f.write_str("ImageKind::PNG(..)"),
             ImageKind::GIF(_) => f.write_str("ImageKind::GIF(..)"),
+            ImageKind::TIFF(_) => f.write_str("ImageKind::TIFF(..)"),
             ImageKind::SVG(_) => f.write_str("ImageKind::SVG(..)"),
+            ImageKind::RGBA8(size, _) => write!(f, "ImageKind::RGBA8({:?}, ..)", size),
         }
     }
 }
@@ -78,7 +91,7 @@ impl ImageHrefResolver {
     ///
     /// base64 encoded data is already decoded.
     ///
-    /// The default implementation would try to load JPEG, PNG, GIF, SVG and SVGZ types.
+    /// The default implementation would try to load JPEG, PNG, GIF, TIFF, SVG and SVGZ types.
     /// Note that it will simply match the `mime` or data's magic.
     /// The actual images would not be decoded. It's up to the renderer.
     pub fn default_data_resolver() -> ImageHrefDataResolverFn {
@@ -87,11 +100,13 @@ impl ImageHrefResolver {
                 "image/jpg" | "image/jpeg" => Some(ImageKind::JPEG(data)),
                 "image/png" => Some(ImageKind::PNG(data)),
                 "image/gif" => Some(ImageKind::GIF(data)),
+                "image/tiff" => Some(ImageKind::TIFF(data)),
                 "image/svg+xml" => load_sub_svg(&data, opts),
                 "text/plain" => match get_image_data_format(&data) {
                     Some(ImageFormat::JPEG) => Some(ImageKind::JPEG(data)),
                     Some(ImageFormat::PNG) => Some(ImageKind::PNG(data)),
                     Some(ImageFormat::GIF) => Some(ImageKind::GIF(data)),
+                    Some(ImageFormat::TIFF) => Some(ImageKind::TIFF(data)),
                     _ => load_sub_svg(&data, opts),
                 },
                 _ => None,
@@ -106,8 +121,27 @@ impl ImageHrefResolver {
     ///
     /// Paths have to be absolute or relative to the input SVG file or relative to
     /// [Options::resources_dir](crate::Options::resources_dir).
+    ///
+    /// When [Options::resource_loader](crate::Options::resource_loader) is set,
+    /// the string is passed to it instead and the filesystem is not accessed.
     pub fn default_string_resolver() -> ImageHrefStringResolverFn {
         Box::new(move |href: &str, opts: &OptionsRef| {
+            if let Some(loader) = opts.resource_loader {
+                let data = match loader.load(href) {
+                    Some(data) => data,
+                    None => {
+                        log::warn!("Failed to load '{}'. Skipped.", href);
+                        return None;
+                    }
+                };
+
+                // `href` doesn't have to be a path, so an image without a known
+                // extension or magic is treated as an SVG, just like `text/plain` Data URLs.
+                let format = get_image_file_format(std::path::Path::new(href), &data)
+                    .unwrap_or(ImageFormat::SVG);
+                return load_image_data(href, Some(format), data, opts);
+            }
+
             let path = opts.get_abs_path(std::path::Path::new(href));
 
             if path.exists() {
@@ -119,16 +153,8 @@ impl ImageHrefResolver {
                     }
                 };
 
-                match get_image_file_format(&path, &data) {
-                    Some(ImageFormat::JPEG) => Some(ImageKind::JPEG(Arc::new(data))),
-                    Some(ImageFormat::PNG) => Some(ImageKind::PNG(Arc::new(data))),
-                    Some(ImageFormat::GIF) => Some(ImageKind::GIF(Arc::new(data))),
-                    Some(ImageFormat::SVG) => load_sub_svg(&data, opts),
-                    _ => {
-                        log::warn!("'{}' is not a PNG, JPEG, GIF or SVG(Z) image.", href);
-                        None
-                    }
-                }
+                let format = get_image_file_format(&path, &data);
+                load_image_data(href, format, data, opts)
             } else {
                 log::warn!("'{}' is not a path to an image.", href);
                 None
@@ -137,6 +163,25 @@ impl ImageHrefResolver {
     }
 }
 
+fn load_image_data(
+    href: &str,
+    format: Option<ImageFormat>,
+    data: Vec<u8>,
+    opts: &OptionsRef,
+) -> Option<ImageKind> {
+    match format {
+        Some(ImageFormat::JPEG) => Some(ImageKind::JPEG(Arc::new(data))),
+        Some(ImageFormat::PNG) => Some(ImageKind::PNG(Arc::new(data))),
+        Some(ImageFormat::GIF) => Some(ImageKind::GIF(Arc::new(data))),
+        Some(ImageFormat::TIFF) => Some(ImageKind::TIFF(Arc::new(data))),
+        Some(ImageFormat::SVG) => load_sub_svg(&data, opts),
+        _ => {
+            log::warn!("'{}' is not a PNG, JPEG, GIF, TIFF or SVG(Z) image.", href);
+            None
+        }
+    }
+}
+
 impl std::fmt::Debug for ImageHrefResolver {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str("ImageHrefResolver { .. }")
@@ -232,18 +277,62 @@ pub(crate) fn get_href_data(href: &str, opt: &OptionsRef) -> Option<ImageKind> {
     }
 }
 
-/// Checks that file has a PNG, a GIF or a JPEG magic bytes.
+/// Returns all external, non-Data URL, `xlink:href` references of `image` and `feImage` elements.
+///
+/// Duplicates are removed.
+pub(crate) fn collect_external_hrefs(doc: &roxmltree::Document) -> Vec<String> {
+    let mut hrefs: Vec<String> = Vec::new();
+    for node in doc.descendants() {
+        if node.tag_name().namespace() != Some(SVG_NS) {
+            continue;
+        }
+
+        if !matches!(node.tag_name().name(), "image" | "feImage") {
+            continue;
+        }
+
+        let href = node.attribute((XLINK_NS, "href")).or_else(|| node.attribute("href"));
+        let href = match href {
+            Some(href) => href,
+            None => continue,
+        };
+
+        // `feImage` can reference an element.
+        if href.starts_with('#') || data_url::DataUrl::process(href).is_ok() {
+            continue;
+        }
+
+        if !hrefs.iter().any(|s| s == href) {
+            hrefs.push(href.to_string());
+        }
+    }
+
+    hrefs
+}
+
+/// Resources loaded by `Tree::from_str_async` in advance.
+pub(crate) struct PreloadedResources(pub Vec<(String, Vec<u8>)>);
+
+impl crate::ResourceLoader for PreloadedResources {
+    fn load(&self, href: &str) -> Option<Vec<u8>> {
+        self.0.iter().find(|(s, _)| s == href).map(|(_, data)| data.clone())
+    }
+}
+
+/// Checks that file has a PNG, a GIF, a JPEG or a TIFF magic bytes.
 /// Or an SVG(Z) extension.
 fn get_image_file_format(path: &std::path::Path, data: &[u8]) -> Option<ImageFormat> {
-    let ext = crate::utils::file_extension(path)?.to_lowercase();
-    if ext == "svg" || ext == "svgz" {
-        return Some(ImageFormat::SVG);
+    if let Some(ext) = crate::utils::file_extension(path) {
+        let ext = ext.to_lowercase();
+        if ext == "svg" || ext == "svgz" {
+            return Some(ImageFormat::SVG);
+        }
     }
 
     get_image_data_format(data.get(0..8)?)
 }
 
-/// Checks that file has a PNG, a GIF or a JPEG magic bytes.
+/// Checks that file has a PNG, a GIF, a JPEG or a TIFF magic bytes.
 fn get_image_data_format(data: &[u8]) -> Option<ImageFormat> {
     if data.starts_with(b"\x89PNG\r\n\x1a\n") {
         Some(ImageFormat::PNG)
@@ -251,6 +340,8 @@ fn get_image_data_format(data: &[u8]) -> Option<ImageFormat> {
         Some(ImageFormat::JPEG)
     } else if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
         Some(ImageFormat::GIF)
+    } else if data.starts_with(b"II*\0") || data.starts_with(b"MM\0*") {
+        Some(ImageFormat::TIFF)
     } else {
         None
     }
@@ -0,0 +1,68 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Simple layout utilities built on top of the bounding box API.
+
+use crate::{Node, NodeExt, Transform};
+
+/// A layout that places nodes into columns of the same width.
+///
+/// Useful for rendering tables and grids.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ColumnLayout {
+    /// The column width.
+    ///
+    /// The widest bounding box among all the nodes, in canvas coordinates.
+    pub column_width: f64,
+
+    /// Alignment transforms, one per node, in the same order.
+    ///
+    /// Each transform is in the node parent coordinates and should be prepended
+    /// to the node transform.
+    pub transforms: Vec<Transform>,
+}
+
+impl ColumnLayout {
+    /// Creates a layout where each node occupies its own column.
+    ///
+    /// Columns are placed next to each other, starting at the left edge
+    /// of the first node bounding box. Each node is aligned to the left edge
+    /// of its column. The vertical position is preserved.
+    ///
+    /// Nodes without a bounding box are handled as zero-sized and will not be moved.
+    pub fn from_groups(groups: &[Node]) -> ColumnLayout {
+        let bboxes: Vec<_> = groups.iter().map(|node| node.calculate_bbox()).collect();
+
+        let column_width = bboxes.iter().flatten().fold(0.0, |w, bbox| bbox.width().max(w));
+        let start_x = bboxes.first().cloned().flatten().map(|bbox| bbox.x()).unwrap_or(0.0);
+
+        let mut transforms = Vec::with_capacity(groups.len());
+        for (i, (node, bbox)) in groups.iter().zip(bboxes.iter()).enumerate() {
+            let bbox = match bbox {
+                Some(v) => v,
+                None => {
+                    transforms.push(Transform::default());
+                    continue;
+                }
+            };
+
+            let dx = start_x + i as f64 * column_width - bbox.x();
+
+            // The offset is in canvas coordinates, so we have to convert it
+            // into the parent coordinates first.
+            let parent_ts = node.parent().map(|p| p.abs_transform()).unwrap_or_default();
+            let ts = match parent_ts.invert() {
+                Some(inv) => Transform::new_translate(inv.a * dx, inv.b * dx),
+                None => Transform::default(),
+            };
+
+            transforms.push(ts);
+        }
+
+        ColumnLayout {
+            column_width,
+            transforms,
+        }
+    }
+}
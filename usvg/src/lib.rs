@@ -111,8 +111,11 @@ mod converter;
 mod error;
 #[cfg(feature = "export")] mod export;
 #[cfg(feature = "filter")] pub mod filter;
+mod fix_xml;
 mod geom;
+mod html;
 mod image;
+pub mod layout;
 mod marker;
 mod mask;
 mod options;
@@ -334,6 +337,7 @@ pub enum NodeKind {
     ClipPath(ClipPath),
     Mask(Mask),
     Pattern(Pattern),
+    Hatch(Hatch),
     #[cfg(feature = "filter")] Filter(filter::Filter),
     Path(Path),
     Image(Image),
@@ -354,6 +358,7 @@ impl NodeKind {
             NodeKind::ClipPath(ref e) => e.id.as_str(),
             NodeKind::Mask(ref e) => e.id.as_str(),
             NodeKind::Pattern(ref e) => e.id.as_str(),
+            NodeKind::Hatch(ref e) => e.id.as_str(),
             #[cfg(feature = "filter")]
             NodeKind::Filter(ref e) => e.id.as_str(),
             NodeKind::Path(ref e) => e.id.as_str(),
@@ -375,6 +380,7 @@ impl NodeKind {
             NodeKind::ClipPath(ref e) => e.transform,
             NodeKind::Mask(_) => Transform::default(),
             NodeKind::Pattern(ref e) => e.transform,
+            NodeKind::Hatch(ref e) => e.transform,
             #[cfg(feature = "filter")]
             NodeKind::Filter(_) => Transform::default(),
             NodeKind::Path(ref e) => e.transform,
@@ -426,6 +432,11 @@ pub struct Path {
     /// Stroke style.
     pub stroke: Option<Stroke>,
 
+    /// Fill and stroke painting order.
+    ///
+    /// `paint-order` in SVG.
+    pub paint_order: PaintOrder,
+
     /// Rendering mode.
     ///
     /// `shape-rendering` in SVG.
@@ -443,6 +454,14 @@ pub struct Path {
     /// that were converted from text.
     pub text_bbox: Option<Rect>,
 
+    /// Positions of glyphs this path was created from.
+    ///
+    /// In the same coordinates as `data`.
+    ///
+    /// As with `text_bbox`, will be set only for paths that were converted from text.
+    /// Paths created from text decorations do not have glyphs.
+    pub glyph_positions: Vec<GlyphPosition>,
+
     /// Segments list.
     ///
     /// All segments are in absolute coordinates.
@@ -457,14 +476,48 @@ impl Default for Path {
             visibility: Visibility::Visible,
             fill: None,
             stroke: None,
+            paint_order: PaintOrder::default(),
             rendering_mode: ShapeRendering::default(),
             text_bbox: None,
+            glyph_positions: Vec::new(),
             data: std::rc::Rc::new(PathData::default()),
         }
     }
 }
 
 
+/// A position of a laid out glyph.
+///
+/// Can be used to place click targets or underlines over a text
+/// without doing the text layout again.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct GlyphPosition {
+    /// The first codepoint of the glyph's cluster.
+    ///
+    /// A cluster can contain multiple codepoints, like with ligatures.
+    pub unicode_codepoint: char,
+
+    /// The X coordinate of the glyph box top-left corner.
+    pub x: f64,
+
+    /// The Y coordinate of the glyph box top-left corner.
+    pub y: f64,
+
+    /// The glyph box width, not affected by `letter-spacing` and `word-spacing`.
+    ///
+    /// Rotation and skew of the glyph are not taken into account.
+    pub width: f64,
+
+    /// The glyph box height, which is the font's ascent minus descent.
+    pub height: f64,
+
+    /// An advance to the next glyph, including `letter-spacing` and `word-spacing`.
+    ///
+    /// Can be negative.
+    pub advance: f64,
+}
+
+
 /// An `enable-background`.
 ///
 /// Contains only the `new [ <x> <y> <width> <height> ]` value.
@@ -520,6 +573,12 @@ pub struct Group {
     ///
     /// `None` indicates an `accumulate` value.
     pub enable_background: Option<EnableBackground>,
+
+    /// A color used to fill the group's bounding box before rendering its children.
+    ///
+    /// Can be set only on `g` elements via the `background-color` property.
+    /// Color alpha is ignored.
+    pub background_color: Option<Color>,
 }
 
 impl Default for Group {
@@ -534,11 +593,53 @@ impl Default for Group {
             filter_fill: None,
             filter_stroke: None,
             enable_background: None,
+            background_color: None,
         }
     }
 }
 
 
+fn prepare_text<'a>(text: &'a str, opt: &OptionsRef) -> std::borrow::Cow<'a, str> {
+    if opt.html_mode {
+        std::borrow::Cow::Owned(html::prepare(text))
+    } else {
+        std::borrow::Cow::Borrowed(text)
+    }
+}
+
+// Parses an SVG string and passes the XML tree to `f`.
+fn parse_text<T, F>(text: &str, opt: &OptionsRef, f: F) -> Result<T, Error>
+    where F: FnOnce(&roxmltree::Document) -> Result<T, Error>
+{
+    let text = prepare_text(text, opt);
+    let err = match parse_xml(&text) {
+        Ok(doc) => return f(&doc),
+        Err(e) => e,
+    };
+
+    if !opt.fix_invalid_xml {
+        return Err(err);
+    }
+
+    let (text, fixes) = fix_xml::fix(&text);
+    if fixes.is_empty() {
+        return Err(err);
+    }
+
+    // Report the original error, since the fixed text is never visible to the caller.
+    let doc = parse_xml(&text).map_err(|_| err)?;
+    log::warn!("An invalid XML was fixed on load: {}.", fixes);
+    f(&doc)
+}
+
+fn parse_xml(text: &str) -> Result<roxmltree::Document<'_>, Error> {
+    let mut xml_opt = roxmltree::ParsingOptions::default();
+    xml_opt.allow_dtd = true;
+
+    roxmltree::Document::parse_with_options(text, xml_opt).map_err(Error::ParsingFailed)
+}
+
+
 /// Alias for `rctree::Node<NodeKind>`.
 pub type Node = rctree::Node<NodeKind>;
 
@@ -566,18 +667,39 @@ impl Tree {
 
     /// Parses `Tree` from an SVG string.
     pub fn from_str(text: &str, opt: &OptionsRef) -> Result<Self, Error> {
-        let mut xml_opt = roxmltree::ParsingOptions::default();
-        xml_opt.allow_dtd = true;
+        parse_text(text, opt, |doc| Self::from_xmltree(doc, opt))
+    }
 
-        let doc = roxmltree::Document::parse_with_options(text, xml_opt)
-            .map_err(Error::ParsingFailed)?;
+    /// Parses `Tree` from an SVG string, loading external resources asynchronously.
+    ///
+    /// All external resources referenced by the document are fetched via `loader` first
+    /// and only then the document is converted, which is fully synchronous.
+    /// Resources are fetched one by one.
+    ///
+    /// Overrides [`OptionsRef::resource_loader`].
+    pub async fn from_str_async(
+        text: &str,
+        opt: &OptionsRef<'_>,
+        loader: &dyn AsyncResourceLoader,
+    ) -> Result<Self, Error> {
+        let hrefs = parse_text(text, opt, |doc| Ok(image::collect_external_hrefs(doc)))?;
+
+        let mut resources = Vec::with_capacity(hrefs.len());
+        for href in hrefs {
+            if let Some(data) = loader.load(&href).await {
+                resources.push((href, data));
+            }
+        }
 
-        Self::from_xmltree(&doc, opt)
+        let resources = image::PreloadedResources(resources);
+        let mut opt: OptionsRef = opt.clone();
+        opt.resource_loader = Some(&resources);
+        Self::from_str(text, &opt)
     }
 
     /// Parses `Tree` from `roxmltree::Document`.
     pub fn from_xmltree(doc: &roxmltree::Document, opt: &OptionsRef) -> Result<Self, Error> {
-        let doc = svgtree::Document::parse(doc)?;
+        let doc = svgtree::Document::parse(doc, opt.duplicate_id_handling, opt.profile)?;
         Self::from_svgtree(doc, opt)
     }
 
@@ -674,6 +796,25 @@ impl Tree {
         crate::export::convert(self, opt)
     }
 
+    /// Returns a transform from the SVG user space to a `target_width` x `target_height`
+    /// pixels rectangle.
+    ///
+    /// This is the same transform `resvg` applies to the root element when rendering
+    /// to an image of that size, like the one returned by `FitTo::fit_to`.
+    /// It respects `viewBox` and `preserveAspectRatio`.
+    ///
+    /// To map screen coordinates back to the user space, e.g. for picking,
+    /// use an inverted transform via `Transform::invert`.
+    ///
+    /// Returns an identity transform when the target size is not positive.
+    pub fn view_box_transform(&self, target_width: f64, target_height: f64) -> Transform {
+        let svg = self.svg_node();
+        match Size::new(target_width, target_height) {
+            Some(size) => utils::view_box_to_transform(svg.view_box.rect, svg.view_box.aspect, size),
+            None => Transform::default(),
+        }
+    }
+
     /// Set a view box for the tree.
     pub(crate) fn set_view_box(&mut self, rect: Rect) {
         if let NodeKind::Svg(svg) = &mut *self.root.borrow_mut() {
@@ -762,6 +903,7 @@ impl NodeExt for Node {
             NodeKind::LinearGradient(ref lg) => Some(lg.units),
             NodeKind::RadialGradient(ref rg) => Some(rg.units),
             NodeKind::Pattern(ref patt) => Some(patt.units),
+            NodeKind::Hatch(ref hatch) => Some(hatch.units),
             _ => None,
         }
     }
@@ -29,6 +29,7 @@ pub(crate) fn is_valid(node: svgtree::Node) -> bool {
 pub(crate) fn convert(
     node: svgtree::Node,
     path: &PathData,
+    context: (&Option<style::Fill>, &Option<style::Stroke>),
     state: &converter::State,
     id_generator: &mut converter::NodeIdGenerator,
     parent: &mut Node,
@@ -54,7 +55,7 @@ pub(crate) fn convert(
                 continue;
             }
 
-            resolve(node, path, marker, *kind, state, id_generator, parent, tree);
+            resolve(node, path, marker, *kind, context, state, id_generator, parent, tree);
         }
     }
 }
@@ -76,6 +77,7 @@ fn resolve(
     path: &PathData,
     marker_node: svgtree::Node,
     marker_kind: MarkerKind,
+    context: (&Option<style::Fill>, &Option<style::Stroke>),
     state: &converter::State,
     id_generator: &mut converter::NodeIdGenerator,
     parent: &mut Node,
@@ -160,6 +162,7 @@ fn resolve(
 
         let mut marker_state = state.clone();
         marker_state.parent_marker = Some(marker_node);
+        marker_state.context_element = Some((context.0.clone(), context.1.clone()));
         converter::convert_children(marker_node, &marker_state, id_generator, &mut g_node, tree);
 
         if !g_node.has_children() {
@@ -2,6 +2,9 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
+use std::future::Future;
+use std::pin::Pin;
+
 use crate::{ImageHrefResolver, ImageRendering, ShapeRendering, TextRendering, Size, ScreenSize};
 
 /// Image fit options.
@@ -50,6 +53,104 @@ impl FitTo {
 }
 
 
+/// A loader of external resources.
+///
+/// By default, external resources are loaded from the filesystem,
+/// relative to [`Options::resources_dir`].
+/// A custom loader can be used when there is no filesystem access,
+/// like in WASM, or when resources are stored somewhere else,
+/// like in an in-memory asset pack.
+pub trait ResourceLoader: Send + Sync {
+    /// Loads a resource referenced by `href`.
+    ///
+    /// `href` is passed as is, without any resolving.
+    /// Data URLs are handled by usvg itself and will not be passed here.
+    ///
+    /// Returns `None` when a resource cannot be loaded. Such resources would be skipped.
+    fn load(&self, href: &str) -> Option<Vec<u8>>;
+}
+
+impl std::fmt::Debug for dyn ResourceLoader + '_ {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ResourceLoader { .. }")
+    }
+}
+
+
+/// An asynchronous loader of external resources.
+///
+/// See [`ResourceLoader`] and [`Tree::from_str_async`](crate::Tree::from_str_async) for details.
+pub trait AsyncResourceLoader: Send + Sync {
+    /// Loads a resource referenced by `href`.
+    ///
+    /// Returns `None` when a resource cannot be loaded. Such resources would be skipped.
+    fn load<'a>(&'a self, href: &'a str) -> Pin<Box<dyn Future<Output = Option<Vec<u8>>> + Send + 'a>>;
+}
+
+impl std::fmt::Debug for dyn AsyncResourceLoader + '_ {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("AsyncResourceLoader { .. }")
+    }
+}
+
+
+/// A way to handle elements with the same `id`.
+///
+/// An `id` must be unique in an SVG document, but in practice it's not always the case.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum DuplicateIdHandling {
+    /// Links reference the first element with a given `id`, like in browsers.
+    UseFirst,
+    /// Links reference the last element with a given `id`.
+    UseLast,
+    /// Parsing fails with [`Error::DuplicateId`](crate::Error::DuplicateId).
+    Error,
+}
+
+impl_enum_default!(DuplicateIdHandling, UseFirst);
+
+
+/// An SVG profile.
+///
+/// Elements and attributes that are not a part of the selected profile are skipped
+/// with a warning, as if they were not present in the document.
+/// Can be used to check how an SVG would look in a less capable renderer.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SvgProfile {
+    /// SVG 1.1 Full.
+    ///
+    /// Disables SVG 2 features, like `hatch`, `feDropShadow`, filter functions,
+    /// `paint-order`, `transform-origin`, `stroke-alignment`, `context-fill`, `context-stroke`,
+    /// the `path()` clip path, `d` on basic shapes and the CSS `translate`, `rotate`
+    /// and `scale` properties.
+    ///
+    /// The list is not complete. Other SVG 2 features are still allowed,
+    /// like geometry properties in CSS. `mix-blend-mode`, `isolation` and `transform-box`
+    /// are not supported by `usvg` at all and are ignored by any profile.
+    Full11,
+    /// SVG Tiny 1.2.
+    ///
+    /// In addition to `Full11` restrictions, disables clipping paths, masks, markers,
+    /// patterns, filters, `symbol`, `textPath`, `tref`, style sheets and `opacity`
+    /// on anything but `image`, as well as `spreadMethod`, `gradientTransform`, `fx`, `fy`
+    /// and `xlink:href` on gradients.
+    /// The `style` attribute is still supported.
+    Tiny12,
+    /// SVG 2, as far as it's supported by `usvg`.
+    SVG2,
+}
+
+impl_enum_default!(SvgProfile, SVG2);
+
+impl_enum_from_str!(SvgProfile,
+    "svg-1.1"       => SvgProfile::Full11,
+    "svg-tiny-1.2"  => SvgProfile::Tiny12,
+    "svg-2"         => SvgProfile::SVG2
+);
+
+impl_from_str!(SvgProfile);
+
+
 /// Processing options.
 #[derive(Debug)]
 pub struct Options {
@@ -136,6 +237,51 @@ pub struct Options {
     ///
     /// Default: see type's documentation for details
     pub image_href_resolver: ImageHrefResolver,
+
+    /// A custom loader of external resources.
+    ///
+    /// When set, will be used instead of the filesystem by the default
+    /// [`ImageHrefResolver`].
+    ///
+    /// Default: `None`
+    pub resource_loader: Option<Box<dyn ResourceLoader>>,
+
+    /// Parse the input as an SVG fragment extracted from an HTML document.
+    ///
+    /// Such fragments usually do not have namespace declarations and can use
+    /// HTML named character references, like `&nbsp;`.
+    /// When enabled, bare elements are treated as SVG ones, the `xlink` prefix
+    /// is always available and HTML character references are resolved.
+    ///
+    /// The input still has to be a well-formed XML.
+    /// Affects only `Tree::from_str` and `Tree::from_data`.
+    ///
+    /// Default: false
+    pub html_mode: bool,
+
+    /// Specifies which element should be referenced when multiple elements have the same `id`.
+    ///
+    /// A warning is logged for each duplicated `id`.
+    ///
+    /// Default: UseFirst
+    pub duplicate_id_handling: DuplicateIdHandling,
+
+    /// Try to fix common XML errors when the input is not a well-formed XML.
+    ///
+    /// SVG files extracted from web pages often contain HTML-isms,
+    /// like unescaped `&` in URLs, unquoted attribute values or `<br>` without a closing tag.
+    /// When enabled and the XML parsing fails, such errors are fixed and the parsing is restarted.
+    /// A warning with the list of fixes is logged.
+    ///
+    /// Affects only `Tree::from_str` and `Tree::from_data`.
+    ///
+    /// Default: false
+    pub fix_invalid_xml: bool,
+
+    /// Restricts parsing to the specified SVG profile.
+    ///
+    /// Default: SVG2
+    pub profile: SvgProfile,
 }
 
 impl Default for Options {
@@ -155,6 +301,11 @@ impl Default for Options {
             #[cfg(feature = "text")]
             fontdb: fontdb::Database::new(),
             image_href_resolver: ImageHrefResolver::default(),
+            resource_loader: None,
+            html_mode: false,
+            duplicate_id_handling: DuplicateIdHandling::default(),
+            fix_invalid_xml: false,
+            profile: SvgProfile::default(),
         }
     }
 }
@@ -177,6 +328,11 @@ impl Options {
             #[cfg(feature = "text")]
             fontdb: &self.fontdb,
             image_href_resolver: &self.image_href_resolver,
+            resource_loader: self.resource_loader.as_deref(),
+            html_mode: self.html_mode,
+            duplicate_id_handling: self.duplicate_id_handling,
+            fix_invalid_xml: self.fix_invalid_xml,
+            profile: self.profile,
         }
     }
 }
@@ -200,6 +356,11 @@ pub struct OptionsRef<'a> {
     #[cfg(feature = "text")]
     pub fontdb: &'a fontdb::Database,
     pub image_href_resolver: &'a ImageHrefResolver,
+    pub resource_loader: Option<&'a dyn ResourceLoader>,
+    pub html_mode: bool,
+    pub duplicate_id_handling: DuplicateIdHandling,
+    pub fix_invalid_xml: bool,
+    pub profile: SvgProfile,
 }
 
 impl OptionsRef<'_> {
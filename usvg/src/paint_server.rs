@@ -3,11 +3,11 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
 use svgtypes::{Length, LengthUnit as Unit};
-use strict_num::PositiveF64;
+use strict_num::{NonZeroPositiveF64, PositiveF64};
 
 use crate::svgtree::{self, AId, EId};
-use crate::{Color, NodeExt, NodeKind, NormalizedF64, Opacity, OptionLog};
-use crate::{Tree, Units, converter, SvgColorExt};
+use crate::{Color, NodeExt, NodeKind, NormalizedF64, Opacity, OptionLog, Path, PathData, PathSegment};
+use crate::{SharedPathData, Tree, Units, converter, style, SvgColorExt};
 use crate::geom::{FuzzyEq, FuzzyZero, IsValidLength, Line, Rect, Transform, ViewBox};
 
 
@@ -176,6 +176,63 @@ pub struct Pattern {
 }
 
 
+/// A hatch element.
+///
+/// `hatch` element in SVG 2.
+///
+/// Children are paths converted from `hatchpath` elements, which are only stroked.
+/// They are placed in a tile `pitch` wide and repeated along the x axis.
+/// Along the y axis the content is repeated with `period`, making the tile infinite.
+#[derive(Clone, Debug)]
+pub struct Hatch {
+    /// Element's ID.
+    ///
+    /// Taken from the SVG itself.
+    /// Can't be empty.
+    pub id: String,
+
+    /// Coordinate system units.
+    ///
+    /// `hatchUnits` in SVG.
+    pub units: Units,
+
+    /// Content coordinate system units.
+    ///
+    /// `hatchContentUnits` in SVG.
+    pub content_units: Units,
+
+    /// Hatch transform.
+    ///
+    /// `transform` in SVG.
+    pub transform: Transform,
+
+    /// Hatch origin x coordinate.
+    ///
+    /// `x` in SVG.
+    pub x: f64,
+
+    /// Hatch origin y coordinate.
+    ///
+    /// `y` in SVG.
+    pub y: f64,
+
+    /// The distance between hatch tiles.
+    ///
+    /// `pitch` in SVG.
+    pub pitch: NonZeroPositiveF64,
+
+    /// The tile rotation angle in degrees.
+    ///
+    /// `rotate` in SVG.
+    pub rotate: f64,
+
+    /// The distance along the y axis after which the content is repeated.
+    ///
+    /// In content units. Resolved from `hatchpath` elements.
+    pub period: NonZeroPositiveF64,
+}
+
+
 pub(crate) enum ServerOrColor {
     Server {
         id: String,
@@ -206,6 +263,7 @@ pub(crate) fn convert(
         EId::LinearGradient => convert_linear(node, state, tree),
         EId::RadialGradient => convert_radial(node, state, tree),
         EId::Pattern => convert_pattern(node, state, id_generator, tree),
+        EId::Hatch => convert_hatch(node, state, id_generator, tree),
         _ => unreachable!(),
     }
 }
@@ -360,6 +418,131 @@ fn convert_pattern(
     })
 }
 
+#[inline(never)]
+fn convert_hatch(
+    node: svgtree::Node,
+    state: &converter::State,
+    id_generator: &mut converter::NodeIdGenerator,
+    tree: &mut Tree,
+) -> Option<ServerOrColor> {
+    let node_with_children = find_pattern_with_children(node)?;
+
+    let units = convert_units(node, AId::HatchUnits, Units::ObjectBoundingBox);
+    let content_units = convert_units(node, AId::HatchContentUnits, Units::UserSpaceOnUse);
+
+    let transform = resolve_attr(node, AId::Transform)
+        .attribute(AId::Transform).unwrap_or_default();
+
+    // 'A value of zero disables rendering of the element.'
+    let pitch = resolve_number(node, AId::Pitch, units, state, Length::zero());
+    let pitch = NonZeroPositiveF64::new(pitch)
+        .log_none(|| log::warn!("Hatch '{}' has an invalid pitch. Skipped.", node.element_id()))?;
+
+    let rotate = resolve_attr(node, AId::Rotate)
+        .attribute::<&Vec<f64>>(AId::Rotate)
+        .and_then(|list| list.first().cloned())
+        .unwrap_or(0.0);
+
+    let mut paths = Vec::new();
+    for child in node_with_children.children() {
+        if !child.has_tag_name(EId::Hatchpath) {
+            continue;
+        }
+
+        let stroke = match style::resolve_stroke(child, true, state, id_generator, tree) {
+            Some(v) => v,
+            None => continue,
+        };
+
+        let offset = child.convert_length(AId::Offset, content_units, state, Length::zero());
+        let data = child.attribute::<SharedPathData>(AId::D).map(|data| {
+            let mut data = (*data).clone();
+            data.transform(Transform::new_translate(offset, 0.0));
+            data
+        });
+
+        paths.push((child, stroke, offset, data));
+    }
+
+    if paths.is_empty() {
+        return None;
+    }
+
+    // Each `hatchpath` with a custom path is repeated along the y axis
+    // using the distance between its start and end points. When the paths have
+    // different distances, the largest one is used.
+    let mut period = 0.0;
+    let mut height = 0.0;
+    for data in paths.iter().filter_map(|(_, _, _, data)| data.as_ref()) {
+        period = y_period(data).max(period);
+        if let Some(bbox) = data.bbox() {
+            height = bbox.height().max(height);
+        }
+    }
+
+    // Closed paths are repeated one after another and
+    // a period of straight lines doesn't matter.
+    let period = [period, height, 1.0].iter().cloned().find(|n| *n > 0.0).unwrap_or(1.0);
+    let period = NonZeroPositiveF64::new(period)?;
+
+    let mut hatch = tree.append_to_defs(NodeKind::Hatch(Hatch {
+        id: node.element_id().to_string(),
+        units,
+        content_units,
+        transform,
+        x: resolve_number(node, AId::X, units, state, Length::zero()),
+        y: resolve_number(node, AId::Y, units, state, Length::zero()),
+        pitch,
+        rotate,
+        period,
+    }));
+
+    for (child, stroke, offset, data) in paths {
+        // Without a `d` attribute, a hatch path is an infinite vertical line.
+        let data = data.unwrap_or_else(|| {
+            let mut data = PathData::new();
+            data.push_move_to(offset, 0.0);
+            data.push_line_to(offset, period.get());
+            data
+        });
+
+        let rendering_mode = child
+            .find_attribute(AId::ShapeRendering)
+            .unwrap_or(state.opt.shape_rendering);
+
+        hatch.append_kind(NodeKind::Path(Path {
+            id: child.element_id().to_string(),
+            stroke: Some(stroke),
+            rendering_mode,
+            data: std::rc::Rc::new(data),
+            ..Path::default()
+        }));
+    }
+
+    Some(ServerOrColor::Server {
+        id: node.element_id().to_string(),
+        units,
+    })
+}
+
+// Returns the vertical distance between the path start and end points.
+fn y_period(data: &PathData) -> f64 {
+    let start = match data.first() {
+        Some(PathSegment::MoveTo { y, .. }) => *y,
+        _ => return 0.0,
+    };
+
+    let end = match data.last() {
+        Some(PathSegment::MoveTo { y, .. })
+        | Some(PathSegment::LineTo { y, .. })
+        | Some(PathSegment::CurveTo { y, .. }) => *y,
+        // A closed path ends at its start point.
+        _ => return 0.0,
+    };
+
+    (end - start).abs()
+}
+
 fn convert_spread_method(node: svgtree::Node) -> SpreadMethod {
     let node = resolve_attr(node, AId::SpreadMethod);
     node.attribute(AId::SpreadMethod).unwrap_or_default()
@@ -393,12 +576,15 @@ fn find_gradient_with_stops(node: svgtree::Node) -> Option<svgtree::Node> {
     None
 }
 
+// Also used by `hatch`, which can reference only other `hatch` elements.
 fn find_pattern_with_children(node: svgtree::Node) -> Option<svgtree::Node> {
+    let tag_name = node.tag_name()?;
     for link_id in node.href_iter() {
         let link = node.document().get(link_id);
-        if !link.has_tag_name(EId::Pattern) {
+        if !link.has_tag_name(tag_name) {
             log::warn!(
-                "Pattern '{}' cannot reference '{}' via 'xlink:href'.",
+                "{} '{}' cannot reference '{}' via 'xlink:href'.",
+                if tag_name == EId::Hatch { "Hatch" } else { "Pattern" },
                 node.element_id(), link.tag_name().unwrap()
             );
             return None;
@@ -552,7 +738,7 @@ fn resolve_attr(
     match node.tag_name().unwrap() {
         EId::LinearGradient => resolve_lg_attr(node, name),
         EId::RadialGradient => resolve_rg_attr(node, name),
-        EId::Pattern => resolve_pattern_attr(node, name),
+        EId::Pattern | EId::Hatch => resolve_pattern_attr(node, name),
         EId::Filter => resolve_filter_attr(node, name),
         _ => node,
     }
@@ -644,7 +830,8 @@ fn resolve_pattern_attr(
             None => return node,
         };
 
-        if tag_name != EId::Pattern {
+        // `pattern` and `hatch` can reference only elements of the same type.
+        if Some(tag_name) != node.tag_name() {
             break;
         }
 
@@ -4,9 +4,9 @@
 
 use std::rc::Rc;
 
-use kurbo::{ParamCurveArclen, ParamCurveExtrema, ParamCurve};
+use kurbo::{ParamCurveArclen, ParamCurveExtrema};
 
-use crate::{Rect, PathBbox, Transform, FuzzyZero};
+use crate::{Rect, PathBbox, Transform, FillRule, FuzzyZero};
 
 /// A path's absolute segment.
 ///
@@ -192,7 +192,8 @@ impl PathData {
 
     /// Calculates path's length.
     ///
-    /// Length from the first segment to the first MoveTo, ClosePath or slice end.
+    /// A total length of all subpaths, including closing segments.
+    /// Curves length is integrated numerically, with an accuracy relative to the curve size.
     ///
     /// This operation is expensive.
     #[inline]
@@ -200,6 +201,18 @@ impl PathData {
         calc_length(self)
     }
 
+    /// Checks that a point is inside the path's fill area.
+    ///
+    /// Open subpaths are treated as closed, just like during filling.
+    #[inline]
+    pub fn contains_point(&self, x: f64, y: f64, fill_rule: FillRule) -> bool {
+        let winding = calc_winding(self, x, y);
+        match fill_rule {
+            FillRule::NonZero => winding != 0,
+            FillRule::EvenOdd => winding % 2 != 0,
+        }
+    }
+
     /// Applies the transform to the path.
     #[inline]
     pub fn transform(&mut self, ts: Transform) {
@@ -445,7 +458,14 @@ fn calc_bbox_with_transform(
     // TODO: find a better way
     // It's an approximation, but it's better than nothing.
     if let Some(stroke) = stroke {
-        let w = stroke.width.get() / if ts.is_default() {
+        // An inner stroke stays inside the fill area and an outer one is a full width away from it.
+        let width = match stroke.alignment {
+            super::StrokeAlignment::Center => stroke.width.get(),
+            super::StrokeAlignment::Inner => 0.0,
+            super::StrokeAlignment::Outer => stroke.width.get() * 2.0,
+        };
+
+        let w = width / if ts.is_default() {
             2.0
         } else {
             2.0 / (ts.a * ts.d - ts.b * ts.c).abs().sqrt()
@@ -534,42 +554,74 @@ fn calc_length(segments: &[PathSegment]) -> f64 {
         }
     };
 
-    fn create_curve_from_line(px: f64, py: f64, x: f64, y: f64) -> kurbo::CubicBez {
-        let line = kurbo::Line::new(kurbo::Point::new(px, py), kurbo::Point::new(x, y));
-        let p1 = line.eval(0.33);
-        let p2 = line.eval(0.66);
-        kurbo::CubicBez::from_points(px, py, p1.x, p1.y, p2.x, p2.y, x, y)
-    }
-
     let mut length = 0.0;
     for seg in segments {
-        let curve = match *seg {
+        match *seg {
             PathSegment::MoveTo { x, y } => {
                 prev_mx = x;
                 prev_my = y;
                 prev_x = x;
                 prev_y = y;
-                continue;
             }
             PathSegment::LineTo { x, y } => {
-                create_curve_from_line(prev_x, prev_y, x, y)
+                length += (x - prev_x).hypot(y - prev_y);
+                prev_x = x;
+                prev_y = y;
             }
             PathSegment::CurveTo { x1, y1, x2, y2, x, y } => {
-                kurbo::CubicBez::from_points(prev_x, prev_y, x1, y1, x2, y2, x, y)
+                let curve = kurbo::CubicBez::from_points(prev_x, prev_y, x1, y1, x2, y2, x, y);
+
+                // The accuracy is absolute, so it has to depend on the curve size.
+                // Otherwise, small curves would have a huge relative error.
+                let polygon_len = (x1 - prev_x).hypot(y1 - prev_y)
+                    + (x2 - x1).hypot(y2 - y1)
+                    + (x - x2).hypot(y - y2);
+                if polygon_len.is_fuzzy_zero() {
+                    prev_x = x;
+                    prev_y = y;
+                    continue;
+                }
+
+                length += curve.arclen(polygon_len * 1e-7);
+                prev_x = x;
+                prev_y = y;
             }
             PathSegment::ClosePath => {
-                create_curve_from_line(prev_x, prev_y, prev_mx, prev_my)
+                length += (prev_mx - prev_x).hypot(prev_my - prev_y);
+                prev_x = prev_mx;
+                prev_y = prev_my;
             }
-        };
-
-        length += curve.arclen(0.5);
-        prev_x = curve.p3.x;
-        prev_y = curve.p3.y;
+        }
     }
 
     length
 }
 
+fn calc_winding(segments: &[PathSegment], x: f64, y: f64) -> i32 {
+    let mut path = kurbo::BezPath::new();
+    for seg in segments {
+        match *seg {
+            PathSegment::MoveTo { x, y } => {
+                // Open subpaths are closed implicitly.
+                if !path.elements().is_empty() {
+                    path.close_path();
+                }
+
+                path.move_to((x, y));
+            }
+            PathSegment::LineTo { x, y } => path.line_to((x, y)),
+            PathSegment::CurveTo { x1, y1, x2, y2, x, y } => path.curve_to((x1, y1), (x2, y2), (x, y)),
+            PathSegment::ClosePath => path.close_path(),
+        }
+    }
+
+    if !path.elements().is_empty() {
+        path.close_path();
+    }
+
+    kurbo::Shape::winding(&path, kurbo::Point::new(x, y))
+}
+
 fn transform_path(segments: &mut [PathSegment], ts: Transform) {
     for seg in segments {
         match seg {
@@ -13,7 +13,19 @@ pub(crate) fn convert(
     node: svgtree::Node,
     state: &converter::State,
 ) -> Option<SharedPathData> {
-    match node.tag_name()? {
+    let tag_name = node.tag_name()?;
+
+    // Basic shapes can have a `d` attribute that overrides their geometry.
+    // This follows SVG 2, where `d` is a geometry property,
+    // and is used by some morphing animations.
+    let is_basic_shape = matches!(tag_name,
+        EId::Rect | EId::Circle | EId::Ellipse | EId::Line | EId::Polyline | EId::Polygon
+    );
+    if is_basic_shape && node.has_attribute(AId::D) {
+        return convert_path(node);
+    }
+
+    match tag_name {
         EId::Rect => convert_rect(node, state),
         EId::Circle => convert_circle(node, state),
         EId::Ellipse => convert_ellipse(node, state),
@@ -102,17 +114,17 @@ fn resolve_rx_ry(
         }
     }
 
-    // Resolve.
-    let (rx, ry) = match (rx_opt, ry_opt) {
-        (None,     None)     => (Length::zero(), Length::zero()),
+    // Resolve. Percentages are resolved first, since a missing value
+    // must be equal to the resolved value of the other one.
+    let rx = rx_opt.map(|v| units::convert_length(v, node, AId::Rx, Units::UserSpaceOnUse, state));
+    let ry = ry_opt.map(|v| units::convert_length(v, node, AId::Ry, Units::UserSpaceOnUse, state));
+    let (rx, ry) = match (rx, ry) {
+        (None,     None)     => (0.0, 0.0),
         (Some(rx), None)     => (rx, rx),
         (None,     Some(ry)) => (ry, ry),
         (Some(rx), Some(ry)) => (rx, ry),
     };
 
-    let rx = units::convert_length(rx, node, AId::Rx, Units::UserSpaceOnUse, state);
-    let ry = units::convert_length(ry, node, AId::Ry, Units::UserSpaceOnUse, state);
-
     (rx, ry)
 }
 
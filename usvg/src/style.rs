@@ -103,6 +103,40 @@ impl_enum_from_str!(LineJoin,
 );
 
 
+/// A stroke alignment.
+///
+/// `stroke-alignment` attribute in the SVG 2.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum StrokeAlignment {
+    Inner,
+    Center,
+    Outer,
+}
+
+impl_enum_default!(StrokeAlignment, Center);
+
+impl_enum_from_str!(StrokeAlignment,
+    "inner"     => StrokeAlignment::Inner,
+    "center"    => StrokeAlignment::Center,
+    "outer"     => StrokeAlignment::Outer
+);
+
+
+/// A path painting order.
+///
+/// `paint-order` attribute in the SVG 2.
+/// Markers are converted into separate groups, so only the fill and stroke order is stored.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum PaintOrder {
+    FillAndStroke,
+    StrokeAndFill,
+}
+
+impl_enum_default!(PaintOrder, FillAndStroke);
+
+
 /// A stroke style.
 #[allow(missing_docs)]
 #[derive(Clone, Debug)]
@@ -115,6 +149,7 @@ pub struct Stroke {
     pub width: StrokeWidth,
     pub linecap: LineCap,
     pub linejoin: LineJoin,
+    pub alignment: StrokeAlignment,
 }
 
 impl Default for Stroke {
@@ -130,6 +165,7 @@ impl Default for Stroke {
             width: StrokeWidth::new(1.0).unwrap(),
             linecap: LineCap::default(),
             linejoin: LineJoin::default(),
+            alignment: StrokeAlignment::default(),
         }
     }
 }
@@ -260,7 +296,7 @@ pub(crate) fn resolve_fill(
 
     let mut sub_opacity = Opacity::ONE;
     let paint = if let Some(n) = node.find_node_with_attribute(AId::Fill) {
-        convert_paint(n, AId::Fill, has_bbox, state, &mut sub_opacity, id_generator, tree)?
+        convert_paint(node, n, AId::Fill, has_bbox, state, &mut sub_opacity, id_generator, tree)?
     } else {
         Paint::Color(Color::black())
     };
@@ -286,7 +322,7 @@ pub(crate) fn resolve_stroke(
 
     let mut sub_opacity = Opacity::ONE;
     let paint = if let Some(n) = node.find_node_with_attribute(AId::Stroke) {
-        convert_paint(n, AId::Stroke, has_bbox, state, &mut sub_opacity, id_generator, tree)?
+        convert_paint(node, n, AId::Stroke, has_bbox, state, &mut sub_opacity, id_generator, tree)?
     } else {
         return None;
     };
@@ -307,13 +343,20 @@ pub(crate) fn resolve_stroke(
         width,
         linecap: node.find_attribute(AId::StrokeLinecap).unwrap_or_default(),
         linejoin: node.find_attribute(AId::StrokeLinejoin).unwrap_or_default(),
+        alignment: node.find_attribute(AId::StrokeAlignment).unwrap_or_default(),
     };
 
     Some(stroke)
 }
 
+// `node` is the element that is being painted and `paint_node` is the element
+// that actually defines the paint. They are different when the paint is inherited.
+//
+// `currentColor` is inherited as is and must be resolved using
+// the `color` property of the element that is being painted.
 fn convert_paint(
     node: svgtree::Node,
+    paint_node: svgtree::Node,
     aid: AId,
     has_bbox: bool,
     state: &converter::State,
@@ -321,7 +364,7 @@ fn convert_paint(
     id_generator: &mut converter::NodeIdGenerator,
     tree: &mut Tree,
 ) -> Option<Paint> {
-    match node.attribute::<&svgtree::AttributeValue>(aid)? {
+    match paint_node.attribute::<&svgtree::AttributeValue>(aid)? {
         svgtree::AttributeValue::CurrentColor => {
             let svg_color: svgtypes::Color = node.find_attribute(AId::Color)
                 .unwrap_or_else(svgtypes::Color::black);
@@ -334,8 +377,20 @@ fn convert_paint(
             *opacity = alpha;
             Some(Paint::Color(color))
         }
+        svgtree::AttributeValue::ContextFill => {
+            // Only the paint itself is inherited from the context element.
+            // Without a context element, the value is treated as `none`.
+            state.context_element.as_ref()
+                .and_then(|(fill, _)| fill.as_ref())
+                .map(|fill| fill.paint.clone())
+        }
+        svgtree::AttributeValue::ContextStroke => {
+            state.context_element.as_ref()
+                .and_then(|(_, stroke)| stroke.as_ref())
+                .map(|stroke| stroke.paint.clone())
+        }
         svgtree::AttributeValue::Paint(func_iri, fallback) => {
-            if let Some(link) = node.document().element_by_id(func_iri) {
+            if let Some(link) = paint_node.document().element_by_id(func_iri) {
                 let tag_name = link.tag_name().unwrap();
                 if tag_name.is_paint_server() {
                     match paint_server::convert(link, state, id_generator, tree) {
@@ -0,0 +1,367 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! CSS Color Module Level 4 functions that are not supported by `svgtypes`.
+//!
+//! `hsl()` is supported by `svgtypes` as well, but only the CSS Color 3 syntax.
+//! Both syntaxes are parsed here, so they are rounded the same way.
+//!
+//! We are rendering in sRGB, so all colors are converted into it during parsing.
+//! Colors outside the sRGB gamut are clipped.
+
+use std::str::FromStr;
+
+/// Parses a color, including the CSS Color 4 functions.
+pub fn parse_color(text: &str) -> Option<svgtypes::Color> {
+    match parse_color_function(text) {
+        Some(color) => Some(color),
+        None => svgtypes::Color::from_str(text).ok(),
+    }
+}
+
+/// Parses only the CSS Color 4 functions.
+///
+/// Returns `None` for anything else, including invalid functions.
+pub fn parse_color_function(text: &str) -> Option<svgtypes::Color> {
+    let text = text.trim();
+    let open_idx = text.find('(')?;
+    if !text.ends_with(')') {
+        return None;
+    }
+
+    let name = text[..open_idx].trim_end().to_ascii_lowercase();
+    let mut args = Args::parse(&text[open_idx + 1..text.len() - 1])?;
+
+    let rgb = match name.as_str() {
+        "color" => parse_color_func(&args)?,
+        "oklab" => parse_oklab(&args)?,
+        "oklch" => parse_oklch(&args)?,
+        "hsl" | "hsla" => parse_hsl(&mut args)?,
+        "hwb" => parse_hwb(&mut args)?,
+        _ => return None,
+    };
+
+    Some(to_color(text, rgb, args.alpha))
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Component {
+    Number(f64),
+    Percent(f64),
+    None,
+}
+
+impl Component {
+    fn parse(text: &str) -> Option<Self> {
+        if text.eq_ignore_ascii_case("none") {
+            Some(Component::None)
+        } else if let Some(n) = text.strip_suffix('%') {
+            Some(Component::Percent(f64::from_str(n).ok()?))
+        } else {
+            Some(Component::Number(f64::from_str(text).ok()?))
+        }
+    }
+
+    /// Returns a value in a 0..=1 range, when both `50` and `50%` are equal to `0.5`.
+    fn to_fraction(self) -> f64 {
+        match self {
+            Component::Number(n) | Component::Percent(n) => (n / 100.0).clamp(0.0, 1.0),
+            Component::None => 0.0,
+        }
+    }
+
+    /// Returns the value in which `100%` is equal to `one`. `none` is resolved to zero.
+    fn resolve(self, one: f64) -> f64 {
+        match self {
+            Component::Number(n) => n,
+            Component::Percent(n) => n / 100.0 * one,
+            Component::None => 0.0,
+        }
+    }
+}
+
+struct Args {
+    components: Vec<String>,
+    alpha: f64,
+}
+
+impl Args {
+    fn parse(text: &str) -> Option<Self> {
+        let mut parts = text.splitn(2, '/');
+        let components: Vec<String> = parts.next()?
+            .split(|c: char| c == ',' || c.is_ascii_whitespace())
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_string())
+            .collect();
+
+        let alpha = match parts.next() {
+            Some(alpha) => Component::parse(alpha.trim())?.resolve(1.0),
+            None => 1.0,
+        };
+
+        Some(Args { components, alpha })
+    }
+
+    /// Treats the fourth component as alpha, like in `hsla(120, 100%, 25%, 0.5)`.
+    fn take_legacy_alpha(&mut self) -> Option<()> {
+        if self.components.len() == 4 {
+            let alpha = self.components.pop()?;
+            self.alpha = Component::parse(&alpha)?.resolve(1.0);
+        }
+
+        Some(())
+    }
+}
+
+// See https://www.w3.org/TR/css-color-4/#color-function
+fn parse_color_func(args: &Args) -> Option<[f64; 3]> {
+    if args.components.len() != 4 {
+        return None;
+    }
+
+    let mut c = [0.0; 3];
+    for (i, text) in args.components[1..].iter().enumerate() {
+        c[i] = Component::parse(text)?.resolve(1.0);
+    }
+
+    let space = args.components[0].to_ascii_lowercase();
+    let rgb = match space.as_str() {
+        "srgb" => c,
+        "srgb-linear" => linear_to_srgb(c),
+        "display-p3" => {
+            linear_to_srgb(xyz_to_linear_srgb(mul(&P3_TO_XYZ, map(c, srgb_to_linear))))
+        }
+        "a98-rgb" => {
+            linear_to_srgb(xyz_to_linear_srgb(mul(&A98_RGB_TO_XYZ, map(c, a98_rgb_to_linear))))
+        }
+        "prophoto-rgb" => {
+            let xyz = mul(&PROPHOTO_RGB_TO_XYZ, map(c, prophoto_rgb_to_linear));
+            linear_to_srgb(xyz_to_linear_srgb(mul(&D50_TO_D65, xyz)))
+        }
+        "rec2020" => {
+            linear_to_srgb(xyz_to_linear_srgb(mul(&REC2020_TO_XYZ, map(c, rec2020_to_linear))))
+        }
+        "xyz" | "xyz-d65" => linear_to_srgb(xyz_to_linear_srgb(c)),
+        "xyz-d50" => linear_to_srgb(xyz_to_linear_srgb(mul(&D50_TO_D65, c))),
+        _ => return None,
+    };
+
+    Some(rgb)
+}
+
+// See https://www.w3.org/TR/css-color-4/#specifying-oklab-oklch
+fn parse_oklab(args: &Args) -> Option<[f64; 3]> {
+    if args.components.len() != 3 {
+        return None;
+    }
+
+    let l = Component::parse(&args.components[0])?.resolve(1.0);
+    let a = Component::parse(&args.components[1])?.resolve(0.4);
+    let b = Component::parse(&args.components[2])?.resolve(0.4);
+    Some(oklab_to_srgb([l, a, b]))
+}
+
+fn parse_oklch(args: &Args) -> Option<[f64; 3]> {
+    if args.components.len() != 3 {
+        return None;
+    }
+
+    let l = Component::parse(&args.components[0])?.resolve(1.0);
+    let c = Component::parse(&args.components[1])?.resolve(0.4).max(0.0);
+    let h = parse_hue(&args.components[2])?.to_radians();
+    Some(oklab_to_srgb([l, c * h.cos(), c * h.sin()]))
+}
+
+// See https://www.w3.org/TR/css-color-4/#the-hsl-notation
+fn parse_hsl(args: &mut Args) -> Option<[f64; 3]> {
+    args.take_legacy_alpha()?;
+    if args.components.len() != 3 {
+        return None;
+    }
+
+    let h = parse_hue(&args.components[0])?;
+    let s = Component::parse(&args.components[1])?.to_fraction();
+    let l = Component::parse(&args.components[2])?.to_fraction();
+    Some(hsl_to_srgb(h, s, l))
+}
+
+// See https://www.w3.org/TR/css-color-4/#the-hwb-notation
+fn parse_hwb(args: &mut Args) -> Option<[f64; 3]> {
+    args.take_legacy_alpha()?;
+    if args.components.len() != 3 {
+        return None;
+    }
+
+    let h = parse_hue(&args.components[0])?;
+    let w = Component::parse(&args.components[1])?.to_fraction();
+    let b = Component::parse(&args.components[2])?.to_fraction();
+
+    // When the sum is larger than 100%, both values are normalized and the result is a gray.
+    if w + b >= 1.0 {
+        let gray = w / (w + b);
+        return Some([gray; 3]);
+    }
+
+    let rgb = hsl_to_srgb(h, 1.0, 0.5);
+    Some(map(rgb, |c| c * (1.0 - w - b) + w))
+}
+
+fn hsl_to_srgb(hue: f64, saturation: f64, lightness: f64) -> [f64; 3] {
+    let hue = hue.rem_euclid(360.0);
+    let a = saturation * lightness.min(1.0 - lightness);
+    let f = |n: f64| {
+        let k = (n + hue / 30.0) % 12.0;
+        lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+    };
+
+    [f(0.0), f(8.0), f(4.0)]
+}
+
+/// Parses a hue angle in degrees.
+fn parse_hue(text: &str) -> Option<f64> {
+    if text.eq_ignore_ascii_case("none") {
+        return Some(0.0);
+    }
+
+    let units = [("deg", 1.0), ("grad", 0.9), ("rad", 180.0 / std::f64::consts::PI), ("turn", 360.0)];
+    for &(unit, factor) in &units {
+        if let Some(n) = text.strip_suffix(unit) {
+            return Some(f64::from_str(n).ok()? * factor);
+        }
+    }
+
+    f64::from_str(text).ok()
+}
+
+fn to_color(text: &str, rgb: [f64; 3], alpha: f64) -> svgtypes::Color {
+    // Allow a small error caused by the conversion.
+    let is_in_gamut = rgb.iter().all(|c| *c > -0.001 && *c < 1.001);
+    if !is_in_gamut {
+        log::warn!("'{}' is outside the sRGB gamut and will be clipped.", text);
+    }
+
+    let to_u8 = |c: f64| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+    svgtypes::Color::new_rgba(to_u8(rgb[0]), to_u8(rgb[1]), to_u8(rgb[2]), to_u8(alpha))
+}
+
+type Matrix = [[f64; 3]; 3];
+
+// All matrices are from https://www.w3.org/TR/css-color-4/#color-conversion-code
+
+const P3_TO_XYZ: Matrix = [
+    [0.4865709486482162, 0.26566769316909306, 0.1982172852343625],
+    [0.2289745640697488, 0.6917385218365064, 0.079286914093745],
+    [0.0000000000000000, 0.04511338185890264, 1.043944368900976],
+];
+
+const A98_RGB_TO_XYZ: Matrix = [
+    [0.5766690429101305, 0.1855582379065463, 0.1882286462349947],
+    [0.29734497525053605, 0.6273635662554661, 0.07529145849399788],
+    [0.02703136138641234, 0.07068885253582723, 0.9913375368376388],
+];
+
+// D50
+const PROPHOTO_RGB_TO_XYZ: Matrix = [
+    [0.7977604896723027, 0.13518583717574031, 0.0313493495815248],
+    [0.2880711282292934, 0.7118432178101014, 0.00008565396060525902],
+    [0.0, 0.0, 0.8251046025104601],
+];
+
+const REC2020_TO_XYZ: Matrix = [
+    [0.6369580483012914, 0.14461690358620832, 0.1688809751641721],
+    [0.2627002120112671, 0.6779980715188708, 0.05930171646986196],
+    [0.0, 0.028072693049087428, 1.060985057710791],
+];
+
+// Bradford chromatic adaptation.
+const D50_TO_D65: Matrix = [
+    [0.955473421488075, -0.02309845494876471, 0.06325924320057072],
+    [-0.0283697093338637, 1.0099953980813041, 0.021041441191917323],
+    [0.012314014864481998, -0.020507649298898964, 1.330365926242124],
+];
+
+const XYZ_TO_LINEAR_SRGB: Matrix = [
+    [3.2409699419045226, -1.537383177570094, -0.4986107602930034],
+    [-0.9692436362808796, 1.8759675015077202, 0.04155505740717559],
+    [0.05563007969699366, -0.20397695888897652, 1.0569715142428786],
+];
+
+// From https://bottosson.github.io/posts/oklab/
+const OKLAB_TO_LMS: Matrix = [
+    [1.0, 0.3963377774, 0.2158037573],
+    [1.0, -0.1055613458, -0.0638541728],
+    [1.0, -0.0894841775, -1.2914855480],
+];
+
+const LMS_TO_LINEAR_SRGB: Matrix = [
+    [4.0767416621, -3.3077115913, 0.2309699292],
+    [-1.2684380046, 2.6097574011, -0.3413193965],
+    [-0.0041960863, -0.7034186147, 1.7076147010],
+];
+
+fn mul(m: &Matrix, v: [f64; 3]) -> [f64; 3] {
+    let mut out = [0.0; 3];
+    for (i, row) in m.iter().enumerate() {
+        out[i] = row[0] * v[0] + row[1] * v[1] + row[2] * v[2];
+    }
+    out
+}
+
+fn map<F: Fn(f64) -> f64>(v: [f64; 3], f: F) -> [f64; 3] {
+    [f(v[0]), f(v[1]), f(v[2])]
+}
+
+fn xyz_to_linear_srgb(xyz: [f64; 3]) -> [f64; 3] {
+    mul(&XYZ_TO_LINEAR_SRGB, xyz)
+}
+
+fn oklab_to_srgb(lab: [f64; 3]) -> [f64; 3] {
+    let lms = map(mul(&OKLAB_TO_LMS, lab), |c| c * c * c);
+    linear_to_srgb(mul(&LMS_TO_LINEAR_SRGB, lms))
+}
+
+fn linear_to_srgb(rgb: [f64; 3]) -> [f64; 3] {
+    map(rgb, |c| {
+        let abs = c.abs();
+        if abs > 0.0031308 {
+            c.signum() * (1.055 * abs.powf(1.0 / 2.4) - 0.055)
+        } else {
+            12.92 * c
+        }
+    })
+}
+
+fn srgb_to_linear(c: f64) -> f64 {
+    let abs = c.abs();
+    if abs <= 0.04045 {
+        c / 12.92
+    } else {
+        c.signum() * ((abs + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn a98_rgb_to_linear(c: f64) -> f64 {
+    c.signum() * c.abs().powf(563.0 / 256.0)
+}
+
+fn prophoto_rgb_to_linear(c: f64) -> f64 {
+    let abs = c.abs();
+    if abs <= 16.0 / 512.0 {
+        c / 16.0
+    } else {
+        c.signum() * abs.powf(1.8)
+    }
+}
+
+fn rec2020_to_linear(c: f64) -> f64 {
+    const ALPHA: f64 = 1.09929682680944;
+    const BETA: f64 = 0.018053968510807;
+
+    let abs = c.abs();
+    if abs < BETA * 4.5 {
+        c / 4.5
+    } else {
+        c.signum() * ((abs + ALPHA - 1.0) / ALPHA).powf(1.0 / 0.45)
+    }
+}
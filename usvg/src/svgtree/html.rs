@@ -0,0 +1,201 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! A basic `foreignObject` support.
+//!
+//! We are not an HTML renderer, so only a tiny subset of XHTML is supported:
+//! text, `p`, `div`, `br`, `span`, `b`/`strong` and `i`/`em`. No CSS.
+//! The content is converted into a nested `svg` element with a `text` element per line,
+//! which gives us clipping and positioning for free.
+//!
+//! Lines are broken only by `br` and block elements. Since we do not have access to fonts
+//! during parsing, there is no automatic line wrapping.
+
+use super::{Document, NodeId, EId, AId, NodeKind};
+use crate::Error;
+
+const XHTML_NS: &str = "http://www.w3.org/1999/xhtml";
+
+// Since we do not have access to font metrics, we are using fixed values in `em`.
+const LINE_HEIGHT: f64 = 1.2;
+const BASELINE_OFFSET: f64 = 0.95;
+const PARAGRAPH_MARGIN: f64 = 1.0;
+
+pub fn parse_foreign_object(
+    node: roxmltree::Node,
+    parent_id: NodeId,
+    style_sheet: &simplecss::StyleSheet,
+    ignore_ids: bool,
+    depth: u32,
+    doc: &mut Document,
+) -> Result<(), Error> {
+    debug_assert_eq!(node.tag_name().name(), "foreignObject");
+
+    let mut layout = Layout::default();
+    layout_children(node, Style::default(), depth, &mut layout)?;
+    layout.finish_line(false);
+
+    // Empty lines affect only the position of the following ones.
+    layout.lines.retain(|(_, spans)| !spans.is_empty());
+    if layout.lines.is_empty() {
+        return Ok(());
+    }
+
+    // `foreignObject` establishes a new viewport, just like a nested `svg`.
+    let svg_id = super::parse::parse_svg_element(node, parent_id, EId::Svg, style_sheet, ignore_ids, doc)?;
+
+    for (y, spans) in layout.lines {
+        let y = format!("{}em", y + BASELINE_OFFSET);
+        let text_id = append_element(svg_id, EId::Text, &[(AId::X, "0"), (AId::Y, &y)], doc);
+        for span in spans {
+            let mut attrs = Vec::new();
+            if span.style.bold {
+                attrs.push((AId::FontWeight, "bold"));
+            }
+            if span.style.italic {
+                attrs.push((AId::FontStyle, "italic"));
+            }
+
+            let span_parent_id = if attrs.is_empty() {
+                text_id
+            } else {
+                append_element(text_id, EId::Tspan, &attrs, doc)
+            };
+
+            doc.append(span_parent_id, NodeKind::Text(span.text));
+        }
+    }
+
+    Ok(())
+}
+
+fn append_element(parent_id: NodeId, tag_name: EId, attrs: &[(AId, &str)], doc: &mut Document) -> NodeId {
+    let attrs_start_idx = doc.attrs.len();
+    for &(aid, value) in attrs {
+        super::parse::append_attribute(parent_id, tag_name, aid, value, doc);
+    }
+
+    doc.append(parent_id, NodeKind::Element {
+        tag_name,
+        attributes: attrs_start_idx..doc.attrs.len(),
+    })
+}
+
+fn layout_children(
+    parent: roxmltree::Node,
+    style: Style,
+    depth: u32,
+    layout: &mut Layout,
+) -> Result<(), Error> {
+    if depth > 1024 {
+        return Err(Error::ElementsLimitReached);
+    }
+
+    for node in parent.children() {
+        if node.is_text() {
+            layout.push_text(node.text().unwrap_or_default(), style);
+            continue;
+        }
+
+        if !node.is_element() || node.tag_name().namespace() != Some(XHTML_NS) {
+            continue;
+        }
+
+        let mut style = style;
+        let mut margin = None;
+        match node.tag_name().name() {
+            "br" => {
+                layout.finish_line(true);
+                continue;
+            }
+            "head" | "script" | "style" | "title" => continue,
+            "b" | "strong" => style.bold = true,
+            "i" | "em" => style.italic = true,
+            "p" => margin = Some(PARAGRAPH_MARGIN),
+            "html" | "body" | "div" => margin = Some(0.0),
+            // Everything else, like `span`, is treated as an inline element.
+            _ => {}
+        }
+
+        if let Some(margin) = margin {
+            layout.start_block(margin);
+        }
+
+        layout_children(node, style, depth + 1, layout)?;
+
+        if let Some(margin) = margin {
+            layout.start_block(margin);
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+struct Style {
+    bold: bool,
+    italic: bool,
+}
+
+struct Span {
+    text: String,
+    style: Style,
+}
+
+#[derive(Default)]
+struct Layout {
+    lines: Vec<(f64, Vec<Span>)>,
+    spans: Vec<Span>,
+    y: f64,
+    margin: f64,
+}
+
+impl Layout {
+    fn push_text(&mut self, text: &str, style: Style) {
+        for c in text.chars() {
+            let c = if c.is_ascii_whitespace() { ' ' } else { c };
+
+            // Collapse whitespaces and skip leading ones.
+            if c == ' ' {
+                let prev = self.spans.last().and_then(|s| s.text.chars().last());
+                if prev.is_none() || prev == Some(' ') {
+                    continue;
+                }
+            }
+
+            match self.spans.last_mut() {
+                Some(span) if span.style == style => span.text.push(c),
+                _ => self.spans.push(Span { text: c.to_string(), style }),
+            }
+        }
+    }
+
+    fn start_block(&mut self, margin: f64) {
+        self.finish_line(false);
+        // Adjoining margins are collapsed.
+        self.margin = self.margin.max(margin);
+    }
+
+    fn finish_line(&mut self, force: bool) {
+        // Skip trailing whitespaces.
+        if let Some(span) = self.spans.last_mut() {
+            if span.text.ends_with(' ') {
+                span.text.pop();
+            }
+
+            if span.text.is_empty() {
+                self.spans.pop();
+            }
+        }
+
+        if self.spans.is_empty() && !force {
+            return;
+        }
+
+        self.y += self.margin;
+        self.margin = 0.0;
+        self.lines.push((self.y, std::mem::take(&mut self.spans)));
+        self.y += LINE_HEIGHT;
+    }
+}
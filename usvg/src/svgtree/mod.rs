@@ -10,8 +10,10 @@ use crate::geom::{Rect, Transform, FuzzyEq};
 use crate::{converter, units};
 use crate::{EnableBackground, Opacity, OptionsRef, SharedPathData, Units};
 
+mod color;
 mod parse;
 mod names;
+#[cfg(feature = "text")] mod html;
 #[cfg(feature = "text")] mod text;
 
 pub use names::{EId, AId};
@@ -25,6 +27,8 @@ pub struct Document {
     nodes: Vec<NodeData>,
     attrs: Vec<Attribute>,
     links: HashMap<String, NodeId>,
+    id_handling: crate::DuplicateIdHandling,
+    profile: crate::SvgProfile,
 }
 
 impl Document {
@@ -141,6 +145,8 @@ struct NodeData {
 pub enum AttributeValue {
     None,
     CurrentColor,
+    ContextFill,
+    ContextStroke,
     Angle(svgtypes::Angle),
     AspectRatio(svgtypes::AspectRatio),
     Color(svgtypes::Color),
@@ -405,6 +411,7 @@ impl<'a> Node<'a> {
             curr: self.id(),
             is_first: true,
             is_finished: false,
+            steps: 0,
         }
     }
 
@@ -439,8 +446,39 @@ impl<'a> Node<'a> {
         self.convert_length(aid, Units::UserSpaceOnUse, state, def)
     }
 
+    /// Returns the element `transform` with `transform-origin` applied.
+    pub fn resolve_transform(&self, state: &converter::State) -> Transform {
+        let ts: Transform = self.attribute(AId::Transform).unwrap_or_default();
+        if ts.is_default() {
+            return ts;
+        }
+
+        let origin = self.attribute(AId::TransformOrigin)
+            .and_then(|v| units::convert_transform_origin(v, *self, state));
+        match origin {
+            Some((x, y)) => {
+                let mut origin_ts = Transform::new_translate(x, y);
+                origin_ts.append(&ts);
+                origin_ts.translate(-x, -y);
+                origin_ts
+            }
+            None => ts,
+        }
+    }
+
     pub fn is_visible_element(&self, opt: &OptionsRef) -> bool {
-           self.attribute(AId::Display) != Some("none")
+        let display_none = match self.attribute(AId::Display) {
+            Some("none") => true,
+            // `contents` is allowed only on containers and `tspan`.
+            // All other elements should be treated as `display="none"`.
+            // `a` is already converted into `g` at this point.
+            Some("contents") => !matches!(self.tag_name(),
+                Some(EId::G) | Some(EId::Svg) | Some(EId::Use) | Some(EId::Tspan)
+            ),
+            _ => false,
+        };
+
+           !display_none
         && self.has_valid_transform(AId::Transform)
         && crate::switch::is_condition_passed(*self, opt)
     }
@@ -582,12 +620,16 @@ impl<'a> Iterator for Descendants<'a> {
 }
 
 
+/// The maximum `xlink:href` chain length.
+const MAX_HREF_DEPTH: usize = 32;
+
 pub struct HrefIter<'a> {
     doc: &'a Document,
     origin: NodeId,
     curr: NodeId,
     is_first: bool,
     is_finished: bool,
+    steps: usize,
 }
 
 impl<'a> Iterator for HrefIter<'a> {
@@ -613,6 +655,18 @@ impl<'a> Iterator for HrefIter<'a> {
                 return None;
             }
 
+            // Stop on loops that don't include the origin node, like A -> B -> C -> B,
+            // and on chains that are too long to be real.
+            self.steps += 1;
+            if self.steps > MAX_HREF_DEPTH {
+                log::warn!(
+                    "Element '#{}' has a recursive or a too deep 'xlink:href' chain.",
+                    self.doc.get(self.origin).element_id()
+                );
+                self.is_finished = true;
+                return None;
+            }
+
             self.curr = link.id();
             Some(link.id())
         } else {
@@ -707,6 +761,14 @@ impl<'a> FromValue<'a> for &'a str {
     }
 }
 
+impl<'a> FromValue<'a> for svgtypes::PaintOrder {
+    fn get(node: Node<'a>, aid: AId) -> Option<Self> {
+        // Invalid values fallback to the default order.
+        let value: &str = node.attribute(aid)?;
+        Some(value.parse().unwrap_or_default())
+    }
+}
+
 impl<'a> FromValue<'a> for Node<'a> {
     fn get(node: Node<'a>, aid: AId) -> Option<Self> {
         let a = node.attributes().iter().find(|a| a.name == aid)?;
@@ -759,6 +821,29 @@ impl EId {
               EId::LinearGradient
             | EId::RadialGradient
             | EId::Pattern
+            | EId::Hatch
+        )
+    }
+
+    pub fn is_filter_primitive(&self) -> bool {
+        matches!(self,
+              EId::FeBlend
+            | EId::FeColorMatrix
+            | EId::FeComponentTransfer
+            | EId::FeComposite
+            | EId::FeConvolveMatrix
+            | EId::FeDiffuseLighting
+            | EId::FeDisplacementMap
+            | EId::FeDropShadow
+            | EId::FeFlood
+            | EId::FeGaussianBlur
+            | EId::FeImage
+            | EId::FeMerge
+            | EId::FeMorphology
+            | EId::FeOffset
+            | EId::FeSpecularLighting
+            | EId::FeTile
+            | EId::FeTurbulence
         )
     }
 }
@@ -766,13 +851,15 @@ impl EId {
 impl AId {
     pub fn is_presentation(&self) -> bool {
         matches!(self,
-              AId::BaselineShift
+              AId::AlignmentBaseline
+            | AId::BaselineShift
             | AId::ClipPath
             | AId::ClipRule
             | AId::Color
             | AId::ColorInterpolationFilters
             | AId::Direction
             | AId::Display
+            | AId::DominantBaseline
             | AId::Fill
             | AId::FillOpacity
             | AId::FillRule
@@ -784,7 +871,9 @@ impl AId {
             | AId::FontStretch
             | AId::FontStyle
             | AId::FontVariant
+            | AId::FontVariantNumeric
             | AId::FontWeight
+            | AId::GlyphOrientationVertical
             | AId::ImageRendering
             | AId::LetterSpacing
             | AId::MarkerEnd
@@ -793,10 +882,12 @@ impl AId {
             | AId::Mask
             | AId::Opacity
             | AId::Overflow
+            | AId::PaintOrder
             | AId::ShapeRendering
             | AId::StopColor
             | AId::StopOpacity
             | AId::Stroke
+            | AId::StrokeAlignment
             | AId::StrokeDasharray
             | AId::StrokeDashoffset
             | AId::StrokeLinecap
@@ -807,6 +898,7 @@ impl AId {
             | AId::TextAnchor
             | AId::TextDecoration
             | AId::TextRendering
+            | AId::TransformOrigin
             | AId::Visibility
             | AId::WordSpacing
             | AId::WritingMode)
@@ -822,13 +914,15 @@ impl AId {
 
     pub fn allows_inherit_value(&self) -> bool {
         matches!(self,
-              AId::BaselineShift
+              AId::AlignmentBaseline
+            | AId::BaselineShift
             | AId::ClipPath
             | AId::ClipRule
             | AId::Color
             | AId::ColorInterpolationFilters
             | AId::Direction
             | AId::Display
+            | AId::DominantBaseline
             | AId::Fill
             | AId::FillOpacity
             | AId::FillRule
@@ -840,7 +934,9 @@ impl AId {
             | AId::FontStretch
             | AId::FontStyle
             | AId::FontVariant
+            | AId::FontVariantNumeric
             | AId::FontWeight
+            | AId::GlyphOrientationVertical
             | AId::ImageRendering
             | AId::LetterSpacing
             | AId::MarkerEnd
@@ -849,10 +945,12 @@ impl AId {
             | AId::Mask
             | AId::Opacity
             | AId::Overflow
+            | AId::PaintOrder
             | AId::ShapeRendering
             | AId::StopColor
             | AId::StopOpacity
             | AId::Stroke
+            | AId::StrokeAlignment
             | AId::StrokeDasharray
             | AId::StrokeDashoffset
             | AId::StrokeLinecap
@@ -871,7 +969,8 @@ impl AId {
 
 fn is_non_inheritable(id: AId) -> bool {
     matches!(id,
-          AId::BaselineShift
+          AId::AlignmentBaseline
+        | AId::BaselineShift
         | AId::ClipPath
         | AId::Display
         | AId::Filter
@@ -882,5 +981,6 @@ fn is_non_inheritable(id: AId) -> bool {
         | AId::Overflow
         | AId::StopColor
         | AId::StopOpacity
-        | AId::TextDecoration)
+        | AId::TextDecoration
+        | AId::TransformOrigin)
 }
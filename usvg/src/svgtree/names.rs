@@ -36,7 +36,10 @@ pub enum EId {
     FeTile,
     FeTurbulence,
     Filter,
+    ForeignObject,
     G,
+    Hatch,
+    Hatchpath,
     Image,
     Line,
     LinearGradient,
@@ -63,72 +66,76 @@ pub enum EId {
 static ELEMENTS: Map<EId> = Map {
     key: 732231254413039614,
     disps: &[
-        (0, 12),
-        (1, 11),
-        (10, 26),
-        (2, 42),
-        (1, 19),
-        (0, 5),
-        (1, 13),
-        (8, 50),
-        (0, 0),
+        (0, 27),
+        (1, 31),
+        (0, 13),
         (1, 0),
-        (7, 45),
+        (0, 3),
+        (4, 15),
+        (1, 27),
+        (2, 14),
+        (5, 16),
+        (3, 9),
+        (2, 17),
+        (0, 28),
     ],
     entries: &[
-        ("feFlood", EId::FeFlood),
-        ("radialGradient", EId::RadialGradient),
-        ("feImage", EId::FeImage),
         ("stop", EId::Stop),
-        ("fePointLight", EId::FePointLight),
-        ("feConvolveMatrix", EId::FeConvolveMatrix),
-        ("feComposite", EId::FeComposite),
-        ("clipPath", EId::ClipPath),
-        ("feMerge", EId::FeMerge),
-        ("defs", EId::Defs),
+        ("feFuncA", EId::FeFuncA),
         ("mask", EId::Mask),
+        ("radialGradient", EId::RadialGradient),
         ("svg", EId::Svg),
-        ("symbol", EId::Symbol),
-        ("linearGradient", EId::LinearGradient),
-        ("feSpecularLighting", EId::FeSpecularLighting),
-        ("feFuncB", EId::FeFuncB),
-        ("filter", EId::Filter),
-        ("feFuncG", EId::FeFuncG),
-        ("circle", EId::Circle),
+        ("feMerge", EId::FeMerge),
         ("g", EId::G),
-        ("tref", EId::Tref),
-        ("feFuncA", EId::FeFuncA),
-        ("image", EId::Image),
-        ("text", EId::Text),
-        ("line", EId::Line),
-        ("pattern", EId::Pattern),
+        ("feComponentTransfer", EId::FeComponentTransfer),
+        ("rect", EId::Rect),
+        ("feFuncG", EId::FeFuncG),
+        ("feBlend", EId::FeBlend),
+        ("feImage", EId::FeImage),
         ("use", EId::Use),
-        ("feDropShadow", EId::FeDropShadow),
-        ("feSpotLight", EId::FeSpotLight),
-        ("marker", EId::Marker),
-        ("style", EId::Style),
-        ("switch", EId::Switch),
+        ("filter", EId::Filter),
+        ("clipPath", EId::ClipPath),
         ("tspan", EId::Tspan),
-        ("feColorMatrix", EId::FeColorMatrix),
-        ("feOffset", EId::FeOffset),
-        ("path", EId::Path),
-        ("feGaussianBlur", EId::FeGaussianBlur),
-        ("feTile", EId::FeTile),
-        ("feTurbulence", EId::FeTurbulence),
-        ("feMergeNode", EId::FeMergeNode),
+        ("fePointLight", EId::FePointLight),
+        ("line", EId::Line),
+        ("feFuncB", EId::FeFuncB),
         ("feMorphology", EId::FeMorphology),
-        ("a", EId::A),
-        ("textPath", EId::TextPath),
-        ("ellipse", EId::Ellipse),
-        ("feComponentTransfer", EId::FeComponentTransfer),
         ("feDistantLight", EId::FeDistantLight),
+        ("feTurbulence", EId::FeTurbulence),
+        ("feDropShadow", EId::FeDropShadow),
+        ("defs", EId::Defs),
+        ("ellipse", EId::Ellipse),
+        ("feConvolveMatrix", EId::FeConvolveMatrix),
+        ("feColorMatrix", EId::FeColorMatrix),
+        ("circle", EId::Circle),
+        ("feMergeNode", EId::FeMergeNode),
+        ("symbol", EId::Symbol),
         ("polyline", EId::Polyline),
+        ("image", EId::Image),
+        ("feDiffuseLighting", EId::FeDiffuseLighting),
         ("polygon", EId::Polygon),
-        ("feBlend", EId::FeBlend),
+        ("a", EId::A),
+        ("linearGradient", EId::LinearGradient),
         ("feDisplacementMap", EId::FeDisplacementMap),
-        ("feDiffuseLighting", EId::FeDiffuseLighting),
-        ("rect", EId::Rect),
+        ("text", EId::Text),
+        ("feComposite", EId::FeComposite),
+        ("switch", EId::Switch),
+        ("feSpotLight", EId::FeSpotLight),
+        ("feFlood", EId::FeFlood),
+        ("hatchpath", EId::Hatchpath),
+        ("feOffset", EId::FeOffset),
+        ("marker", EId::Marker),
         ("feFuncR", EId::FeFuncR),
+        ("path", EId::Path),
+        ("style", EId::Style),
+        ("foreignObject", EId::ForeignObject),
+        ("feTile", EId::FeTile),
+        ("textPath", EId::TextPath),
+        ("feGaussianBlur", EId::FeGaussianBlur),
+        ("pattern", EId::Pattern),
+        ("tref", EId::Tref),
+        ("feSpecularLighting", EId::FeSpecularLighting),
+        ("hatch", EId::Hatch),
     ],
 };
 
@@ -157,8 +164,10 @@ impl fmt::Display for EId {
 
 #[derive(Clone, Copy, PartialEq)]
 pub enum AId {
+    AlignmentBaseline,
     Amplitude,
     Azimuth,
+    BackgroundColor,
     BaseFrequency,
     BaselineShift,
     Bias,
@@ -175,6 +184,7 @@ pub enum AId {
     Direction,
     Display,
     Divisor,
+    DominantBaseline,
     Dx,
     Dy,
     EdgeMode,
@@ -193,11 +203,15 @@ pub enum AId {
     FontStretch,
     FontStyle,
     FontVariant,
+    FontVariantNumeric,
     FontWeight,
     Fx,
     Fy,
+    GlyphOrientationVertical,
     GradientTransform,
     GradientUnits,
+    HatchContentUnits,
+    HatchUnits,
     Height,
     Href,
     Id,
@@ -231,9 +245,11 @@ pub enum AId {
     Order,
     Orient,
     Overflow,
+    PaintOrder,
     PatternContentUnits,
     PatternTransform,
     PatternUnits,
+    Pitch,
     Points,
     PointsAtX,
     PointsAtY,
@@ -265,6 +281,7 @@ pub enum AId {
     StopColor,
     StopOpacity,
     Stroke,
+    StrokeAlignment,
     StrokeDasharray,
     StrokeDashoffset,
     StrokeLinecap,
@@ -282,6 +299,7 @@ pub enum AId {
     TextDecoration,
     TextRendering,
     Transform,
+    TransformOrigin,
     Type,
     Values,
     ViewBox,
@@ -303,178 +321,191 @@ pub enum AId {
 static ATTRIBUTES: Map<AId> = Map {
     key: 3213172566270843353,
     disps: &[
-        (0, 32),
-        (0, 1),
-        (0, 16),
-        (0, 31),
-        (9, 125),
-        (1, 4),
-        (5, 102),
-        (0, 44),
-        (11, 69),
-        (5, 77),
-        (0, 20),
         (0, 8),
-        (0, 3),
-        (0, 8),
-        (2, 43),
-        (1, 0),
+        (3, 55),
+        (0, 102),
+        (2, 78),
         (0, 0),
-        (25, 133),
-        (12, 99),
-        (0, 3),
-        (0, 68),
-        (0, 33),
-        (1, 54),
-        (0, 64),
-        (2, 119),
-        (0, 18),
-        (0, 7),
-        (1, 19),
-        (9, 46),
+        (6, 0),
+        (1, 55),
+        (0, 2),
+        (2, 0),
+        (0, 2),
+        (0, 4),
+        (4, 76),
+        (0, 134),
+        (6, 19),
+        (0, 39),
+        (1, 96),
+        (0, 97),
+        (0, 2),
+        (1, 6),
+        (84, 58),
+        (92, 65),
+        (0, 0),
+        (0, 5),
+        (0, 88),
+        (0, 72),
+        (15, 80),
+        (11, 85),
+        (7, 9),
+        (0, 27),
+        (0, 132),
+        (18, 124),
     ],
     entries: &[
-        ("pointsAtZ", AId::PointsAtZ),
+        ("color-interpolation-filters", AId::ColorInterpolationFilters),
+        ("markerUnits", AId::MarkerUnits),
+        ("amplitude", AId::Amplitude),
+        ("tableValues", AId::TableValues),
+        ("filterUnits", AId::FilterUnits),
+        ("width", AId::Width),
+        ("opacity", AId::Opacity),
+        ("kernelUnitLength", AId::KernelUnitLength),
+        ("stroke-alignment", AId::StrokeAlignment),
+        ("baseFrequency", AId::BaseFrequency),
+        ("baseline-shift", AId::BaselineShift),
+        ("stroke-opacity", AId::StrokeOpacity),
+        ("gradientTransform", AId::GradientTransform),
+        ("azimuth", AId::Azimuth),
+        ("cx", AId::Cx),
+        ("refX", AId::RefX),
+        ("pitch", AId::Pitch),
+        ("marker-end", AId::MarkerEnd),
+        ("in", AId::In),
+        ("patternTransform", AId::PatternTransform),
+        ("stroke-linecap", AId::StrokeLinecap),
+        ("in2", AId::In2),
+        ("maskContentUnits", AId::MaskContentUnits),
+        ("mode", AId::Mode),
+        ("space", AId::Space),
+        ("maskUnits", AId::MaskUnits),
+        ("pointsAtX", AId::PointsAtX),
+        ("operator", AId::Operator),
+        ("surfaceScale", AId::SurfaceScale),
+        ("display", AId::Display),
+        ("elevation", AId::Elevation),
+        ("radius", AId::Radius),
+        ("id", AId::Id),
+        ("class", AId::Class),
+        ("k1", AId::K1),
+        ("dominant-baseline", AId::DominantBaseline),
+        ("stop-opacity", AId::StopOpacity),
+        ("direction", AId::Direction),
         ("stop-color", AId::StopColor),
-        ("xChannelSelector", AId::XChannelSelector),
-        ("systemLanguage", AId::SystemLanguage),
+        ("k2", AId::K2),
+        ("diffuseConstant", AId::DiffuseConstant),
+        ("flood-color", AId::FloodColor),
+        ("specularExponent", AId::SpecularExponent),
+        ("font-style", AId::FontStyle),
+        ("letter-spacing", AId::LetterSpacing),
+        ("orient", AId::Orient),
+        ("fx", AId::Fx),
+        ("rx", AId::Rx),
+        ("font-stretch", AId::FontStretch),
         ("cy", AId::Cy),
-        ("dy", AId::Dy),
+        ("markerHeight", AId::MarkerHeight),
+        ("visibility", AId::Visibility),
+        ("d", AId::D),
+        ("slope", AId::Slope),
+        ("stitchTiles", AId::StitchTiles),
+        ("values", AId::Values),
+        ("result", AId::Result),
+        ("startOffset", AId::StartOffset),
+        ("scale", AId::Scale),
+        ("exponent", AId::Exponent),
+        ("font-variant-numeric", AId::FontVariantNumeric),
+        ("rotate", AId::Rotate),
+        ("specularConstant", AId::SpecularConstant),
+        ("text-rendering", AId::TextRendering),
+        ("gradientUnits", AId::GradientUnits),
+        ("word-spacing", AId::WordSpacing),
+        ("targetY", AId::TargetY),
+        ("transform-origin", AId::TransformOrigin),
+        ("k3", AId::K3),
+        ("background-color", AId::BackgroundColor),
+        ("font-variant", AId::FontVariant),
+        ("primitiveUnits", AId::PrimitiveUnits),
         ("preserveAlpha", AId::PreserveAlpha),
-        ("preserveAspectRatio", AId::PreserveAspectRatio),
+        ("writing-mode", AId::WritingMode),
+        ("points", AId::Points),
+        ("overflow", AId::Overflow),
         ("lighting-color", AId::LightingColor),
-        ("stroke-dashoffset", AId::StrokeDashoffset),
-        ("word-spacing", AId::WordSpacing),
-        ("font-style", AId::FontStyle),
-        ("edgeMode", AId::EdgeMode),
-        ("pointsAtY", AId::PointsAtY),
-        ("id", AId::Id),
+        ("stdDeviation", AId::StdDeviation),
+        ("dy", AId::Dy),
+        ("filter", AId::Filter),
+        ("patternContentUnits", AId::PatternContentUnits),
         ("type", AId::Type),
-        ("targetX", AId::TargetX),
-        ("fill", AId::Fill),
-        ("k3", AId::K3),
-        ("kernelUnitLength", AId::KernelUnitLength),
+        ("k4", AId::K4),
+        ("stroke-miterlimit", AId::StrokeMiterlimit),
+        ("text-anchor", AId::TextAnchor),
+        ("color", AId::Color),
+        ("spreadMethod", AId::SpreadMethod),
+        ("bias", AId::Bias),
+        ("font-family", AId::FontFamily),
         ("viewBox", AId::ViewBox),
-        ("baseFrequency", AId::BaseFrequency),
-        ("stroke", AId::Stroke),
-        ("divisor", AId::Divisor),
-        ("slope", AId::Slope),
-        ("markerUnits", AId::MarkerUnits),
-        ("d", AId::D),
-        ("clipPathUnits", AId::ClipPathUnits),
-        ("stop-opacity", AId::StopOpacity),
-        ("transform", AId::Transform),
         ("stroke-dasharray", AId::StrokeDasharray),
-        ("dx", AId::Dx),
-        ("specularExponent", AId::SpecularExponent),
+        ("pointsAtZ", AId::PointsAtZ),
+        ("targetX", AId::TargetX),
+        ("flood-opacity", AId::FloodOpacity),
         ("z", AId::Z),
-        ("direction", AId::Direction),
-        ("letter-spacing", AId::LetterSpacing),
-        ("stroke-miterlimit", AId::StrokeMiterlimit),
-        ("overflow", AId::Overflow),
+        ("offset", AId::Offset),
         ("order", AId::Order),
-        ("intercept", AId::Intercept),
-        ("operator", AId::Operator),
-        ("font-variant", AId::FontVariant),
-        ("filterUnits", AId::FilterUnits),
-        ("values", AId::Values),
-        ("filter", AId::Filter),
-        ("rotate", AId::Rotate),
-        ("href", AId::Href),
-        ("requiredExtensions", AId::RequiredExtensions),
-        ("font-stretch", AId::FontStretch),
-        ("clip-path", AId::ClipPath),
-        ("x1", AId::X1),
-        ("pointsAtX", AId::PointsAtX),
-        ("k4", AId::K4),
-        ("font-weight", AId::FontWeight),
-        ("class", AId::Class),
-        ("patternTransform", AId::PatternTransform),
-        ("markerHeight", AId::MarkerHeight),
-        ("writing-mode", AId::WritingMode),
+        ("enable-background", AId::EnableBackground),
+        ("mask", AId::Mask),
+        ("hatchUnits", AId::HatchUnits),
         ("clip-rule", AId::ClipRule),
+        ("y2", AId::Y2),
+        ("divisor", AId::Divisor),
+        ("requiredFeatures", AId::RequiredFeatures),
+        ("image-rendering", AId::ImageRendering),
+        ("seed", AId::Seed),
+        ("height", AId::Height),
+        ("stroke-linejoin", AId::StrokeLinejoin),
+        ("fill-rule", AId::FillRule),
+        ("style", AId::Style),
+        ("fy", AId::Fy),
+        ("preserveAspectRatio", AId::PreserveAspectRatio),
+        ("kernelMatrix", AId::KernelMatrix),
         ("y1", AId::Y1),
-        ("patternContentUnits", AId::PatternContentUnits),
-        ("primitiveUnits", AId::PrimitiveUnits),
-        ("x2", AId::X2),
-        ("y", AId::Y),
-        ("marker-mid", AId::MarkerMid),
-        ("offset", AId::Offset),
-        ("stroke-width", AId::StrokeWidth),
         ("markerWidth", AId::MarkerWidth),
-        ("fill-opacity", AId::FillOpacity),
-        ("refX", AId::RefX),
-        ("maskContentUnits", AId::MaskContentUnits),
         ("shape-rendering", AId::ShapeRendering),
-        ("amplitude", AId::Amplitude),
-        ("stitchTiles", AId::StitchTiles),
-        ("maskUnits", AId::MaskUnits),
-        ("fx", AId::Fx),
-        ("color", AId::Color),
+        ("stroke-dashoffset", AId::StrokeDashoffset),
         ("numOctaves", AId::NumOctaves),
-        ("stdDeviation", AId::StdDeviation),
-        ("y2", AId::Y2),
-        ("mask", AId::Mask),
-        ("spreadMethod", AId::SpreadMethod),
-        ("display", AId::Display),
-        ("opacity", AId::Opacity),
-        ("font-size", AId::FontSize),
-        ("stroke-linejoin", AId::StrokeLinejoin),
-        ("image-rendering", AId::ImageRendering),
-        ("azimuth", AId::Azimuth),
-        ("in", AId::In),
-        ("ry", AId::Ry),
-        ("bias", AId::Bias),
-        ("radius", AId::Radius),
-        ("enable-background", AId::EnableBackground),
-        ("gradientUnits", AId::GradientUnits),
-        ("specularConstant", AId::SpecularConstant),
-        ("limitingConeAngle", AId::LimitingConeAngle),
-        ("flood-color", AId::FloodColor),
-        ("scale", AId::Scale),
+        ("xChannelSelector", AId::XChannelSelector),
+        ("fill", AId::Fill),
+        ("font-weight", AId::FontWeight),
+        ("alignment-baseline", AId::AlignmentBaseline),
+        ("x1", AId::X1),
         ("x", AId::X),
-        ("diffuseConstant", AId::DiffuseConstant),
+        ("href", AId::Href),
+        ("intercept", AId::Intercept),
         ("refY", AId::RefY),
-        ("yChannelSelector", AId::YChannelSelector),
-        ("style", AId::Style),
-        ("gradientTransform", AId::GradientTransform),
-        ("fill-rule", AId::FillRule),
-        ("height", AId::Height),
-        ("kernelMatrix", AId::KernelMatrix),
-        ("text-anchor", AId::TextAnchor),
-        ("stroke-linecap", AId::StrokeLinecap),
-        ("text-decoration", AId::TextDecoration),
-        ("rx", AId::Rx),
-        ("requiredFeatures", AId::RequiredFeatures),
-        ("patternUnits", AId::PatternUnits),
-        ("mode", AId::Mode),
+        ("limitingConeAngle", AId::LimitingConeAngle),
         ("marker-start", AId::MarkerStart),
-        ("visibility", AId::Visibility),
-        ("result", AId::Result),
-        ("seed", AId::Seed),
-        ("in2", AId::In2),
-        ("cx", AId::Cx),
-        ("k2", AId::K2),
-        ("flood-opacity", AId::FloodOpacity),
-        ("k1", AId::K1),
-        ("text-rendering", AId::TextRendering),
+        ("yChannelSelector", AId::YChannelSelector),
+        ("ry", AId::Ry),
+        ("systemLanguage", AId::SystemLanguage),
         ("r", AId::R),
-        ("marker-end", AId::MarkerEnd),
-        ("surfaceScale", AId::SurfaceScale),
-        ("tableValues", AId::TableValues),
-        ("orient", AId::Orient),
-        ("color-interpolation-filters", AId::ColorInterpolationFilters),
-        ("points", AId::Points),
-        ("baseline-shift", AId::BaselineShift),
-        ("elevation", AId::Elevation),
-        ("width", AId::Width),
-        ("font-family", AId::FontFamily),
-        ("space", AId::Space),
-        ("startOffset", AId::StartOffset),
-        ("fy", AId::Fy),
-        ("stroke-opacity", AId::StrokeOpacity),
-        ("targetY", AId::TargetY),
-        ("exponent", AId::Exponent),
+        ("clipPathUnits", AId::ClipPathUnits),
+        ("transform", AId::Transform),
+        ("patternUnits", AId::PatternUnits),
+        ("text-decoration", AId::TextDecoration),
+        ("dx", AId::Dx),
+        ("clip-path", AId::ClipPath),
+        ("y", AId::Y),
+        ("hatchContentUnits", AId::HatchContentUnits),
+        ("paint-order", AId::PaintOrder),
+        ("edgeMode", AId::EdgeMode),
+        ("pointsAtY", AId::PointsAtY),
+        ("x2", AId::X2),
+        ("stroke-width", AId::StrokeWidth),
+        ("font-size", AId::FontSize),
+        ("requiredExtensions", AId::RequiredExtensions),
+        ("stroke", AId::Stroke),
+        ("glyph-orientation-vertical", AId::GlyphOrientationVertical),
+        ("marker-mid", AId::MarkerMid),
+        ("fill-opacity", AId::FillOpacity),
     ],
 };
 
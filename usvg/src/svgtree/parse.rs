@@ -6,7 +6,7 @@ use std::rc::Rc;
 use std::str::FromStr;
 use std::collections::HashMap;
 
-use crate::{Rect, Error, EnableBackground, Opacity};
+use crate::{Rect, DuplicateIdHandling, Error, EnableBackground, Opacity, SvgProfile};
 use super::{Document, Attribute, AId, EId, Node, NodeId, NodeKind, NodeData, AttributeValue};
 
 const SVG_NS: &str = "http://www.w3.org/2000/svg";
@@ -15,8 +15,12 @@ const XML_NAMESPACE_NS: &str = "http://www.w3.org/XML/1998/namespace";
 
 
 impl Document {
-    pub fn parse(xml: &roxmltree::Document) -> Result<Document, Error> {
-        parse(xml)
+    pub fn parse(
+        xml: &roxmltree::Document,
+        id_handling: DuplicateIdHandling,
+        profile: SvgProfile,
+    ) -> Result<Document, Error> {
+        parse(xml, id_handling, profile)
     }
 
     pub(super) fn append(&mut self, parent_id: NodeId, kind: NodeKind) -> NodeId {
@@ -65,11 +69,17 @@ impl Document {
     }
 }
 
-fn parse(xml: &roxmltree::Document) -> Result<Document, Error> {
+fn parse(
+    xml: &roxmltree::Document,
+    id_handling: DuplicateIdHandling,
+    profile: SvgProfile,
+) -> Result<Document, Error> {
     let mut doc = Document {
         nodes: Vec::new(),
         attrs: Vec::new(),
         links: HashMap::new(),
+        id_handling,
+        profile,
     };
 
     // Add a root node.
@@ -81,7 +91,15 @@ fn parse(xml: &roxmltree::Document) -> Result<Document, Error> {
         kind: NodeKind::Root,
     });
 
-    let style_sheet = resolve_css(xml);
+    let style_sheet = if profile == SvgProfile::Tiny12 {
+        if xml.descendants().any(|n| n.has_tag_name("style")) {
+            log::warn!("'style' is not supported by {}. Skipped.", profile_name(profile));
+        }
+
+        simplecss::StyleSheet::new()
+    } else {
+        resolve_css(xml)
+    };
 
     parse_xml_node_children(xml.root(), xml.root(), doc.root().id, &style_sheet, false, 0, &mut doc)?;
 
@@ -99,6 +117,21 @@ fn parse(xml: &roxmltree::Document) -> Result<Document, Error> {
     let mut links = HashMap::new();
     for node in doc.descendants() {
         if let Some(id) = node.attribute::<&str>(AId::Id) {
+            if links.contains_key(id) {
+                match id_handling {
+                    DuplicateIdHandling::UseFirst => {
+                        log::warn!("Element ID '{}' is not unique. The first element will be used.", id);
+                        continue;
+                    }
+                    DuplicateIdHandling::UseLast => {
+                        log::warn!("Element ID '{}' is not unique. The last element will be used.", id);
+                    }
+                    DuplicateIdHandling::Error => {
+                        return Err(Error::DuplicateId(id.to_string()));
+                    }
+                }
+            }
+
             links.insert(id.to_string(), node.id);
         }
     }
@@ -167,6 +200,20 @@ fn parse_xml_node(
         tag_name = EId::G;
     }
 
+    if !is_supported_element(doc.profile, tag_name) {
+        log::warn!("'{}' is not supported by {}. Skipped.", tag_name, profile_name(doc.profile));
+        return Ok(());
+    }
+
+    if tag_name == EId::ForeignObject {
+        #[cfg(feature = "text")]
+        {
+            super::html::parse_foreign_object(node, parent_id, style_sheet, ignore_ids, depth + 1, doc)?;
+        }
+
+        return Ok(());
+    }
+
     let node_id = parse_svg_element(node, parent_id, tag_name, style_sheet, ignore_ids, doc)?;
     if tag_name == EId::Text {
         #[cfg(feature = "text")]
@@ -235,36 +282,78 @@ pub(super) fn parse_svg_element(
         }
     };
 
-    // Apply CSS.
-    for rule in &style_sheet.rules {
-        if rule.selector.matches(&XmlNode(xml_node)) {
-            for declaration in &rule.declarations {
+    let mut individual_ts = IndividualTransforms::default();
+
+    // The CSS cascade order, from the lowest priority to the highest:
+    //
+    // 1. Presentation attributes (already copied above).
+    // 2. Style sheet rules. Already sorted by specificity and then by source order.
+    // 3. The `style` attribute.
+    // 4. Style sheet rules with `!important`.
+    // 5. The `style` attribute declarations with `!important`.
+    for &important in &[false, true] {
+        // Apply CSS.
+        for rule in &style_sheet.rules {
+            if rule.selector.matches(&XmlNode(xml_node)) {
+                for declaration in &rule.declarations {
+                    if declaration.important != important {
+                        continue;
+                    }
+
+                    // TODO: preform XML attribute normalization
+                    if individual_ts.parse(declaration.name, declaration.value) {
+                        continue;
+                    }
+
+                    if let Some(aid) = AId::from_str(declaration.name) {
+                        if is_css_property(aid, tag_name) {
+                            insert_attribute(aid, declaration.value);
+                        }
+                    } else if declaration.name == "marker" {
+                        insert_attribute(AId::MarkerStart, declaration.value);
+                        insert_attribute(AId::MarkerMid, declaration.value);
+                        insert_attribute(AId::MarkerEnd, declaration.value);
+                    }
+                }
+            }
+        }
+
+        // Split a `style` attribute.
+        if let Some(value) = xml_node.attribute("style") {
+            for declaration in simplecss::DeclarationTokenizer::from(value) {
+                if declaration.important != important {
+                    continue;
+                }
+
                 // TODO: preform XML attribute normalization
+                if individual_ts.parse(declaration.name, declaration.value) {
+                    continue;
+                }
+
                 if let Some(aid) = AId::from_str(declaration.name) {
-                    // Parse only the presentation attributes.
-                    // `transform` isn't a presentation attribute, but should be parsed anyway.
-                    if aid.is_presentation() || aid == AId::Transform {
+                    if is_css_property(aid, tag_name) {
                         insert_attribute(aid, declaration.value);
                     }
-                } else if declaration.name == "marker" {
-                    insert_attribute(AId::MarkerStart, declaration.value);
-                    insert_attribute(AId::MarkerMid, declaration.value);
-                    insert_attribute(AId::MarkerEnd, declaration.value);
                 }
             }
         }
     }
 
-    // Split a `style` attribute.
-    if let Some(value) = xml_node.attribute("style") {
-        for declaration in simplecss::DeclarationTokenizer::from(value) {
-            // TODO: preform XML attribute normalization
-            if let Some(aid) = AId::from_str(declaration.name) {
-                // Parse only the presentation attributes.
-                // `transform` isn't a presentation attribute, but should be parsed anyway.
-                if aid.is_presentation() || aid == AId::Transform {
-                    insert_attribute(aid, declaration.value);
-                }
+    let individual_ts = individual_ts.to_transform();
+    if individual_ts.is_some() && doc.profile != SvgProfile::SVG2 {
+        log::warn!("'translate', 'rotate' and 'scale' properties are not supported by {}. Skipped.",
+                   profile_name(doc.profile));
+    } else if let Some(ts) = individual_ts {
+        let attrs = &mut doc.attrs[attrs_start_idx..];
+        match attrs.iter_mut().find(|a| a.name == AId::Transform) {
+            Some(Attribute { value: AttributeValue::Transform(ref mut transform), .. }) => {
+                transform.prepend(&ts);
+            }
+            _ => {
+                doc.attrs.push(Attribute {
+                    name: AId::Transform,
+                    value: AttributeValue::Transform(ts),
+                });
             }
         }
     }
@@ -281,7 +370,172 @@ pub(super) fn parse_svg_element(
     Ok(node_id)
 }
 
-fn append_attribute(
+// The CSS Transforms 2 individual transform properties: `translate`, `rotate` and `scale`.
+//
+// They are CSS-only, so they are not attributes and will be merged into `transform`.
+// Only 2D transforms are supported.
+#[derive(Default)]
+struct IndividualTransforms {
+    translate: Option<crate::Transform>,
+    rotate: Option<crate::Transform>,
+    scale: Option<crate::Transform>,
+}
+
+impl IndividualTransforms {
+    // Returns `false` when `name` is not an individual transform property.
+    //
+    // Invalid values are ignored, like any other invalid CSS declaration.
+    fn parse(&mut self, name: &str, value: &str) -> bool {
+        let (ts, slot) = match name {
+            "translate" => (parse_css_translate(value), &mut self.translate),
+            "rotate" => (parse_css_rotate(value), &mut self.rotate),
+            "scale" => (parse_css_scale(value), &mut self.scale),
+            _ => return false,
+        };
+
+        match ts {
+            Some(ts) => *slot = Some(ts),
+            None => log::warn!("Failed to parse {} value: '{}'.", name, value),
+        }
+
+        true
+    }
+
+    // 'The individual transform properties are applied first, in the order
+    // translate, rotate and scale, and then the transform property.'
+    fn to_transform(&self) -> Option<crate::Transform> {
+        if self.translate.is_none() && self.rotate.is_none() && self.scale.is_none() {
+            return None;
+        }
+
+        let mut ts = crate::Transform::default();
+        for t in [self.translate, self.rotate, self.scale].iter().flatten() {
+            ts.append(t);
+        }
+
+        Some(ts)
+    }
+}
+
+// `none | <length-percentage> [ <length-percentage> <length>? ]?`
+//
+// Percentages and relative units are not supported,
+// since they cannot be resolved during parsing.
+fn parse_css_translate(value: &str) -> Option<crate::Transform> {
+    let value = value.trim();
+    if value == "none" {
+        return Some(crate::Transform::default());
+    }
+
+    let mut list = Vec::new();
+    for token in value.split_whitespace() {
+        let length = svgtypes::Length::from_str(token).ok()?;
+        // Absolute units have fixed ratios in CSS.
+        let n = length.number;
+        list.push(match length.unit {
+            svgtypes::LengthUnit::None | svgtypes::LengthUnit::Px => n,
+            svgtypes::LengthUnit::In => n * 96.0,
+            svgtypes::LengthUnit::Cm => n * 96.0 / 2.54,
+            svgtypes::LengthUnit::Mm => n * 96.0 / 25.4,
+            svgtypes::LengthUnit::Pt => n * 4.0 / 3.0,
+            svgtypes::LengthUnit::Pc => n * 16.0,
+            _ => return None,
+        });
+    }
+
+    // The third value is a translation along the z axis, which has no effect in 2D.
+    match list.as_slice() {
+        [tx] => Some(crate::Transform::new_translate(*tx, 0.0)),
+        [tx, ty] | [tx, ty, _] => Some(crate::Transform::new_translate(*tx, *ty)),
+        _ => None,
+    }
+}
+
+// `none | <angle> | [ x | y | z | <number>{3} ] && <angle>`
+//
+// Only rotations around the z axis are supported.
+fn parse_css_rotate(value: &str) -> Option<crate::Transform> {
+    let value = value.trim();
+    if value == "none" {
+        return Some(crate::Transform::default());
+    }
+
+    let parse_angle = |s: &str| svgtypes::Angle::from_str(s).ok().map(|a| a.to_degrees());
+
+    let tokens: Vec<_> = value.split_whitespace().collect();
+    let angle = match tokens.as_slice() {
+        [angle] => parse_angle(angle)?,
+        ["z", angle] | [angle, "z"] => parse_angle(angle)?,
+        [x, y, z, angle] => {
+            let x = svgtypes::Number::from_str(x).ok()?.0;
+            let y = svgtypes::Number::from_str(y).ok()?.0;
+            let z = svgtypes::Number::from_str(z).ok()?.0;
+            if x != 0.0 || y != 0.0 || z == 0.0 {
+                return None;
+            }
+
+            // A rotation around `0 0 -1` is the same as a reversed rotation around `0 0 1`.
+            let angle = parse_angle(angle)?;
+            if z < 0.0 { -angle } else { angle }
+        }
+        _ => return None,
+    };
+
+    Some(crate::Transform::new_rotate(angle))
+}
+
+// `none | [ <number> | <percentage> ]{1,3}`
+fn parse_css_scale(value: &str) -> Option<crate::Transform> {
+    let value = value.trim();
+    if value == "none" {
+        return Some(crate::Transform::default());
+    }
+
+    let mut list = Vec::new();
+    for token in value.split_whitespace() {
+        let length = svgtypes::Length::from_str(token).ok()?;
+        list.push(match length.unit {
+            svgtypes::LengthUnit::None => length.number,
+            svgtypes::LengthUnit::Percent => length.number / 100.0,
+            _ => return None,
+        });
+    }
+
+    // The third value is a scale along the z axis, which has no effect in 2D.
+    match list.as_slice() {
+        [s] => Some(crate::Transform::new_scale(*s, *s)),
+        [sx, sy] | [sx, sy, _] => Some(crate::Transform::new_scale(*sx, *sy)),
+        _ => None,
+    }
+}
+
+// Checks that an attribute can be set via CSS.
+//
+// Only the presentation attributes and the SVG 2 geometry properties are allowed.
+// `transform` isn't a presentation attribute, but should be parsed anyway.
+fn is_css_property(aid: AId, tag_name: EId) -> bool {
+    if aid.is_presentation() || aid == AId::Transform {
+        return true;
+    }
+
+    // Geometry properties apply only to specific elements.
+    // For example, `x` on `text` is a list of coordinates and not a property.
+    //
+    // The `svg` element size is ignored on purpose, since style sheets
+    // written for HTML pages often set it and it makes little sense for a standalone image.
+    match aid {
+        AId::Cx | AId::Cy => matches!(tag_name, EId::Circle | EId::Ellipse),
+        AId::R => tag_name == EId::Circle,
+        AId::BackgroundColor => tag_name == EId::G,
+        AId::Rx | AId::Ry => matches!(tag_name, EId::Rect | EId::Ellipse),
+        AId::X | AId::Y | AId::Width | AId::Height => {
+            matches!(tag_name, EId::Rect | EId::Image | EId::Use)
+        }
+        _ => false,
+    }
+}
+
+pub(super) fn append_attribute(
     parent_id: NodeId,
     tag_name: EId,
     aid: AId,
@@ -302,6 +556,12 @@ fn append_attribute(
         return false;
     }
 
+    if !is_supported_attribute(doc.profile, tag_name, aid, value) {
+        log::warn!("'{}' on '{}' is not supported by {}. Skipped.",
+                   aid, tag_name, profile_name(doc.profile));
+        return false;
+    }
+
     if aid.allows_inherit_value() && value == "inherit" {
         return resolve_inherit(parent_id, tag_name, aid, doc);
     }
@@ -310,6 +570,76 @@ fn append_attribute(
     true
 }
 
+pub(super) fn is_supported_element(profile: SvgProfile, tag_name: EId) -> bool {
+    let is_svg2 = matches!(tag_name, EId::Hatch | EId::Hatchpath | EId::FeDropShadow);
+    match profile {
+        SvgProfile::SVG2 => true,
+        SvgProfile::Full11 => !is_svg2,
+        SvgProfile::Tiny12 => {
+            !is_svg2 && !tag_name.is_filter_primitive() && !matches!(tag_name,
+                  EId::ClipPath
+                | EId::Filter
+                | EId::Marker
+                | EId::Mask
+                | EId::Pattern
+                | EId::Symbol
+                | EId::TextPath
+                | EId::Tref
+            )
+        }
+    }
+}
+
+fn is_supported_attribute(profile: SvgProfile, tag_name: EId, aid: AId, value: &str) -> bool {
+    let is_svg2 = match aid {
+        AId::PaintOrder | AId::TransformOrigin | AId::StrokeAlignment => true,
+        // Only `path` had a `d` attribute before SVG 2.
+        AId::D => tag_name != EId::Path,
+        AId::Fill | AId::Stroke => value == "context-fill" || value == "context-stroke",
+        AId::ClipPath => value.trim_start().starts_with("path("),
+        // Filter functions, like `blur()`, were added in SVG 2.
+        AId::Filter => !value.split(')').all(|func| {
+            let func = func.trim();
+            func.is_empty() || func == "none" || func.starts_with("url(")
+        }),
+        _ => false,
+    };
+
+    match profile {
+        SvgProfile::SVG2 => true,
+        SvgProfile::Full11 => !is_svg2,
+        SvgProfile::Tiny12 => {
+            let is_advanced_gradient = tag_name.is_gradient() && matches!(aid,
+                  AId::Fx
+                | AId::Fy
+                | AId::GradientTransform
+                | AId::Href
+                | AId::SpreadMethod
+            );
+
+            // `opacity` applies only to `image` in SVG Tiny 1.2.
+            let is_opacity = aid == AId::Opacity && tag_name != EId::Image;
+
+            !is_svg2 && !is_advanced_gradient && !is_opacity && !matches!(aid,
+                  AId::ClipPath
+                | AId::Filter
+                | AId::Mask
+                | AId::MarkerStart
+                | AId::MarkerMid
+                | AId::MarkerEnd
+            )
+        }
+    }
+}
+
+pub(super) fn profile_name(profile: SvgProfile) -> &'static str {
+    match profile {
+        SvgProfile::Full11 => "SVG 1.1 Full",
+        SvgProfile::Tiny12 => "SVG Tiny 1.2",
+        SvgProfile::SVG2 => "SVG 2",
+    }
+}
+
 fn parse_svg_attribute(
     tag_name: EId,
     aid: AId,
@@ -350,15 +680,32 @@ fn parse_svg_attribute(
         | AId::Cx | AId::Cy
         | AId::Fx | AId::Fy
         | AId::RefX | AId::RefY
-        | AId::Width | AId::Height
+        | AId::Pitch
         | AId::MarkerWidth | AId::MarkerHeight
         | AId::StartOffset => {
             AttributeValue::Length(svgtypes::Length::from_str(value).ok()?)
         }
 
+          AId::Width
+        | AId::Height => {
+            // `auto` is valid only on elements that establish a viewport or embed content.
+            // It's treated as an unset value, which is 100% for `svg`, `symbol` and `use`.
+            // On other elements, like `pattern`, it's an invalid length and will be skipped,
+            // so the value can still be inherited via `xlink:href`.
+            let allows_auto = matches!(tag_name,
+                EId::Svg | EId::Symbol | EId::Use | EId::Image | EId::ForeignObject
+            );
+            match value {
+                "auto" if allows_auto => AttributeValue::String(value.to_string()),
+                _ => AttributeValue::Length(svgtypes::Length::from_str(value).ok()?),
+            }
+        }
+
         AId::Offset => {
             if let EId::FeFuncR | EId::FeFuncG | EId::FeFuncB | EId::FeFuncA = tag_name {
                 AttributeValue::Number(svgtypes::Number::from_str(value).ok()?.0)
+            } else if tag_name == EId::Hatchpath {
+                AttributeValue::Length(svgtypes::Length::from_str(value).ok()?)
             } else {
                 // offset = <number> | <percentage>
                 let l = svgtypes::Length::from_str(value).ok()?;
@@ -422,6 +769,16 @@ fn parse_svg_attribute(
         }
 
         AId::Fill => {
+            match value {
+                "context-fill" => return Some(AttributeValue::ContextFill),
+                "context-stroke" => return Some(AttributeValue::ContextStroke),
+                _ => {}
+            }
+
+            if let Some(color) = super::color::parse_color_function(value) {
+                return Some(AttributeValue::Color(color));
+            }
+
             match svgtypes::Paint::from_str(value) {
                 Ok(svgtypes::Paint::None) => AttributeValue::None,
                 Ok(svgtypes::Paint::Inherit) => unreachable!(),
@@ -438,6 +795,16 @@ fn parse_svg_attribute(
         }
 
         AId::Stroke => {
+            match value {
+                "context-fill" => return Some(AttributeValue::ContextFill),
+                "context-stroke" => return Some(AttributeValue::ContextStroke),
+                _ => {}
+            }
+
+            if let Some(color) = super::color::parse_color_function(value) {
+                return Some(AttributeValue::Color(color));
+            }
+
             match svgtypes::Paint::from_str(value).ok()? {
                 svgtypes::Paint::None => AttributeValue::None,
                 svgtypes::Paint::Inherit => unreachable!(),
@@ -449,6 +816,15 @@ fn parse_svg_attribute(
             }
         }
 
+        AId::ClipPath if value.starts_with("path(") => {
+            let segments = parse_path(parse_css_path_func(value)?);
+            if segments.len() >= 2 {
+                AttributeValue::Path(Rc::new(segments))
+            } else {
+                return None;
+            }
+        }
+
           AId::ClipPath
         | AId::MarkerEnd
         | AId::MarkerMid
@@ -464,15 +840,16 @@ fn parse_svg_attribute(
         }
 
         AId::Color => {
-            AttributeValue::Color(svgtypes::Color::from_str(value).ok()?)
+            AttributeValue::Color(super::color::parse_color(value)?)
         }
 
-          AId::FloodColor
+          AId::BackgroundColor
+        | AId::FloodColor
         | AId::LightingColor
         | AId::StopColor => {
             match value {
                 "currentColor" => AttributeValue::CurrentColor,
-                _ => AttributeValue::Color(svgtypes::Color::from_str(value).ok()?),
+                _ => AttributeValue::Color(super::color::parse_color(value)?),
             }
         }
 
@@ -488,7 +865,7 @@ fn parse_svg_attribute(
           AId::Transform
         | AId::GradientTransform
         | AId::PatternTransform => {
-            AttributeValue::Transform(svgtypes::Transform::from_str(value).ok()?.into())
+            AttributeValue::Transform(parse_transform(value)?)
         }
 
         AId::FontSize => {
@@ -571,6 +948,62 @@ fn parse_svg_attribute(
     })
 }
 
+// Extracts path data from the CSS `path()` function.
+//
+// Like `path('M 10 20 L 30 40')`.
+// The optional `fill-rule` argument is not supported.
+fn parse_css_path_func(value: &str) -> Option<&str> {
+    let value = value.strip_prefix("path(")?.strip_suffix(')')?.trim();
+    let quote = value.chars().next()?;
+    if quote != '\'' && quote != '"' {
+        log::warn!("Unsupported 'path()' function: '{}'.", value);
+        return None;
+    }
+
+    value[1..].strip_suffix(quote)
+}
+
+// Parses a transform list.
+//
+// Unlike `svgtypes::Transform::from_str`, keeps the transforms before the first error,
+// so `translate(10 20) rotate(45` would be resolved to `translate(10 20)`.
+fn parse_transform(text: &str) -> Option<crate::Transform> {
+    let mut ts = crate::Transform::default();
+    let mut is_empty = true;
+    for token in svgtypes::TransformListParser::from(text) {
+        let token = match token {
+            Ok(v) => v,
+            Err(e) => {
+                log::warn!("Malformed transform '{}': {}. Only the valid part will be used.", text, e);
+                break;
+            }
+        };
+
+        match token {
+            svgtypes::TransformListToken::Matrix { a, b, c, d, e, f } => {
+                ts.append(&crate::Transform::new(a, b, c, d, e, f));
+            }
+            svgtypes::TransformListToken::Translate { tx, ty } => ts.translate(tx, ty),
+            svgtypes::TransformListToken::Scale { sx, sy } => ts.scale(sx, sy),
+            svgtypes::TransformListToken::Rotate { angle } => ts.rotate(angle),
+            svgtypes::TransformListToken::SkewX { angle } => {
+                ts.append(&crate::Transform::new(1.0, 0.0, angle.to_radians().tan(), 1.0, 0.0, 0.0));
+            }
+            svgtypes::TransformListToken::SkewY { angle } => {
+                ts.append(&crate::Transform::new(1.0, angle.to_radians().tan(), 0.0, 1.0, 0.0, 0.0));
+            }
+        }
+
+        is_empty = false;
+    }
+
+    if is_empty {
+        None
+    } else {
+        Some(ts)
+    }
+}
+
 #[inline(never)]
 fn parse_path(text: &str) -> crate::PathData {
     // Previous MoveTo coordinates.
@@ -811,7 +1244,9 @@ fn resolve_inherit(
 
     // Fallback to a default value if possible.
     let value = match aid {
-          AId::ImageRendering
+          AId::AlignmentBaseline
+        | AId::DominantBaseline
+        | AId::ImageRendering
         | AId::ShapeRendering
         | AId::TextRendering => "auto",
 
@@ -828,6 +1263,7 @@ fn resolve_inherit(
           AId::FontStretch
         | AId::FontStyle
         | AId::FontVariant
+        | AId::FontVariantNumeric
         | AId::FontWeight
         | AId::LetterSpacing
         | AId::WordSpacing => "normal",
@@ -851,6 +1287,8 @@ fn resolve_inherit(
         AId::Display =>                     "inline",
         AId::FontSize =>                    "medium",
         AId::Overflow =>                    "visible",
+        AId::PaintOrder =>                  "normal",
+        AId::StrokeAlignment =>             "center",
         AId::StrokeDashoffset =>            "0",
         AId::StrokeLinecap =>               "butt",
         AId::StrokeLinejoin =>              "miter",
@@ -868,6 +1306,7 @@ fn resolve_inherit(
 
 fn resolve_href<'a>(
     node: roxmltree::Node<'a, 'a>,
+    id_handling: DuplicateIdHandling,
 ) -> Option<roxmltree::Node<'a, 'a>> {
     let link_value = node.attribute((XLINK_NS, "href"))
         .or_else(|| node.attribute("href"))?;
@@ -881,7 +1320,20 @@ fn resolve_href<'a>(
     // Technically we can use https://crates.io/crates/hashlink,
     // but this is an additional dependency.
     // And performance even on huge files is still good enough.
-    node.document().descendants().find(|n| n.attribute("id") == Some(link_id))
+    find_element_by_id(node.document(), link_id, id_handling)
+}
+
+// Duplicated IDs are reported later, during the links collection.
+pub(super) fn find_element_by_id<'a>(
+    xml: &'a roxmltree::Document<'a>,
+    id: &str,
+    id_handling: DuplicateIdHandling,
+) -> Option<roxmltree::Node<'a, 'a>> {
+    let mut iter = xml.descendants().filter(|n| n.attribute("id") == Some(id));
+    match id_handling {
+        DuplicateIdHandling::UseLast => iter.last(),
+        DuplicateIdHandling::UseFirst | DuplicateIdHandling::Error => iter.next(),
+    }
 }
 
 fn parse_svg_use_element(
@@ -892,7 +1344,7 @@ fn parse_svg_use_element(
     depth: u32,
     doc: &mut Document,
 ) -> Result<(), Error> {
-    let link = match resolve_href(node) {
+    let link = match resolve_href(node, doc.id_handling) {
         Some(v) => v,
         None => return Ok(()),
     };
@@ -927,7 +1379,7 @@ fn parse_svg_use_element(
     // `use1` should be removed.
     let mut is_recursive = false;
     for link_child in link.descendants().skip(1).filter(|n| n.has_tag_name((SVG_NS, "use"))) {
-        if let Some(link2) = resolve_href(link_child) {
+        if let Some(link2) = resolve_href(link_child, doc.id_handling) {
             if link2 == node || link2 == link {
                 is_recursive = true;
                 break;
@@ -1012,7 +1464,8 @@ fn find_recursive_pattern(
     aid: AId,
     doc: &mut Document,
 ) -> Option<NodeId> {
-    for pattern_node in doc.root().descendants().filter(|n| n.has_tag_name(EId::Pattern)) {
+    let is_pattern = |n: &Node| n.has_tag_name(EId::Pattern) || n.has_tag_name(EId::Hatch);
+    for pattern_node in doc.root().descendants().filter(is_pattern) {
         for node in pattern_node.descendants() {
             if let Some(&AttributeValue::Paint(ref link_id, _)) = node.attribute(aid) {
                 if link_id == pattern_node.element_id() {
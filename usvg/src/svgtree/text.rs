@@ -1,5 +1,5 @@
 use super::{Document, Node, NodeId, EId, AId, NodeKind};
-use crate::Error;
+use crate::{DuplicateIdHandling, Error};
 
 const XLINK_NS: &str = "http://www.w3.org/1999/xlink";
 
@@ -60,6 +60,12 @@ fn parse_svg_text_element_impl(
             continue;
         }
 
+        if !super::parse::is_supported_element(doc.profile, tag_name) {
+            log::warn!("'{}' is not supported by {}. Skipped.",
+                       tag_name, super::parse::profile_name(doc.profile));
+            continue;
+        }
+
         // We are converting `tref` into `tspan` to simplify later use.
         let mut is_tref = false;
         if tag_name == EId::Tref {
@@ -75,7 +81,7 @@ fn parse_svg_text_element_impl(
                 .or_else(|| node.attribute("href"));
 
             if let Some(href) = link_value {
-                if let Some(text) = resolve_tref_text(node.document(), href) {
+                if let Some(text) = resolve_tref_text(node.document(), href, doc.id_handling) {
                     let text = trim_text(&text, space);
                     doc.append(node_id, NodeKind::Text(text));
                 }
@@ -91,11 +97,12 @@ fn parse_svg_text_element_impl(
 fn resolve_tref_text(
     xml: &roxmltree::Document,
     href: &str,
+    id_handling: DuplicateIdHandling,
 ) -> Option<String> {
     let id = svgtypes::IRI::from_str(href).ok()?.0;
 
     // Find linked element in the original tree.
-    let node = xml.descendants().find(|n| n.attribute("id") == Some(id))?;
+    let node = super::parse::find_element_by_id(xml, id, id_handling)?;
 
     // `tref` should be linked to an SVG element.
     super::parse::parse_tag_name(node)?;
@@ -9,7 +9,7 @@ use strict_num::NonZeroPositiveF64;
 
 use crate::svgtree::{self, AId, EId};
 use crate::{OptionLog, ShapeRendering, TextRendering, Visibility, converter, style, units};
-use crate::{SharedPathData, Transform, Tree, Units};
+use crate::{SharedPathData, Tree, Units};
 use super::TextNode;
 use super::fontdb_ext::{self, DatabaseExt};
 
@@ -120,7 +120,8 @@ pub struct TextSpan {
     pub stroke: Option<style::Stroke>,
     pub font: super::fontdb_ext::Font,
     pub font_size: NonZeroPositiveF64,
-    pub small_caps: bool,
+    pub font_features: Vec<rustybuzz::Feature>,
+    pub text_orientation: TextOrientation,
     pub decoration: TextDecoration,
     pub baseline_shift: f64,
     pub visibility: Visibility,
@@ -142,6 +143,17 @@ pub enum WritingMode {
 }
 
 
+/// Glyphs orientation in the vertical writing mode.
+///
+/// [In the CSS spec](https://www.w3.org/TR/css-writing-modes-3/#text-orientation).
+#[derive(Clone, Copy, PartialEq)]
+pub enum TextOrientation {
+    Mixed,
+    Upright,
+    Sideways,
+}
+
+
 struct IterState {
     chars_count: usize,
     chunk_bytes_count: usize,
@@ -250,10 +262,12 @@ fn collect_text_chunks_impl(
             stroke: style::resolve_stroke(parent, true, state, id_generator, tree),
             font,
             font_size,
-            small_caps: parent.find_attribute(AId::FontVariant) == Some("small-caps"),
+            font_features: resolve_font_features(parent),
+            text_orientation: resolve_text_orientation(parent),
             decoration: resolve_decoration(text_node, parent, state, id_generator, tree),
             visibility: parent.find_attribute(AId::Visibility).unwrap_or_default(),
-            baseline_shift: resolve_baseline_shift(parent, state),
+            baseline_shift: resolve_baseline_shift(parent, state)
+                - resolve_alignment_baseline(text_node, parent, font, font_size.get()),
             letter_spacing: parent.resolve_length(AId::LetterSpacing, state, 0.0),
             word_spacing: parent.resolve_length(AId::WordSpacing, state, 0.0),
         };
@@ -336,7 +350,8 @@ fn resolve_text_flow(
     };
 
     // The reference path's transform needs to be applied
-    let path = if let Some(node_transform) = linked_node.attribute::<Transform>(AId::Transform) {
+    let node_transform = linked_node.resolve_transform(state);
+    let path = if !node_transform.is_default() {
         let mut path_copy = path.as_ref().clone();
         path_copy.transform(node_transform);
         Rc::new(path_copy)
@@ -718,11 +733,45 @@ fn resolve_baseline_shift(
     shift
 }
 
-fn resolve_font_weight(node: svgtree::Node) -> fontdb::Weight {
-    fn bound(min: usize, val: usize, max: usize) -> usize {
-        std::cmp::max(min, std::cmp::min(max, val))
+/// Returns an offset of the alignment baseline relative to the alphabetic one.
+///
+/// `alignment-baseline` has a priority over `dominant-baseline`.
+///
+/// Fonts rarely have the `BASE` table, so, just like Chrome, we are synthesizing
+/// all baselines from the font metrics.
+/// See https://www.w3.org/TR/css-inline-3/#baseline-synthesis-fonts
+fn resolve_alignment_baseline(
+    text_node: TextNode,
+    node: svgtree::Node,
+    font: fontdb_ext::Font,
+    font_size: f64,
+) -> f64 {
+    // TODO: vertical text is always centered for now
+    if convert_writing_mode(text_node) == WritingMode::TopToBottom {
+        return 0.0;
+    }
+
+    let mut baseline = node.attribute(AId::AlignmentBaseline).unwrap_or("auto");
+    if baseline == "auto" || baseline == "baseline" {
+        // `use-script`, `no-change` and `reset-size` are not supported
+        // and will fallback to the alphabetic baseline.
+        baseline = node.find_attribute(AId::DominantBaseline).unwrap_or("auto");
     }
 
+    match baseline {
+        "before-edge" | "text-before-edge" | "text-top" | "top" => font.ascent(font_size),
+        "after-edge" | "text-after-edge" | "text-bottom" | "bottom" | "ideographic" => {
+            font.descent(font_size)
+        }
+        "middle" => font.x_height(font_size) * 0.5,
+        "central" | "center" => font.ascent(font_size) - font.height(font_size) * 0.5,
+        "hanging" => font.ascent(font_size) * 0.8,
+        "mathematical" => font.ascent(font_size) * 0.5,
+        _ => 0.0,
+    }
+}
+
+fn resolve_font_weight(node: svgtree::Node) -> fontdb::Weight {
     let nodes: Vec<_> = node.ancestors().collect();
     let mut weight = 400;
     for n in nodes.iter().rev().skip(1) { // skip Root
@@ -738,31 +787,77 @@ fn resolve_font_weight(node: svgtree::Node) -> fontdb::Weight {
             "700" => 700,
             "800" => 800,
             "900" => 900,
+            // Relative weights are resolved using the inherited weight.
+            // See https://www.w3.org/TR/css-fonts-4/#relative-weights
             "bolder" => {
-                // By the CSS2 spec the default value should be 400
-                // so `bolder` will result in 500.
-                // But Chrome and Inkscape will give us 700.
-                // Have no idea is it a bug or something, but
-                // we will follow such behavior for now.
-                let step = if weight == 400 { 300 } else { 100 };
-
-                bound(100, weight + step, 900)
+                match weight {
+                    0..=349 => 400,
+                    350..=549 => 700,
+                    550..=899 => 900,
+                    _ => weight,
+                }
             }
             "lighter" => {
-                // By the CSS2 spec the default value should be 400
-                // so `lighter` will result in 300.
-                // But Chrome and Inkscape will give us 200.
-                // Have no idea is it a bug or something, but
-                // we will follow such behavior for now.
-                let step = if weight == 400 { 200 } else { 100 };
-
-                bound(100, weight - step, 900)
+                match weight {
+                    0..=99 => weight,
+                    100..=549 => 100,
+                    550..=749 => 400,
+                    _ => 700,
+                }
             }
             _ => weight,
         };
     }
 
-    fontdb::Weight(weight as u16)
+    fontdb::Weight(weight)
+}
+
+fn resolve_font_features(node: svgtree::Node) -> Vec<rustybuzz::Feature> {
+    let mut tags: Vec<&[u8; 4]> = Vec::new();
+
+    if node.find_attribute(AId::FontVariant) == Some("small-caps") {
+        tags.push(b"smcp");
+    }
+
+    // See https://www.w3.org/TR/css-fonts-4/#font-variant-numeric-prop
+    let numeric = node.find_attribute(AId::FontVariantNumeric).unwrap_or("normal");
+    let mut numeric_tags = Vec::new();
+    for name in numeric.split_whitespace() {
+        let tag: &[u8; 4] = match name {
+            "normal" => continue,
+            "lining-nums" => b"lnum",
+            "oldstyle-nums" => b"onum",
+            "proportional-nums" => b"pnum",
+            "tabular-nums" => b"tnum",
+            "diagonal-fractions" => b"frac",
+            "stacked-fractions" => b"afrc",
+            "ordinal" => b"ordn",
+            "slashed-zero" => b"zero",
+            _ => {
+                log::warn!("Invalid font-variant-numeric value: '{}'.", numeric);
+                numeric_tags.clear();
+                break;
+            }
+        };
+
+        numeric_tags.push(tag);
+    }
+    tags.extend(numeric_tags);
+
+    tags.into_iter()
+        .map(|tag| rustybuzz::Feature::new(rustybuzz::Tag::from_bytes(tag), 1, ..))
+        .collect()
+}
+
+fn resolve_text_orientation(node: svgtree::Node) -> TextOrientation {
+    // SVG 2 defines the deprecated `glyph-orientation-vertical` as an alias
+    // to the `text-orientation` property.
+    // See https://www.w3.org/TR/css-writing-modes-3/#glyph-orientation
+    match node.find_attribute(AId::GlyphOrientationVertical) {
+        Some("0") | Some("0deg") => TextOrientation::Upright,
+        Some("90") | Some("90deg") => TextOrientation::Sideways,
+        _ => TextOrientation::Mixed,
+    }
 }
 
 fn count_chars(node: svgtree::Node) -> usize {
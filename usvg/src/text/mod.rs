@@ -8,8 +8,8 @@ mod convert;
 mod shaper;
 mod fontdb_ext;
 
-use crate::{FillRule, Group, Node, NodeExt, NodeKind, Paint, Path, PathData, PathSegment, Rect, Color};
-use crate::{ShapeRendering, Stroke, StrokeWidth, Transform, TransformFromBBox, Tree, Units};
+use crate::{FillRule, GlyphPosition, Group, Node, NodeExt, NodeKind, Paint, Path, PathData, PathSegment, Rect, Color};
+use crate::{PaintOrder, ShapeRendering, Stroke, StrokeWidth, Transform, TransformFromBBox, Tree, Units};
 use crate::PathBbox;
 use crate::{converter, svgtree};
 use convert::{TextFlow, WritingMode, TextSpan};
@@ -80,9 +80,13 @@ pub(crate) fn convert(
     };
 
     let rendering_mode = convert::resolve_rendering_mode(text_node, state);
+    let paint_order = converter::convert_paint_order(
+        text_node.find_attribute(svgtree::AId::PaintOrder).unwrap_or_default()
+    );
     for mut path in new_paths {
         fix_obj_bounding_box(&mut path, bbox, tree);
         path.rendering_mode = rendering_mode;
+        path.paint_order = paint_order;
         parent.append_kind(NodeKind::Path(path));
     }
 }
@@ -96,7 +100,7 @@ fn text_to_paths(
 ) -> (Vec<Path>, PathBbox) {
     let abs_ts = {
         let mut ts = parent.abs_transform();
-        ts.append(&text_node.attribute(svgtree::AId::Transform).unwrap_or_default());
+        ts.append(&text_node.resolve_transform(state));
         ts
     };
 
@@ -122,7 +126,7 @@ fn text_to_paths(
             continue;
         }
 
-        shaper::apply_writing_mode(writing_mode, &mut clusters);
+        shaper::apply_writing_mode(writing_mode, chunk, &mut clusters);
         shaper::apply_letter_spacing(chunk, &mut clusters);
         shaper::apply_word_spacing(chunk, &mut clusters);
         let mut curr_pos = shaper::resolve_clusters_positions(
@@ -237,6 +241,7 @@ fn convert_span(
 ) -> Option<Path> {
     let mut path_data = PathData::new();
     let mut bboxes_data = PathData::new();
+    let mut glyph_positions = Vec::new();
 
     for cluster in clusters {
         if !cluster.visible {
@@ -261,6 +266,19 @@ fn convert_span(
                     bboxes_data.push_rect(r);
                 }
             }
+
+            let mut ts = *text_ts;
+            ts.append(&cluster.transform);
+            let (x, y) = ts.apply(0.0, -cluster.ascent);
+            let (sx, sy) = ts.get_scale();
+            glyph_positions.push(GlyphPosition {
+                unicode_codepoint: cluster.codepoint,
+                x,
+                y,
+                width: cluster.width * sx,
+                height: cluster.height() * sy,
+                advance: cluster.advance * sx,
+            });
         }
     }
 
@@ -287,8 +305,10 @@ fn convert_span(
         visibility: span.visibility,
         fill,
         stroke: span.stroke.take(),
+        paint_order: PaintOrder::default(),
         rendering_mode: ShapeRendering::default(),
         text_bbox: bboxes_data.bbox().and_then(|r| r.to_rect()),
+        glyph_positions,
         data: Rc::new(path_data),
     };
 
@@ -470,6 +490,11 @@ fn paint_server_to_user_space_on_use(
                 patt.transform.prepend(&ts);
                 patt.units = Units::UserSpaceOnUse;
             }
+            NodeKind::Hatch(ref mut hatch) => {
+                hatch.id = new_id.clone();
+                hatch.transform.prepend(&ts);
+                hatch.units = Units::UserSpaceOnUse;
+            }
             _ => {}
         }
 
@@ -8,7 +8,8 @@ use unicode_script::UnicodeScript;
 use ttf_parser::GlyphId;
 
 use crate::{CubicBezExt, FuzzyZero, IsValidLength, PathData, PathSegment, Transform, converter};
-use super::convert::{ByteIndex, CharacterPosition, TextAnchor, TextChunk, TextFlow, TextPath, WritingMode};
+use super::convert::{ByteIndex, CharacterPosition, TextAnchor, TextChunk, TextFlow, TextOrientation, TextPath,
+                     WritingMode};
 use super::fontdb_ext::{self, DatabaseExt};
 
 
@@ -159,7 +160,7 @@ pub fn outline_chunk(
 ) -> Vec<OutlinedCluster> {
     let mut glyphs = Vec::new();
     for span in &chunk.spans {
-        let tmp_glyphs = shape_text(&chunk.text, span.font, span.small_caps, state);
+        let tmp_glyphs = shape_text(&chunk.text, span.font, &span.font_features, state);
 
         // Do nothing with the first run.
         if glyphs.is_empty() {
@@ -198,10 +199,10 @@ pub fn outline_chunk(
 fn shape_text(
     text: &str,
     font: fontdb_ext::Font,
-    small_caps: bool,
+    features: &[rustybuzz::Feature],
     state: &converter::State,
 ) -> Vec<Glyph> {
-    let mut glyphs = shape_text_with_font(text, font, small_caps, state).unwrap_or_default();
+    let mut glyphs = shape_text_with_font(text, font, features, state).unwrap_or_default();
 
     // Remember all fonts used for shaping.
     let mut used_fonts = vec![font.id];
@@ -223,7 +224,7 @@ fn shape_text(
             };
 
             // Shape again, using a new font.
-            let fallback_glyphs = shape_text_with_font(text, fallback_font, small_caps, state)
+            let fallback_glyphs = shape_text_with_font(text, fallback_font, features, state)
                 .unwrap_or_default();
 
             let all_matched = fallback_glyphs.iter().all(|g| !g.is_missing());
@@ -273,7 +274,7 @@ fn shape_text(
 fn shape_text_with_font(
     text: &str,
     font: fontdb_ext::Font,
-    small_caps: bool,
+    features: &[rustybuzz::Feature],
     state: &converter::State,
 ) -> Option<Vec<Glyph>> {
     state.opt.fontdb.with_face_data(font.id, |font_data, face_index| -> Option<Vec<Glyph>> {
@@ -302,12 +303,7 @@ fn shape_text_with_font(
             buffer.push_str(sub_text);
             buffer.set_direction(hb_direction);
 
-            let mut features = Vec::new();
-            if small_caps {
-                features.push(rustybuzz::Feature::new(rustybuzz::Tag::from_bytes(b"smcp"), 1, ..));
-            }
-
-            let output = rustybuzz::shape(&rb_font, &features, buffer);
+            let output = rustybuzz::shape(&rb_font, features, buffer);
 
             let positions = output.glyph_positions();
             let infos = output.glyph_infos();
@@ -819,9 +815,11 @@ fn is_word_separator_characters(c: char) -> bool {
 }
 
 /// Rotates clusters according to
-/// [Unicode Vertical_Orientation Property](https://www.unicode.org/reports/tr50/tr50-19.html).
+/// [Unicode Vertical_Orientation Property](https://www.unicode.org/reports/tr50/tr50-19.html)
+/// and the `text-orientation` property.
 pub fn apply_writing_mode(
     writing_mode: WritingMode,
+    chunk: &TextChunk,
     clusters: &mut [OutlinedCluster],
 ) {
     if writing_mode != WritingMode::TopToBottom {
@@ -829,8 +827,28 @@ pub fn apply_writing_mode(
     }
 
     for cluster in clusters {
-        let orientation = unicode_vo::char_orientation(cluster.codepoint);
-        if orientation == CharOrientation::Upright {
+        let span = match chunk.span_at(cluster.byte_idx) {
+            Some(v) => v,
+            None => continue,
+        };
+
+        let is_upright_char = unicode_vo::char_orientation(cluster.codepoint) == CharOrientation::Upright;
+        let is_upright = match span.text_orientation {
+            TextOrientation::Mixed => is_upright_char,
+            TextOrientation::Upright => true,
+            TextOrientation::Sideways => false,
+        };
+
+        if is_upright {
+            if !is_upright_char {
+                // Horizontal-only glyphs, like Latin, do not have a vertical advance,
+                // so we are using 1em, just like for CJK, and center glyphs inside it.
+                let font_size = span.font_size.get();
+                cluster.path.transform(Transform::new_translate((font_size - cluster.width) / 2.0, 0.0));
+                cluster.advance += font_size - cluster.width;
+                cluster.width = font_size;
+            }
+
             // Additional offset. Not sure why.
             let dy = cluster.width - cluster.height();
 
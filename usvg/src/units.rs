@@ -33,10 +33,12 @@ pub(crate) fn convert_length(
                 let view_box = state.view_box;
 
                 match aid {
-                    AId::X | AId::Cx | AId::Width  => {
+                      AId::X | AId::Cx | AId::Dx | AId::Fx
+                    | AId::X1 | AId::X2 | AId::Rx | AId::Width => {
                         convert_percent(length, view_box.width())
                     }
-                    AId::Y | AId::Cy | AId::Height => {
+                      AId::Y | AId::Cy | AId::Dy | AId::Fy
+                    | AId::Y1 | AId::Y2 | AId::Ry | AId::Height => {
                         convert_percent(length, view_box.height())
                     }
                     _ => {
@@ -73,6 +75,94 @@ pub(crate) fn convert_list(
     }
 }
 
+/// Converts a `transform-origin` value into user space coordinates.
+///
+/// Percentages are resolved relative to the current viewport,
+/// like `transform-box: view-box` does. The z offset is ignored.
+#[inline(never)]
+pub(crate) fn convert_transform_origin(
+    text: &str,
+    node: svgtree::Node,
+    state: &converter::State,
+) -> Option<(f64, f64)> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum Token {
+        Left,
+        Right,
+        Top,
+        Bottom,
+        Center,
+        Length(Length),
+    }
+
+    let mut tokens = Vec::new();
+    for s in text.split_ascii_whitespace() {
+        let token = match s {
+            "left" => Token::Left,
+            "right" => Token::Right,
+            "top" => Token::Top,
+            "bottom" => Token::Bottom,
+            "center" => Token::Center,
+            _ => match s.parse() {
+                Ok(length) => Token::Length(length),
+                Err(_) => {
+                    log::warn!("Invalid 'transform-origin' value: '{}'.", text);
+                    return None;
+                }
+            },
+        };
+
+        tokens.push(token);
+    }
+
+    let (x, y) = match *tokens.as_slice() {
+        [t @ Token::Top] | [t @ Token::Bottom] => (Token::Center, t),
+        [t] => (t, Token::Center),
+        // Two keywords can be set in any order, like `top left`.
+        [x, y] | [x, y, Token::Length(_)] => {
+            let is_swapped = matches!(x, Token::Top | Token::Bottom)
+                || matches!(y, Token::Left | Token::Right);
+            let has_length = matches!(x, Token::Length(_)) || matches!(y, Token::Length(_));
+            if is_swapped && !has_length {
+                (y, x)
+            } else {
+                (x, y)
+            }
+        }
+        _ => {
+            log::warn!("Invalid 'transform-origin' value: '{}'.", text);
+            return None;
+        }
+    };
+
+    let x = match x {
+        Token::Left => Length::new(0.0, Unit::Percent),
+        Token::Center => Length::new(50.0, Unit::Percent),
+        Token::Right => Length::new(100.0, Unit::Percent),
+        Token::Length(length) => length,
+        _ => {
+            log::warn!("Invalid 'transform-origin' value: '{}'.", text);
+            return None;
+        }
+    };
+
+    let y = match y {
+        Token::Top => Length::new(0.0, Unit::Percent),
+        Token::Center => Length::new(50.0, Unit::Percent),
+        Token::Bottom => Length::new(100.0, Unit::Percent),
+        Token::Length(length) => length,
+        _ => {
+            log::warn!("Invalid 'transform-origin' value: '{}'.", text);
+            return None;
+        }
+    };
+
+    Some((
+        convert_length(x, node, AId::X, Units::UserSpaceOnUse, state),
+        convert_length(y, node, AId::Y, Units::UserSpaceOnUse, state),
+    ))
+}
+
 fn convert_percent(length: Length, base: f64) -> f64 {
     base * length.number / 100.0
 }
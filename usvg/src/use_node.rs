@@ -27,8 +27,20 @@ pub(crate) fn convert(
         return None;
     }
 
+    // A `use` element is a context element for its content.
+    // Paint resolving can create new paint servers, so we're doing it
+    // only when the content actually references the context element.
+    let mut use_state = state.clone();
+    if uses_context_paint(node) {
+        use_state.context_element = Some((
+            style::resolve_fill(node, true, state, id_generator, tree),
+            style::resolve_stroke(node, true, state, id_generator, tree),
+        ));
+    }
+    let state = &use_state;
+
     // We require an original transformation to setup 'clipPath'.
-    let mut orig_ts: Transform = node.attribute(AId::Transform).unwrap_or_default();
+    let mut orig_ts = node.resolve_transform(state);
     let mut new_ts = Transform::default();
 
     {
@@ -102,10 +114,10 @@ pub(crate) fn convert(
             state.use_size = (None, None);
 
             // Width and height can be set independently.
-            if node.has_attribute(AId::Width) {
+            if node.attribute::<Length>(AId::Width).is_some() {
                 state.use_size.0 = Some(node.convert_user_length(AId::Width, &state, def));
             }
-            if node.has_attribute(AId::Height) {
+            if node.attribute::<Length>(AId::Height).is_some() {
                 state.use_size.1 = Some(node.convert_user_length(AId::Height, &state, def));
             }
 
@@ -118,6 +130,13 @@ pub(crate) fn convert(
     Some(())
 }
 
+// Checks that `use` or its content has `context-fill` or `context-stroke`.
+fn uses_context_paint(node: svgtree::Node) -> bool {
+    node.descendants().any(|n| n.attributes().iter().any(|a| matches!(a.value,
+        svgtree::AttributeValue::ContextFill | svgtree::AttributeValue::ContextStroke
+    )))
+}
+
 pub(crate) fn convert_svg(
     node: svgtree::Node,
     state: &converter::State,
@@ -126,7 +145,7 @@ pub(crate) fn convert_svg(
     tree: &mut Tree,
 ) {
     // We require original transformation to setup 'clipPath'.
-    let mut orig_ts: Transform = node.attribute(AId::Transform).unwrap_or_default();
+    let mut orig_ts = node.resolve_transform(state);
     let mut new_ts = Transform::default();
 
     {
@@ -149,7 +168,7 @@ pub(crate) fn convert_svg(
             // No `viewBox` attribute? Then use `x`, `y`, `width` and `height` instead.
             let x = node.convert_user_length(AId::X, &state, Length::zero());
             let y = node.convert_user_length(AId::Y, &state, Length::zero());
-            let (mut w, mut h) = use_node_size(node, &state);
+            let (mut w, mut h) = use_node_size(node, node, &state);
 
             // If attributes `width` and/or `height` are provided on the `use` element,
             // then these values will override the corresponding attributes
@@ -255,6 +274,11 @@ fn get_clip_rect(
     state: &converter::State,
 ) -> Option<Rect> {
     // No need to clip elements with overflow:visible.
+    //
+    // `auto` means that all content must be visible, either via scrolling or without clipping.
+    // We cannot scroll, so it's the same as `visible`.
+    // `scroll` means that content is clipped and a scroll bar is shown,
+    // so for us it's the same as `hidden`.
     if matches!(symbol_node.attribute(AId::Overflow), Some("visible") | Some("auto")) {
         return None;
     }
@@ -262,7 +286,7 @@ fn get_clip_rect(
     let (x, y, mut w, mut h) = {
         let x = use_node.convert_user_length(AId::X, state, Length::zero());
         let y = use_node.convert_user_length(AId::Y, state, Length::zero());
-        let (w, h) = use_node_size(use_node, state);
+        let (w, h) = use_node_size(use_node, symbol_node, state);
         (x, y, w, h)
     };
 
@@ -287,10 +311,25 @@ fn get_clip_rect(
     Rect::new(x, y, w, h)
 }
 
-fn use_node_size(node: svgtree::Node, state: &converter::State) -> (f64, f64) {
+fn use_node_size(
+    node: svgtree::Node,
+    linked: svgtree::Node,
+    state: &converter::State,
+) -> (f64, f64) {
+    // In SVG 2, `symbol` can have its own `width` and `height`.
+    // `use` overrides them, but each one independently.
+    // `auto` is the same as not set.
+    let size_node = |aid| {
+        if linked.has_tag_name(EId::Symbol) && node.attribute::<Length>(aid).is_none() {
+            linked
+        } else {
+            node
+        }
+    };
+
     let def = Length::new(100.0, LengthUnit::Percent);
-    let w = node.convert_user_length(AId::Width, state, def);
-    let h = node.convert_user_length(AId::Height, state, def);
+    let w = size_node(AId::Width).convert_user_length(AId::Width, state, def);
+    let h = size_node(AId::Height).convert_user_length(AId::Height, state, def);
     (w, h)
 }
 
@@ -299,7 +338,7 @@ fn viewbox_transform(
     linked: svgtree::Node,
     state: &converter::State,
 ) -> Option<Transform> {
-    let (mut w, mut h) = use_node_size(node, state);
+    let (mut w, mut h) = use_node_size(node, linked, state);
 
     if node.tag_name() == Some(EId::Svg) {
         // If attributes `width` and/or `height` are provided on the `use` element,
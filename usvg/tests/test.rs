@@ -142,6 +142,27 @@ fn viewbox_detection() {
     assert!(tree.svg_node().view_box.rect.fuzzy_eq(&usvg::Rect::new(0.0, 0.0, 36.0, 36.0).unwrap()));
 }
 
+#[test]
+fn stroke_alignment_bbox() {
+    use usvg::{FuzzyEq, NodeExt};
+
+    let bbox = |alignment: &str| {
+        let input = format!(
+            "<svg xmlns='http://www.w3.org/2000/svg' width='100' height='100'>\
+                <rect x='20' y='20' width='40' height='40' stroke='black' stroke-width='10' \
+                      stroke-alignment='{}'/>\
+            </svg>", alignment
+        );
+        let tree = usvg::Tree::from_str(&input, &usvg::Options::default().to_ref()).unwrap();
+        let node = tree.root().descendants().find(|n| matches!(*n.borrow(), usvg::NodeKind::Path(_)));
+        node.unwrap().calculate_bbox().unwrap().to_rect().unwrap()
+    };
+
+    assert!(bbox("center").fuzzy_eq(&usvg::Rect::new(15.0, 15.0, 50.0, 50.0).unwrap()));
+    assert!(bbox("inner").fuzzy_eq(&usvg::Rect::new(20.0, 20.0, 40.0, 40.0).unwrap()));
+    assert!(bbox("outer").fuzzy_eq(&usvg::Rect::new(10.0, 10.0, 60.0, 60.0).unwrap()));
+}
+
 macro_rules! test_size_err {
     ($name:ident, $input:expr) => {
         #[test]
@@ -154,3 +175,906 @@ macro_rules! test_size_err {
 
 test_size_err!(size_detection_err,
     "<svg width='0' height='0' viewBox='0 0 10 20' xmlns='http://www.w3.org/2000/svg'>");
+
+#[test]
+fn deep_recursive_href() {
+    // A long chain of cross-referencing elements that ends with a loop.
+    let mut svg = String::from("<svg xmlns='http://www.w3.org/2000/svg' \
+                                     xmlns:xlink='http://www.w3.org/1999/xlink'>");
+    for i in 0..1000 {
+        svg.push_str(&format!("<linearGradient id='lg{}' xlink:href='#lg{}'/>", i, i + 1));
+        svg.push_str(&format!("<pattern id='patt{}' xlink:href='#patt{}'/>", i, i + 1));
+        svg.push_str(&format!("<filter id='filter{}' xlink:href='#filter{}'/>", i, i + 1));
+    }
+    svg.push_str("<linearGradient id='lg1000' xlink:href='#lg500'/>\
+                  <pattern id='patt1000' xlink:href='#patt500'/>\
+                  <filter id='filter1000' xlink:href='#filter500'/>\
+                  <rect width='10' height='10' fill='url(#lg0)' stroke='url(#patt0)'/>\
+                  <rect width='10' height='10' filter='url(#filter0)'/>\
+                  </svg>");
+
+    let tree = usvg::Tree::from_str(&svg, &usvg::Options::default().to_ref()).unwrap();
+    // Invalid paint servers and filters are resolved to `none`.
+    let painted = tree.root().descendants()
+        .filter(|n| match *n.borrow() {
+            usvg::NodeKind::Path(ref path) => path.fill.is_some() || path.stroke.is_some(),
+            _ => false,
+        })
+        .count();
+    assert_eq!(painted, 0);
+
+    // A shorter chain is still resolved.
+    let mut svg = String::from("<svg xmlns='http://www.w3.org/2000/svg' \
+                                     xmlns:xlink='http://www.w3.org/1999/xlink'>");
+    for i in 0..20 {
+        svg.push_str(&format!("<linearGradient id='lg{}' xlink:href='#lg{}'/>", i, i + 1));
+    }
+    svg.push_str("<linearGradient id='lg20'><stop stop-color='green'/></linearGradient>\
+                  <rect width='10' height='10' fill='url(#lg0)'/>\
+                  </svg>");
+
+    let tree = usvg::Tree::from_str(&svg, &usvg::Options::default().to_ref()).unwrap();
+    let fill = tree.root().descendants().find_map(|n| match *n.borrow() {
+        usvg::NodeKind::Path(ref path) => path.fill.clone(),
+        _ => None,
+    });
+    assert!(matches!(fill, Some(usvg::Fill { paint: usvg::Paint::Color(c), .. }) if c == usvg::Color::new_rgb(0, 128, 0)));
+}
+
+#[test]
+fn pattern_auto_size_inheritance() {
+    use usvg::FuzzyEq;
+
+    let svg = "<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>\
+        <pattern id='patt1' width='10' height='20' patternUnits='userSpaceOnUse'>\
+            <rect width='5' height='5'/>\
+        </pattern>\
+        <pattern id='patt2' xlink:href='#patt1' width='auto' height='auto'/>\
+        <rect width='100' height='100' fill='url(#patt2)'/>\
+    </svg>";
+
+    // `auto` is not valid on `pattern`, so the size is inherited.
+    let tree = usvg::Tree::from_str(svg, &usvg::Options::default().to_ref()).unwrap();
+    let rect = tree.defs().children().find_map(|n| match *n.borrow() {
+        usvg::NodeKind::Pattern(ref patt) => Some(patt.rect),
+        _ => None,
+    });
+    assert!(rect.unwrap().fuzzy_eq(&usvg::Rect::new(0.0, 0.0, 10.0, 20.0).unwrap()));
+}
+
+fn duplicate_id_fills(handling: usvg::DuplicateIdHandling) -> Result<Vec<usvg::Color>, usvg::Error> {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>
+        <linearGradient id='lg'><stop stop-color='red'/></linearGradient>
+        <linearGradient id='lg'><stop stop-color='blue'/></linearGradient>
+        <defs>
+            <rect id='rect' width='10' height='10' fill='red'/>
+            <rect id='rect' width='10' height='10' fill='blue'/>
+        </defs>
+        <rect width='10' height='10' fill='url(#lg)'/>
+        <use xlink:href='#rect'/>
+    </svg>";
+
+    let opt = usvg::Options {
+        duplicate_id_handling: handling,
+        .. usvg::Options::default()
+    };
+    let tree = usvg::Tree::from_str(svg, &opt.to_ref())?;
+    let fills = tree.root().descendants().filter_map(|n| match *n.borrow() {
+        usvg::NodeKind::Path(ref path) => match path.fill.as_ref()?.paint {
+            usvg::Paint::Color(c) => Some(c),
+            _ => None,
+        },
+        _ => None,
+    });
+    Ok(fills.collect())
+}
+
+#[test]
+fn duplicate_id_use_first() {
+    let red = usvg::Color::new_rgb(255, 0, 0);
+    assert_eq!(duplicate_id_fills(usvg::DuplicateIdHandling::UseFirst).unwrap(), vec![red, red]);
+}
+
+#[test]
+fn duplicate_id_use_last() {
+    let blue = usvg::Color::new_rgb(0, 0, 255);
+    assert_eq!(duplicate_id_fills(usvg::DuplicateIdHandling::UseLast).unwrap(), vec![blue, blue]);
+}
+
+#[test]
+fn duplicate_id_error() {
+    match duplicate_id_fills(usvg::DuplicateIdHandling::Error) {
+        Err(usvg::Error::DuplicateId(id)) => assert_eq!(id, "lg"),
+        _ => panic!("expected a duplicate ID error"),
+    }
+}
+
+macro_rules! test_html_mode {
+    ($name:ident, $input:expr, $paths:expr) => {
+        #[test]
+        fn $name() {
+            let opt = usvg::Options {
+                html_mode: true,
+                .. usvg::Options::default()
+            };
+            let tree = usvg::Tree::from_str($input, &opt.to_ref()).unwrap();
+            let paths = tree.root().descendants()
+                .filter(|n| matches!(*n.borrow(), usvg::NodeKind::Path(_)))
+                .count();
+            assert_eq!(paths, $paths);
+        }
+    };
+}
+
+test_html_mode!(html_mode_without_namespace,
+    "<svg><rect fill='red' width='100' height='100'/></svg>",
+    1
+);
+
+test_html_mode!(html_mode_with_namespace,
+    "<svg xmlns='http://www.w3.org/2000/svg'><rect width='10' height='10'/></svg>",
+    1
+);
+
+test_html_mode!(html_mode_xlink_without_namespace,
+    "<svg width='100' height='100'>\
+        <rect id='rect1' width='10' height='10'/>\
+        <use xlink:href='#rect1' x='20'/>\
+    </svg>",
+    2
+);
+
+test_html_mode!(html_mode_href_without_namespace,
+    "<svg width='100' height='100'>\
+        <rect id='rect1' width='10' height='10'/>\
+        <use href='#rect1' x='20'/>\
+    </svg>",
+    2
+);
+
+test_html_mode!(html_mode_with_entities,
+    "<svg width='100' height='100'>\
+        <desc>&copy;&nbsp;2022 &mdash; &hellip;</desc>\
+        <rect id='rect&nbsp;1' width='10' height='10'/>\
+    </svg>",
+    1
+);
+
+test_html_mode!(html_mode_with_xml_declaration,
+    "<?xml version='1.0'?><svg width='100' height='100'><rect width='10' height='10'/></svg>",
+    1
+);
+
+test_size_err!(html_mode_disabled,
+    "<svg><rect fill='red' width='100' height='100'/></svg>");
+
+macro_rules! test_fix_invalid_xml {
+    ($name:ident, $input:expr, $paths:expr) => {
+        #[test]
+        fn $name() {
+            assert!(usvg::Tree::from_str($input, &usvg::Options::default().to_ref()).is_err());
+
+            let opt = usvg::Options {
+                fix_invalid_xml: true,
+                .. usvg::Options::default()
+            };
+            let tree = usvg::Tree::from_str($input, &opt.to_ref()).unwrap();
+            let paths = tree.root().descendants()
+                .filter(|n| matches!(*n.borrow(), usvg::NodeKind::Path(_)))
+                .count();
+            assert_eq!(paths, $paths);
+        }
+    };
+}
+
+test_fix_invalid_xml!(fix_invalid_xml_ampersand,
+    "<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>\
+        <title>Tom & Jerry &amp; &#169; &#xA9;</title>\
+        <rect id='a&b' width='10' height='10'/>\
+        <use xlink:href='#a&b' x='20'/>\
+    </svg>",
+    2
+);
+
+test_fix_invalid_xml!(fix_invalid_xml_unquoted_attributes,
+    "<svg xmlns='http://www.w3.org/2000/svg' width=100 height=100>\
+        <rect width=10 height=10 fill=#ff0000/>\
+        <rect x = 20 width=10 height=10 fill='blue'></rect>\
+    </svg>",
+    2
+);
+
+test_fix_invalid_xml!(fix_invalid_xml_void_elements,
+    "<svg xmlns='http://www.w3.org/2000/svg'>\
+        <foreignObject width='100' height='100'>\
+            <div xmlns='http://www.w3.org/1999/xhtml'>Text<br>Text<br></br><img src=image.png></div>\
+        </foreignObject>\
+        <rect width='10' height='10'/>\
+    </svg>",
+    1
+);
+
+test_fix_invalid_xml!(fix_invalid_xml_non_ascii_text,
+    "<svg xmlns='http://www.w3.org/2000/svg'>\
+        <title>é & b</title>\
+        <desc>Привет & 你好</desc>\
+        <rect width='10' height='10'/>\
+    </svg>",
+    1
+);
+
+#[test]
+fn fix_invalid_xml_unfixable() {
+    let opt = usvg::Options {
+        fix_invalid_xml: true,
+        .. usvg::Options::default()
+    };
+    let res = usvg::Tree::from_str("<svg xmlns='http://www.w3.org/2000/svg'><g></svg>", &opt.to_ref());
+    assert!(matches!(res, Err(usvg::Error::ParsingFailed(_))));
+}
+
+struct AssetPack(Vec<(&'static str, Vec<u8>)>);
+
+impl usvg::ResourceLoader for AssetPack {
+    fn load(&self, href: &str) -> Option<Vec<u8>> {
+        self.0.iter().find(|(name, _)| *name == href).map(|(_, data)| data.clone())
+    }
+}
+
+fn count_images_with_loader(input: &str) -> usize {
+    let png = std::fs::read("../tests/images/image.png").unwrap();
+    let svg = std::fs::read("../tests/images/image.svg").unwrap();
+    let opt = usvg::Options {
+        resource_loader: Some(Box::new(AssetPack(vec![("image.png", png), ("logo", svg)]))),
+        .. usvg::Options::default()
+    };
+
+    let tree = usvg::Tree::from_str(input, &opt.to_ref()).unwrap();
+    tree.root().descendants()
+        .filter(|n| matches!(*n.borrow(), usvg::NodeKind::Image(_)))
+        .count()
+}
+
+#[test]
+fn resource_loader() {
+    let input = "<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>\
+        <image xlink:href='image.png' width='10' height='10'/>\
+        <image xlink:href='logo' width='10' height='10'/>\
+    </svg>";
+    assert_eq!(count_images_with_loader(input), 2);
+}
+
+#[test]
+fn resource_loader_missing() {
+    // The loader is used instead of the filesystem, even when the file exists.
+    let input = "<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>\
+        <image xlink:href='../tests/images/image.jpg' width='10' height='10'/>\
+    </svg>";
+    assert_eq!(count_images_with_loader(input), 0);
+}
+
+impl usvg::AsyncResourceLoader for AssetPack {
+    fn load<'a>(
+        &'a self,
+        href: &'a str,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Option<Vec<u8>>> + Send + 'a>> {
+        Box::pin(async move { usvg::ResourceLoader::load(self, href) })
+    }
+}
+
+// A minimal executor, since the loader above never returns `Pending`.
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    struct NoopWaker;
+    impl std::task::Wake for NoopWaker {
+        fn wake(self: std::sync::Arc<Self>) {}
+    }
+
+    let waker = std::task::Waker::from(std::sync::Arc::new(NoopWaker));
+    let mut cx = std::task::Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+    loop {
+        if let std::task::Poll::Ready(v) = future.as_mut().poll(&mut cx) {
+            return v;
+        }
+    }
+}
+
+fn assert_send<T: Send>(v: T) -> T { v }
+
+#[test]
+fn async_resource_loader() {
+    let png = std::fs::read("../tests/images/image.png").unwrap();
+    let loader = AssetPack(vec![("image.png", png)]);
+    let input = "<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>\
+        <image xlink:href='image.png' width='10' height='10'/>\
+        <image xlink:href='image.png' x='20' width='10' height='10'/>\
+        <image xlink:href='missing.png' x='40' width='10' height='10'/>\
+    </svg>";
+
+    let opt = usvg::Options::default();
+    let opt = opt.to_ref();
+    let tree = block_on(assert_send(usvg::Tree::from_str_async(input, &opt, &loader))).unwrap();
+    let count = tree.root().descendants()
+        .filter(|n| matches!(*n.borrow(), usvg::NodeKind::Image(_)))
+        .count();
+    assert_eq!(count, 2);
+}
+
+#[test]
+fn view_box_transform() {
+    let input = "<svg xmlns='http://www.w3.org/2000/svg' viewBox='10 20 100 50'/>";
+    let tree = usvg::Tree::from_str(input, &usvg::Options::default().to_ref()).unwrap();
+
+    // `xMidYMid meet` by default.
+    let ts = tree.view_box_transform(200.0, 200.0);
+    assert_eq!(ts, usvg::Transform::new(2.0, 0.0, 0.0, 2.0, -20.0, 10.0));
+    assert_eq!(ts.apply(10.0, 20.0), (0.0, 50.0));
+
+    // Screen to user space, for picking.
+    let inv_ts = ts.invert().unwrap();
+    assert_eq!(inv_ts.apply(100.0, 100.0), (60.0, 45.0));
+
+    assert_eq!(tree.view_box_transform(0.0, 100.0), usvg::Transform::default());
+}
+
+#[test]
+fn view_box_transform_negative_origin() {
+    let input = "<svg xmlns='http://www.w3.org/2000/svg' viewBox='-50 -50 200 200'/>";
+    let tree = usvg::Tree::from_str(input, &usvg::Options::default().to_ref()).unwrap();
+
+    let ts = tree.view_box_transform(400.0, 400.0);
+    assert_eq!(ts.apply(-50.0, -50.0), (0.0, 0.0));
+    assert_eq!(ts.apply(-30.0, -30.0), (40.0, 40.0));
+    // Outside the viewport.
+    let (x, y) = ts.apply(-60.0, -60.0);
+    assert!(x < 0.0 && y < 0.0);
+}
+
+fn path_transform(attrs: &str) -> usvg::Transform {
+    let input = format!(
+        "<svg xmlns='http://www.w3.org/2000/svg'><rect width='10' height='10' {}/></svg>", attrs
+    );
+    let tree = usvg::Tree::from_str(&input, &usvg::Options::default().to_ref()).unwrap();
+    let ts = tree.root().descendants().find_map(|n| match *n.borrow() {
+        usvg::NodeKind::Path(ref path) => Some(path.transform),
+        _ => None,
+    });
+    ts.unwrap()
+}
+
+#[test]
+fn individual_transform_properties() {
+    let mut ts = usvg::Transform::default();
+    ts.translate(10.0, 20.0);
+    ts.rotate(45.0);
+    ts.scale(2.0, 2.0);
+    // Applied before `transform`, in a fixed order.
+    assert_eq!(path_transform("style='translate: 10px 20px; rotate: 45deg;' transform='scale(2)'"), ts);
+    assert_eq!(path_transform("style='scale: 2; rotate: 45deg; translate: 10px 20px'"), ts);
+
+    assert_eq!(path_transform("style='translate: 1in'"), usvg::Transform::new_translate(96.0, 0.0));
+    assert_eq!(path_transform("style='rotate: z 0.25turn'"), usvg::Transform::new_rotate(90.0));
+    assert_eq!(path_transform("style='rotate: 0 0 2 45deg'"), usvg::Transform::new_rotate(45.0));
+    assert_eq!(path_transform("style='rotate: 0 0 -1 45deg'"), usvg::Transform::new_rotate(-45.0));
+    assert_eq!(path_transform("style='scale: 50% 2'"), usvg::Transform::new_scale(0.5, 2.0));
+    assert_eq!(path_transform("style='scale: 2; scale: none'"), usvg::Transform::default());
+
+    // Relative units and 3D rotations are not supported and ignored.
+    assert_eq!(path_transform("style='translate: 10%'"), usvg::Transform::default());
+    assert_eq!(path_transform("style='rotate: x 45deg'"), usvg::Transform::default());
+
+    // Not presentation attributes.
+    assert_eq!(path_transform("scale='2'"), usvg::Transform::default());
+}
+
+#[test]
+fn transform_origin() {
+    // The default viewport is 100x100.
+    let ts = usvg::Transform::new(2.0, 0.0, 0.0, 2.0, -50.0, -50.0);
+    assert_eq!(path_transform("transform='scale(2)' transform-origin='center'"), ts);
+    assert_eq!(path_transform("transform='scale(2)' style='transform-origin: 50% 50%'"), ts);
+    assert_eq!(path_transform("transform='scale(2)' transform-origin='top left'"), usvg::Transform::new_scale(2.0, 2.0));
+
+    let ts = usvg::Transform::new(2.0, 0.0, 0.0, 2.0, -10.0, -20.0);
+    assert_eq!(path_transform("transform='scale(2)' transform-origin='10px 20px'"), ts);
+    assert_eq!(path_transform("style='scale: 2; transform-origin: 10px 20px 5px'"), ts);
+
+    let ts = usvg::Transform::new(2.0, 0.0, 0.0, 2.0, -100.0, -100.0);
+    assert_eq!(path_transform("transform='scale(2)' transform-origin='bottom right'"), ts);
+
+    // The vertical offset is `center` by default.
+    let ts = usvg::Transform::new(2.0, 0.0, 0.0, 2.0, -100.0, -50.0);
+    assert_eq!(path_transform("transform='scale(2)' transform-origin='100%'"), ts);
+
+    // Invalid values are ignored.
+    let ts = usvg::Transform::new_scale(2.0, 2.0);
+    assert_eq!(path_transform("transform='scale(2)' transform-origin='top 10px'"), ts);
+    assert_eq!(path_transform("transform='scale(2)' transform-origin='left right'"), ts);
+}
+
+fn circle(path: &mut usvg::PathData, cx: f64, cy: f64, r: f64, sweep: bool) {
+    path.push_move_to(cx + r, cy);
+    path.push_arc_to(r, r, 0.0, false, sweep, cx - r, cy);
+    path.push_arc_to(r, r, 0.0, false, sweep, cx + r, cy);
+    path.push_close_path();
+}
+
+#[test]
+fn contains_point_donut() {
+    let mut path = usvg::PathData::new();
+    circle(&mut path, 100.0, 100.0, 80.0, true);
+    circle(&mut path, 100.0, 100.0, 40.0, true);
+
+    let fill_rule = usvg::FillRule::EvenOdd;
+    assert!(!path.contains_point(100.0, 100.0, fill_rule)); // hole
+    assert!(path.contains_point(100.0, 40.0, fill_rule)); // ring
+    assert!(path.contains_point(160.0, 100.0, fill_rule)); // ring
+    assert!(!path.contains_point(190.0, 100.0, fill_rule)); // outside
+    assert!(!path.contains_point(30.0, 30.0, fill_rule)); // outside, inside the bbox
+
+    // The same direction, so the hole is filled.
+    assert!(path.contains_point(100.0, 100.0, usvg::FillRule::NonZero));
+}
+
+#[test]
+fn contains_point_nonzero_hole() {
+    // An inner circle with an opposite direction makes a hole.
+    let mut path = usvg::PathData::new();
+    circle(&mut path, 100.0, 100.0, 80.0, true);
+    circle(&mut path, 100.0, 100.0, 40.0, false);
+
+    let fill_rule = usvg::FillRule::NonZero;
+    assert!(!path.contains_point(100.0, 100.0, fill_rule));
+    assert!(path.contains_point(100.0, 40.0, fill_rule));
+}
+
+#[test]
+fn contains_point_open_subpath() {
+    // A triangle without ClosePath.
+    let mut path = usvg::PathData::new();
+    path.push_move_to(0.0, 0.0);
+    path.push_line_to(100.0, 0.0);
+    path.push_line_to(0.0, 100.0);
+
+    assert!(path.contains_point(20.0, 20.0, usvg::FillRule::NonZero));
+    assert!(!path.contains_point(80.0, 80.0, usvg::FillRule::NonZero));
+}
+
+fn circle_path_length(r: f64) -> f64 {
+    // A circle approximated by 4 cubic curves.
+    // Uses a control point distance with the minimal radial error,
+    // since the common 0.5523 one makes the curve itself ~0.014% longer.
+    let k = r * 0.551915024494;
+    let mut path = usvg::PathData::new();
+    path.push_move_to(r, 0.0);
+    path.push_curve_to(r, k, k, r, 0.0, r);
+    path.push_curve_to(-k, r, -r, k, -r, 0.0);
+    path.push_curve_to(-r, -k, -k, -r, 0.0, -r);
+    path.push_curve_to(k, -r, r, -k, r, 0.0);
+    path.push_close_path();
+    path.length()
+}
+
+#[test]
+fn path_length_circle() {
+    for &r in &[0.01, 1.0, 100.0, 10000.0] {
+        let expected = 2.0 * std::f64::consts::PI * r;
+        let error = (circle_path_length(r) - expected).abs() / expected;
+        assert!(error < 0.0001, "r: {}, error: {}", r, error);
+    }
+}
+
+#[test]
+fn path_length_subpaths() {
+    let mut path = usvg::PathData::new();
+    path.push_rect(usvg::Rect::new(0.0, 0.0, 30.0, 40.0).unwrap());
+    path.push_move_to(100.0, 0.0);
+    path.push_line_to(130.0, 40.0);
+    assert_eq!(path.length(), 140.0 + 50.0);
+}
+
+#[test]
+fn text_glyph_positions() {
+    let mut opt = usvg::Options::default();
+    opt.fontdb.load_fonts_dir("../tests/fonts");
+
+    let input = "<svg xmlns='http://www.w3.org/2000/svg'>\
+        <text x='10' y='50' font-family='Noto Sans' font-size='20' letter-spacing='5'>Ab</text>\
+    </svg>";
+    let tree = usvg::Tree::from_str(input, &opt.to_ref()).unwrap();
+    let paths: Vec<_> = tree.root().descendants().filter_map(|n| match *n.borrow() {
+        usvg::NodeKind::Path(ref path) => Some(path.clone()),
+        _ => None,
+    }).collect();
+    assert_eq!(paths.len(), 1);
+
+    let glyphs = &paths[0].glyph_positions;
+    assert_eq!(glyphs.len(), 2);
+    assert_eq!(glyphs[0].unicode_codepoint, 'A');
+    assert_eq!(glyphs[1].unicode_codepoint, 'b');
+
+    // Glyph boxes are above the baseline and stacked along it.
+    assert_eq!(glyphs[0].x, 10.0);
+    assert!(glyphs[0].y < 50.0 && glyphs[0].y + glyphs[0].height > 50.0);
+    assert_eq!(glyphs[0].advance, glyphs[0].width + 5.0);
+    assert!((glyphs[1].x - (glyphs[0].x + glyphs[0].advance)).abs() < 1e-6);
+    assert_eq!(glyphs[0].y, glyphs[1].y);
+}
+
+fn first_glyph_x(body: &str) -> f64 {
+    let mut opt = usvg::Options::default();
+    opt.fontdb.load_fonts_dir("../tests/fonts");
+
+    let input = format!(
+        "<svg xmlns='http://www.w3.org/2000/svg' font-family='Noto Sans'>{}</svg>", body
+    );
+    let tree = usvg::Tree::from_str(&input, &opt.to_ref()).unwrap();
+    let x = tree.root().descendants().find_map(|n| match *n.borrow() {
+        usvg::NodeKind::Path(ref path) => path.glyph_positions.first().map(|g| g.x),
+        _ => None,
+    });
+    x.unwrap()
+}
+
+#[test]
+fn text_em_ex_units() {
+    // Relative to the current element font size, including an inherited one.
+    let x = first_glyph_x("<text x='10' y='50' font-size='20px'><tspan dx='2em'>A</tspan></text>");
+    assert_eq!(x, 50.0);
+
+    let x = first_glyph_x("<text x='10' y='50' font-size='20'><tspan font-size='10' dx='2em'>A</tspan></text>");
+    assert_eq!(x, 30.0);
+
+    // `ex` is half of `em`.
+    let x = first_glyph_x("<text x='10' y='50' font-size='20'><tspan dx='2ex'>A</tspan></text>");
+    assert_eq!(x, 30.0);
+}
+
+fn glyph_advances(body: &str) -> Vec<f64> {
+    let mut opt = usvg::Options::default();
+    opt.fontdb.load_fonts_dir("../tests/fonts");
+
+    let input = format!(
+        "<svg xmlns='http://www.w3.org/2000/svg' font-family='Noto Sans' font-size='20'>{}</svg>", body
+    );
+    let tree = usvg::Tree::from_str(&input, &opt.to_ref()).unwrap();
+    tree.root().descendants().filter_map(|n| match *n.borrow() {
+        usvg::NodeKind::Path(ref path) => Some(path.glyph_positions.iter().map(|g| g.advance).collect::<Vec<_>>()),
+        _ => None,
+    }).flatten().collect()
+}
+
+#[test]
+fn font_variant_numeric() {
+    // Noto Sans uses tabular figures by default.
+    let tabular = glyph_advances("<text y='50'>1180</text>");
+    assert!(tabular.iter().all(|a| *a == tabular[0]));
+    assert_eq!(glyph_advances("<text y='50' font-variant-numeric='tabular-nums'>1180</text>"), tabular);
+
+    let proportional = glyph_advances("<text y='50' font-variant-numeric='proportional-nums'>1180</text>");
+    assert!(proportional[0] < tabular[0]);
+    assert!(proportional[0] < proportional[2]);
+
+    // Inherited and combined with other keywords.
+    let old_style = glyph_advances(
+        "<g font-variant-numeric='oldstyle-nums tabular-nums'><text y='50'>1180</text></g>"
+    );
+    assert!(old_style.iter().all(|a| *a == old_style[0]));
+    assert_ne!(old_style, tabular);
+
+    // An unknown keyword invalidates the whole value.
+    let invalid = glyph_advances("<text y='50' font-variant-numeric='proportional-nums unknown'>1180</text>");
+    assert_eq!(invalid, tabular);
+}
+
+fn fill_color(fill: &str) -> (u8, u8, u8, f64) {
+    let input = format!(
+        "<svg viewBox='0 0 10 10' xmlns='http://www.w3.org/2000/svg'>\
+            <rect width='10' height='10' fill='{}'/>\
+        </svg>", fill
+    );
+    let tree = usvg::Tree::from_str(&input, &usvg::Options::default().to_ref()).unwrap();
+    let fill = tree.root().descendants().find_map(|n| match *n.borrow() {
+        usvg::NodeKind::Path(ref path) => path.fill.clone(),
+        _ => None,
+    }).unwrap();
+
+    match fill.paint {
+        usvg::Paint::Color(c) => (c.red, c.green, c.blue, fill.opacity.get()),
+        _ => panic!("not a color"),
+    }
+}
+
+#[test]
+fn color_function() {
+    assert_eq!(fill_color("color(srgb 1 0.5 0)"), (255, 128, 0, 1.0));
+    assert_eq!(fill_color("color(srgb 100% 50% 0% / 50%)"), (255, 128, 0, 128.0 / 255.0));
+    assert_eq!(fill_color("color(srgb-linear 0.2 0.2 0.2)"), (124, 124, 124, 1.0));
+
+    // Inside the sRGB gamut.
+    assert_eq!(fill_color("color(display-p3 0.5 0.5 0.5)"), (128, 128, 128, 1.0));
+    assert_eq!(fill_color("color(display-p3 0.9175 0.2003 0.1387)"), (255, 0, 0, 1.0));
+    assert_eq!(fill_color("color(xyz-d65 0.9505 1 1.089)"), (255, 255, 255, 1.0));
+    assert_eq!(fill_color("color(xyz-d50 0.9642 1 0.8252)"), (255, 255, 255, 1.0));
+
+    // Outside the sRGB gamut, so clipped.
+    assert_eq!(fill_color("color(display-p3 0 1 0)"), (0, 255, 0, 1.0));
+    assert_eq!(fill_color("color(rec2020 1 0 0)"), (255, 0, 0, 1.0));
+
+    // Unknown color space.
+    assert_eq!(fill_color("color(unknown 1 0 0)"), (0, 0, 0, 1.0));
+}
+
+#[test]
+fn oklab_oklch_functions() {
+    assert_eq!(fill_color("oklab(1 0 0)"), (255, 255, 255, 1.0));
+    assert_eq!(fill_color("oklab(0% 0 0)"), (0, 0, 0, 1.0));
+    assert_eq!(fill_color("oklab(62.8% 0.2249 0.1258)"), (255, 0, 0, 1.0));
+    assert_eq!(fill_color("oklab(62.8% 56.225% 31.45% / 0.5)"), (255, 0, 0, 128.0 / 255.0));
+
+    assert_eq!(fill_color("oklch(62.8% 0.2577 29.23)"), (255, 0, 0, 1.0));
+    assert_eq!(fill_color("oklch(62.8% 0.2577 0.0812turn)"), (255, 0, 0, 1.0));
+    assert_eq!(fill_color("oklch(100% 0 none)"), (255, 255, 255, 1.0));
+
+    // Outside the sRGB gamut, so clipped.
+    assert_eq!(fill_color("oklch(50% 0.2 240)"), (0, 105, 199, 1.0));
+}
+
+#[test]
+fn hsl_hwb_functions() {
+    assert_eq!(fill_color("hsl(120, 100%, 25%)"), (0, 128, 0, 1.0));
+    assert_eq!(fill_color("hsla(120, 100%, 25%, 0.5)"), (0, 128, 0, 128.0 / 255.0));
+    assert_eq!(fill_color("hsl(120deg 100% 25%)"), (0, 128, 0, 1.0));
+    assert_eq!(fill_color("hsl(0.5turn 100% 50% / 50%)"), (0, 255, 255, 128.0 / 255.0));
+    assert_eq!(fill_color("hsl(120.5 100% 25%)"), (0, 128, 1, 1.0));
+
+    // `none` is treated as zero.
+    assert_eq!(fill_color("hsl(none, 50%, 50%)"), (191, 64, 64, 1.0));
+    assert_eq!(fill_color("hsl(120 none 50%)"), (128, 128, 128, 1.0));
+
+    assert_eq!(fill_color("hwb(210, 40%, 40%)"), (102, 128, 153, 1.0));
+    assert_eq!(fill_color("hwb(120 0% 50% / 0.5)"), (0, 128, 0, 128.0 / 255.0));
+    // Whiteness and blackness are normalized when their sum is larger than 100%.
+    assert_eq!(fill_color("hwb(0 60% 60%)"), (128, 128, 128, 1.0));
+    assert_eq!(fill_color("hwb(0 30% 90%)"), (64, 64, 64, 1.0));
+}
+
+#[test]
+fn column_layout() {
+    use usvg::NodeExt;
+
+    let mut opt = usvg::Options::default();
+    opt.fontdb.load_fonts_dir("../tests/fonts");
+    opt.keep_named_groups = true;
+
+    let svg = "\
+    <svg xmlns='http://www.w3.org/2000/svg' font-family='Noto Sans' font-size='20'>
+        <g id='g1'><text x='10' y='20'>A</text></g>
+        <g id='g2' transform='scale(2)'><text x='10' y='40'>Long text</text></g>
+        <g id='g3'><text x='10' y='100'>Text</text></g>
+    </svg>";
+    let tree = usvg::Tree::from_str(svg, &opt.to_ref()).unwrap();
+
+    let mut groups: Vec<_> = ["g1", "g2", "g3"].iter().map(|id| tree.node_by_id(id).unwrap()).collect();
+    let widths: Vec<_> = groups.iter().map(|g| g.calculate_bbox().unwrap().width()).collect();
+    let start_x = groups[0].calculate_bbox().unwrap().x();
+
+    let layout = usvg::layout::ColumnLayout::from_groups(&groups);
+    assert!((layout.column_width - widths[1]).abs() < 1e-6);
+    assert_eq!(layout.transforms.len(), 3);
+
+    for (i, (group, ts)) in groups.iter_mut().zip(layout.transforms.iter()).enumerate() {
+        let old_bbox = group.calculate_bbox().unwrap();
+        if let usvg::NodeKind::Group(ref mut g) = *group.borrow_mut() {
+            g.transform.prepend(ts);
+        }
+
+        let bbox = group.calculate_bbox().unwrap();
+        let x = start_x + i as f64 * layout.column_width;
+        assert!((bbox.x() - x).abs() < 1e-6);
+        assert!((bbox.y() - old_bbox.y()).abs() < 1e-6);
+        assert!((bbox.width() - old_bbox.width()).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn drop_shadow_spread() {
+    let filter = |value: &str| {
+        let svg = format!("\
+        <svg xmlns='http://www.w3.org/2000/svg'>
+            <rect width='10' height='10' filter='{}'/>
+        </svg>", value);
+        let tree = usvg::Tree::from_str(&svg, &usvg::Options::default().to_ref()).unwrap();
+        let filter = tree.defs().children().find_map(|n| match *n.borrow() {
+            usvg::NodeKind::Filter(ref filter) => Some(filter.clone()),
+            _ => None,
+        });
+        filter.unwrap()
+    };
+
+    let morphology = |filter: &usvg::filter::Filter| match filter.primitives[0].kind {
+        usvg::filter::Kind::Morphology(ref fe) => Some((fe.operator, fe.radius_x.get())),
+        _ => None,
+    };
+
+    let grow = filter("drop-shadow(2 3 1 5 red)");
+    assert_eq!(morphology(&grow), Some((usvg::filter::MorphologyOperator::Dilate, 5.0)));
+    assert!(matches!(grow.primitives.last().unwrap().kind, usvg::filter::Kind::Merge(_)));
+
+    let shrink = filter("drop-shadow(rgb(0, 0, 0) 2 3 1 -2) blur(1)");
+    assert_eq!(morphology(&shrink), Some((usvg::filter::MorphologyOperator::Erode, 2.0)));
+
+    let plain = filter("drop-shadow(2 3 1)");
+    assert_eq!(plain.primitives.len(), 1);
+    assert!(matches!(plain.primitives[0].kind, usvg::filter::Kind::DropShadow(_)));
+}
+
+#[test]
+fn filter_reference_cycle() {
+    let offset_input = |primitives: &str| {
+        let svg = format!("\
+        <svg xmlns='http://www.w3.org/2000/svg'>
+            <filter id='filter1'>{}</filter>
+            <rect width='10' height='10' filter='url(#filter1)'/>
+        </svg>", primitives);
+        let tree = usvg::Tree::from_str(&svg, &usvg::Options::default().to_ref()).unwrap();
+        let filter = tree.defs_by_id("filter1").unwrap();
+        let input = match *filter.borrow() {
+            usvg::NodeKind::Filter(ref filter) => match filter.primitives[1].kind {
+                usvg::filter::Kind::Offset(ref fe) => fe.input.clone(),
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        };
+        input
+    };
+
+    // `offset` -> `blur` -> `offset`
+    // Resolved to the previous result, like any other unknown reference.
+    let input = offset_input("
+        <feFlood result='flood'/>
+        <feOffset in='blur' result='offset'/>
+        <feGaussianBlur in='offset' result='blur'/>");
+    assert_eq!(input, usvg::filter::Input::Reference("flood".to_string()));
+
+    // An implicit input creates a cycle too.
+    let input = offset_input("
+        <feFlood result='flood'/>
+        <feOffset in='blur'/>
+        <feGaussianBlur result='blur'/>");
+    assert_eq!(input, usvg::filter::Input::Reference("flood".to_string()));
+
+    // A cycle-free forward reference.
+    let input = offset_input("
+        <feFlood result='flood'/>
+        <feOffset in='blur'/>
+        <feGaussianBlur in='flood' result='blur'/>");
+    assert_eq!(input, usvg::filter::Input::Reference("flood".to_string()));
+}
+
+#[test]
+fn fe_image_target_ids() {
+    use usvg::NodeExt;
+
+    let mut opt = usvg::Options::default();
+    opt.fontdb.load_fonts_dir("../tests/fonts");
+    opt.keep_named_groups = true;
+
+    // A text with several paths, which are placed into a named group.
+    let input = "<svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'>\
+        <filter id='filter1'><feImage xlink:href='#text1'/></filter>\
+        <text id='text1' x='10' y='50' font-family='Noto Sans' opacity='0.5'>\
+            A<tspan fill='green'>b</tspan>\
+        </text>\
+        <rect width='10' height='10' filter='url(#filter1)'/>\
+    </svg>";
+    let tree = usvg::Tree::from_str(input, &opt.to_ref()).unwrap();
+
+    let ids: Vec<_> = tree.root().descendants()
+        .map(|n| n.id().to_string())
+        .filter(|id| id == "text1")
+        .collect();
+    assert_eq!(ids.len(), 1);
+}
+
+fn parse_with_profile(svg: &str, profile: usvg::SvgProfile) -> usvg::Tree {
+    let opt = usvg::Options {
+        profile,
+        ..usvg::Options::default()
+    };
+
+    usvg::Tree::from_str(svg, &opt.to_ref()).unwrap()
+}
+
+#[test]
+fn profile_tiny12() {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg'>
+        <linearGradient id='lg1' gradientTransform='scale(2)' spreadMethod='reflect'>
+            <stop stop-color='red'/>
+            <stop offset='1' stop-color='blue'/>
+        </linearGradient>
+        <clipPath id='clip1'><rect width='5' height='5'/></clipPath>
+        <mask id='mask1'><rect width='5' height='5' fill='white'/></mask>
+        <filter id='filter1'><feGaussianBlur stdDeviation='2'/></filter>
+        <g clip-path='url(#clip1)' mask='url(#mask1)' filter='url(#filter1)' opacity='0.5'>
+            <rect width='10' height='10' fill='url(#lg1)'/>
+        </g>
+        <symbol id='symbol1'><rect width='5' height='5'/></symbol>
+        <use href='#symbol1' x='20'/>
+        <rect x='60' width='10' height='10' opacity='0.5'/>
+        <style>#rect1 { fill: green }</style>
+        <rect id='rect1' x='40' width='10' height='10'/>
+    </svg>";
+
+    for &(profile, is_tiny) in &[(usvg::SvgProfile::SVG2, false), (usvg::SvgProfile::Tiny12, true)] {
+        let tree = parse_with_profile(svg, profile);
+
+        // A group without clip path, mask, filter and opacity is removed.
+        // `opacity` is ignored on the shape too.
+        let group = tree.root().descendants().find_map(|n| match *n.borrow() {
+            usvg::NodeKind::Group(ref g) if g.clip_path.is_some() || g.opacity.get() != 1.0 => Some(g.clone()),
+            _ => None,
+        });
+        assert_eq!(group.is_none(), is_tiny);
+        if let Some(group) = group {
+            assert!(group.mask.is_some());
+            assert!(!group.filter.is_empty());
+        }
+
+        let paths: Vec<_> = tree.root().descendants()
+            .filter(|n| !tree.is_in_defs(n))
+            .filter_map(|n| match *n.borrow() {
+                usvg::NodeKind::Path(ref path) => Some(path.clone()),
+                _ => None,
+            })
+            .collect();
+        // The `symbol` content is not rendered.
+        assert_eq!(paths.len(), if is_tiny { 3 } else { 4 });
+        // The style sheet is ignored.
+        let is_green = match paths.last().unwrap().fill {
+            Some(usvg::Fill { paint: usvg::Paint::Color(c), .. }) => c == usvg::Color::new_rgb(0, 128, 0),
+            _ => false,
+        };
+        assert_eq!(is_green, !is_tiny);
+
+        let gradient = tree.defs().children().find_map(|n| match *n.borrow() {
+            usvg::NodeKind::LinearGradient(ref lg) => Some(lg.clone()),
+            _ => None,
+        }).unwrap();
+        assert_eq!(gradient.transform.is_default(), is_tiny);
+        assert_eq!(gradient.spread_method == usvg::SpreadMethod::Pad, is_tiny);
+    }
+}
+
+#[test]
+fn profile_full11() {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg'>
+        <rect width='10' height='10' fill='green' stroke='black'
+              paint-order='stroke' stroke-alignment='outer' filter='blur(2)'
+              style='translate: 10px 20px'/>
+    </svg>";
+
+    let tree = parse_with_profile(svg, usvg::SvgProfile::SVG2);
+    assert!(tree.root().descendants().any(|n| match *n.borrow() {
+        usvg::NodeKind::Group(ref g) => !g.filter.is_empty(),
+        _ => false,
+    }));
+    assert!(tree.root().descendants().any(|n| n.borrow().transform() == usvg::Transform::new_translate(10.0, 20.0)));
+
+    let tree = parse_with_profile(svg, usvg::SvgProfile::Full11);
+    for node in tree.root().descendants() {
+        match *node.borrow() {
+            usvg::NodeKind::Group(ref g) => assert!(g.filter.is_empty()),
+            usvg::NodeKind::Path(ref path) => {
+                assert_eq!(path.paint_order, usvg::PaintOrder::FillAndStroke);
+                assert_eq!(path.stroke.as_ref().unwrap().alignment, usvg::StrokeAlignment::Center);
+            }
+            _ => {}
+        }
+
+        assert!(node.borrow().transform().is_default());
+    }
+}
@@ -0,0 +1,223 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! WebAssembly bindings.
+
+use std::cell::RefCell;
+
+use resvg::{tiny_skia, usvg};
+use serde::Deserialize;
+use usvg::{fontdb, NodeExt};
+use wasm_bindgen::prelude::*;
+
+#[cfg(feature = "wee_alloc")]
+#[global_allocator]
+static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_OPTIONS: &'static str = r#"
+/**
+ * Options accepted by `render_svg`, `render_node` and `get_size` as a JSON string.
+ */
+export interface RenderOptions {
+    width?: number;
+    height?: number;
+    zoom?: number;
+    dpi?: number;
+    font_family?: string;
+    font_size?: number;
+    languages?: string[];
+    shape_rendering?: 'optimizeSpeed' | 'crispEdges' | 'geometricPrecision';
+    text_rendering?: 'optimizeSpeed' | 'optimizeLegibility' | 'geometricPrecision';
+    image_rendering?: 'optimizeQuality' | 'optimizeSpeed';
+}
+"#;
+
+thread_local! {
+    // There are no system fonts, so all fonts are loaded via `load_font`.
+    static FONTS: RefCell<fontdb::Database> = RefCell::new(fontdb::Database::new());
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+struct RenderOptions {
+    width: Option<u32>,
+    height: Option<u32>,
+    zoom: Option<f32>,
+    dpi: Option<f64>,
+    font_family: Option<String>,
+    font_size: Option<f64>,
+    languages: Option<Vec<String>>,
+    shape_rendering: Option<ShapeRendering>,
+    text_rendering: Option<TextRendering>,
+    image_rendering: Option<ImageRendering>,
+}
+
+#[derive(Deserialize, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+enum ShapeRendering {
+    OptimizeSpeed,
+    CrispEdges,
+    GeometricPrecision,
+}
+
+#[derive(Deserialize, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+enum TextRendering {
+    OptimizeSpeed,
+    OptimizeLegibility,
+    GeometricPrecision,
+}
+
+#[derive(Deserialize, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+enum ImageRendering {
+    OptimizeQuality,
+    OptimizeSpeed,
+}
+
+impl RenderOptions {
+    fn from_json(json: Option<String>) -> Result<Self, JsValue> {
+        match json {
+            Some(json) => serde_json::from_str(&json)
+                .map_err(|e| JsValue::from_str(&format!("invalid options: {}", e))),
+            None => Ok(RenderOptions::default()),
+        }
+    }
+
+    fn fit_to(&self) -> Result<usvg::FitTo, JsValue> {
+        match (self.width, self.height, self.zoom) {
+            (None, None, None) => Ok(usvg::FitTo::Original),
+            (Some(w), None, None) => Ok(usvg::FitTo::Width(w)),
+            (None, Some(h), None) => Ok(usvg::FitTo::Height(h)),
+            (None, None, Some(z)) => Ok(usvg::FitTo::Zoom(z)),
+            _ => Err(JsValue::from_str("only one of width, height and zoom can be set")),
+        }
+    }
+
+    fn to_usvg(&self) -> usvg::Options {
+        let mut opt = usvg::Options::default();
+
+        if let Some(dpi) = self.dpi {
+            opt.dpi = dpi;
+        }
+
+        if let Some(ref family) = self.font_family {
+            opt.font_family = family.clone();
+        }
+
+        if let Some(size) = self.font_size {
+            opt.font_size = size;
+        }
+
+        if let Some(ref languages) = self.languages {
+            opt.languages = languages.clone();
+        }
+
+        if let Some(mode) = self.shape_rendering {
+            opt.shape_rendering = match mode {
+                ShapeRendering::OptimizeSpeed => usvg::ShapeRendering::OptimizeSpeed,
+                ShapeRendering::CrispEdges => usvg::ShapeRendering::CrispEdges,
+                ShapeRendering::GeometricPrecision => usvg::ShapeRendering::GeometricPrecision,
+            };
+        }
+
+        if let Some(mode) = self.text_rendering {
+            opt.text_rendering = match mode {
+                TextRendering::OptimizeSpeed => usvg::TextRendering::OptimizeSpeed,
+                TextRendering::OptimizeLegibility => usvg::TextRendering::OptimizeLegibility,
+                TextRendering::GeometricPrecision => usvg::TextRendering::GeometricPrecision,
+            };
+        }
+
+        if let Some(mode) = self.image_rendering {
+            opt.image_rendering = match mode {
+                ImageRendering::OptimizeQuality => usvg::ImageRendering::OptimizeQuality,
+                ImageRendering::OptimizeSpeed => usvg::ImageRendering::OptimizeSpeed,
+            };
+        }
+
+        opt
+    }
+}
+
+/// Loads a TrueType or OpenType font (or a collection) for all subsequent calls.
+#[wasm_bindgen]
+pub fn load_font(data: Vec<u8>) {
+    FONTS.with(|fonts| fonts.borrow_mut().load_font_data(data));
+}
+
+/// Returns the image size as `[width, height]`.
+#[wasm_bindgen]
+pub fn get_size(svg: &str, options_json: Option<String>) -> Result<Box<[f64]>, JsValue> {
+    let options = RenderOptions::from_json(options_json)?;
+    let tree = parse(svg, &options)?;
+    let size = tree.svg_node().size;
+    Ok(Box::new([size.width(), size.height()]))
+}
+
+/// Renders an image into PNG.
+#[wasm_bindgen]
+pub fn render_svg(svg: &str, options_json: Option<String>) -> Result<Vec<u8>, JsValue> {
+    let options = RenderOptions::from_json(options_json)?;
+    let fit_to = options.fit_to()?;
+    let tree = parse(svg, &options)?;
+
+    let size = fit_to.fit_to(tree.svg_node().size.to_screen_size())
+        .ok_or_else(|| JsValue::from_str("target size is zero"))?;
+
+    // Unwrap is safe, because `size` is already valid.
+    let mut pixmap = tiny_skia::Pixmap::new(size.width(), size.height()).unwrap();
+    resvg::render(&tree, fit_to, tiny_skia::Transform::default(), pixmap.as_mut())
+        .ok_or_else(|| JsValue::from_str("failed to render an image"))?;
+
+    encode_png(&pixmap)
+}
+
+/// Renders a node with the specified ID into PNG.
+///
+/// The image size is based on the node bounding box.
+#[wasm_bindgen]
+pub fn render_node(svg: &str, node_id: &str, options_json: Option<String>) -> Result<Vec<u8>, JsValue> {
+    let options = RenderOptions::from_json(options_json)?;
+    let fit_to = options.fit_to()?;
+
+    let mut options = options.to_usvg();
+    // Otherwise the node can be removed during the conversion.
+    options.keep_named_groups = true;
+    let tree = parse_with(svg, &options)?;
+
+    let node = tree.node_by_id(node_id)
+        .ok_or_else(|| JsValue::from_str(&format!("SVG doesn't have '{}' ID", node_id)))?;
+
+    let bbox = node.calculate_bbox().and_then(|r| r.to_rect())
+        .ok_or_else(|| JsValue::from_str("node has zero size"))?;
+
+    let size = fit_to.fit_to(bbox.to_screen_size())
+        .ok_or_else(|| JsValue::from_str("target size is zero"))?;
+
+    // Unwrap is safe, because `size` is already valid.
+    let mut pixmap = tiny_skia::Pixmap::new(size.width(), size.height()).unwrap();
+    resvg::render_node(&tree, &node, fit_to, tiny_skia::Transform::default(), pixmap.as_mut())
+        .ok_or_else(|| JsValue::from_str("failed to render a node"))?;
+
+    encode_png(&pixmap)
+}
+
+fn parse(svg: &str, options: &RenderOptions) -> Result<usvg::Tree, JsValue> {
+    parse_with(svg, &options.to_usvg())
+}
+
+fn parse_with(svg: &str, opt: &usvg::Options) -> Result<usvg::Tree, JsValue> {
+    FONTS.with(|fonts| {
+        let fonts = fonts.borrow();
+        let mut opt = opt.to_ref();
+        opt.fontdb = &fonts;
+        usvg::Tree::from_str(svg, &opt).map_err(|e| JsValue::from_str(&e.to_string()))
+    })
+}
+
+fn encode_png(pixmap: &tiny_skia::Pixmap) -> Result<Vec<u8>, JsValue> {
+    pixmap.encode_png().map_err(|e| JsValue::from_str(&e.to_string()))
+}